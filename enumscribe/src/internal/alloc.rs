@@ -0,0 +1,8 @@
+//! Re-exports of the `alloc` types used by generated code, so that the code works in `no_std`
+//! crates which have not declared `extern crate alloc`.
+
+pub use ::alloc::borrow::{Cow, ToOwned};
+pub use ::alloc::boxed::Box;
+pub use ::alloc::format;
+pub use ::alloc::string::String;
+pub use ::alloc::vec::Vec;
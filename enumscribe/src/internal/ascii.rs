@@ -0,0 +1,52 @@
+//! Utilities for matching strings against variant names while ignoring the case of ASCII letters.
+
+/// Returns whether `a` and `b` are equal when the case of ASCII letters is ignored. This is the
+/// same as `str::eq_ignore_ascii_case`, but may be used in constant expressions.
+#[inline]
+#[must_use]
+pub const fn ascii_ci_eq(a: &str, b: &str) -> bool {
+    let a = a.as_bytes();
+    let b = b.as_bytes();
+
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < a.len() {
+        if !a[i].eq_ignore_ascii_case(&b[i]) {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ascii_ci_eq;
+
+    #[test]
+    fn test_ascii_ci_eq() {
+        assert!(ascii_ci_eq("", ""));
+        assert!(ascii_ci_eq("lhr", "LHR"));
+        assert!(ascii_ci_eq("LhR", "lHr"));
+        assert!(ascii_ci_eq("crates.io", "CRATES.IO"));
+        assert!(ascii_ci_eq("\u{e9}t\u{e9}", "\u{e9}T\u{e9}"));
+        assert!(!ascii_ci_eq("lhr", "lgw"));
+        assert!(!ascii_ci_eq("lhr", "lh"));
+        assert!(!ascii_ci_eq("", "a"));
+        assert!(!ascii_ci_eq("@", "`"));
+        assert!(!ascii_ci_eq("[", "{"));
+        assert!(!ascii_ci_eq("gro\u{df}", "GROSS"));
+        assert!(!ascii_ci_eq("\u{e9}", "\u{c9}"));
+    }
+
+    #[test]
+    fn test_ascii_ci_eq_const() {
+        const EQ: bool = ascii_ci_eq("Heathrow", "HEATHROW");
+        const NE: bool = ascii_ci_eq("Heathrow", "Gatwick");
+        assert_eq!([EQ, NE], [true, false]);
+    }
+}
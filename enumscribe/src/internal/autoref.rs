@@ -0,0 +1,49 @@
+//! Traits used by the [`scribe!`](crate::scribe) macro to pick which scribe trait to call.
+//!
+//! This uses autoref specialization: [ViaScribeStaticStr] is implemented for [Wrap] and
+//! [ViaScribeCowStr] for `&Wrap`, so calling the method on a `&Wrap` finds the
+//! [ScribeStaticStr] implementation first, and only needs to take another reference to find the
+//! [ScribeCowStr](crate::ScribeCowStr) implementation if there is none.
+
+use crate::ScribeStaticStr;
+
+#[cfg(feature = "alloc")]
+use crate::ScribeCowStr;
+#[cfg(feature = "alloc")]
+use ::alloc::borrow::Cow;
+
+/// A reference to the value being scribed.
+pub struct Wrap<'a, T: ?Sized>(pub &'a T);
+
+/// Scribes a value which implements [ScribeStaticStr].
+pub trait ViaScribeStaticStr {
+    /// Calls [ScribeStaticStr::scribe].
+    fn __enumscribe_scribe(&self) -> &'static str;
+}
+
+impl<T: ScribeStaticStr + ?Sized> ViaScribeStaticStr for Wrap<'_, T> {
+    #[inline]
+    fn __enumscribe_scribe(&self) -> &'static str {
+        self.0.scribe()
+    }
+}
+
+/// Scribes a value which implements [ScribeCowStr], but not [ScribeStaticStr].
+#[cfg(feature = "alloc")]
+pub trait ViaScribeCowStr {
+    /// Calls [ScribeCowStr::scribe].
+    fn __enumscribe_scribe(&self) -> Cow<'static, str>;
+}
+
+/// Without the `alloc` feature there is no `ScribeCowStr`, so this has no implementations. It
+/// still exists so that [`scribe!`](crate::scribe) can import it either way.
+#[cfg(not(feature = "alloc"))]
+pub trait ViaScribeCowStr {}
+
+#[cfg(feature = "alloc")]
+impl<T: ScribeCowStr + ?Sized> ViaScribeCowStr for &Wrap<'_, T> {
+    #[inline]
+    fn __enumscribe_scribe(&self) -> Cow<'static, str> {
+        self.0.scribe()
+    }
+}
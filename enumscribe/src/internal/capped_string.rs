@@ -1,7 +1,9 @@
 //! Module for the [`CappedString`](CappedString) type, which is a string type which always stores
 //! its data inline.
 
-use core::{str, ops::Deref, borrow::Borrow, fmt};
+use core::{str, ops::Deref, borrow::Borrow, convert::TryFrom, fmt};
+#[cfg(all(feature = "serde", feature = "alloc"))]
+use alloc::string::String;
 
 /// A string type which is either borrowed or stores up to `N` bytes of string data inline.
 pub enum CowCappedString<'a, const N: usize> {
@@ -12,6 +14,51 @@ pub enum CowCappedString<'a, const N: usize> {
 }
 
 impl<'a, const N: usize> CowCappedString<'a, N> {
+    /// Returns the given string with every `"\r\n"` replaced by `"\n"`. The string is borrowed if
+    /// it does not contain `"\r\n"`. Otherwise, returns `None` if the replaced string is larger
+    /// than `N` bytes.
+    #[inline]
+    #[must_use]
+    pub fn normalize_newlines(s: &'a str) -> Option<Self> {
+        if !s.contains("\r\n") {
+            return Some(CowCappedString::Borrowed(s));
+        }
+
+        let mut normalized = CappedString::new();
+        let mut chars = s.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if c == '\r' && chars.peek() == Some(&'\n') {
+                continue;
+            }
+            normalized.push(c)?;
+        }
+
+        Some(CowCappedString::Owned(normalized))
+    }
+
+    /// Returns the given string converted to Unicode Normalization Form C. The string is borrowed
+    /// if it is already in NFC. Otherwise, returns `None` if the converted string is larger than
+    /// `N` bytes.
+    #[cfg(feature = "nfc")]
+    #[inline]
+    #[must_use]
+    pub fn nfc(s: &'a str) -> Option<Self> {
+        use unicode_normalization::{is_nfc_quick, IsNormalized, UnicodeNormalization};
+
+        if is_nfc_quick(s.chars()) == IsNormalized::Yes {
+            return Some(CowCappedString::Borrowed(s));
+        }
+
+        let mut normalized = CappedString::new();
+
+        for c in s.nfc() {
+            normalized.push(c)?;
+        }
+
+        Some(CowCappedString::Owned(normalized))
+    }
+
     /// Returns the string data contained by this `CowCappedString`.
     #[inline]
     #[must_use]
@@ -83,6 +130,17 @@ impl<'de, const N: usize> serde::de::Visitor<'de> for CowCappedStringVisitor<N>
             .map(CowCappedString::Owned)
     }
 
+    // Formats which only produce owned strings (for example, when the string had to be unescaped,
+    // or when the value is buffered by `#[serde(flatten)]`) call this rather than `visit_str`.
+    #[cfg(feature = "alloc")]
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        CappedStringVisitor::<N>.visit_str(&v)
+            .map(CowCappedString::Owned)
+    }
+
     fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
     where
         E: serde::de::Error,
@@ -119,25 +177,79 @@ pub struct CappedString<const N: usize> {
 impl<const N: usize> CappedString<N> {
     /// Returns a new `CappedString` containing a copy of the given string data. Returns `None` if
     /// the string data is larger than `N` bytes.
+    #[allow(clippy::should_implement_trait)]
     #[inline]
     #[must_use]
     pub fn from_str(s: &str) -> Option<Self> {
         unsafe { Self::from_utf8_unchecked(s.as_bytes()) }
     }
 
+    /// Returns a new, empty `CappedString`.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self { buf: [0u8; N], len: 0 }
+    }
+
+    /// Returns a new `CappedString` containing the characters yielded by the given iterator.
+    /// Returns `None` if the characters take up more than `N` bytes, in which case the iterator
+    /// is not consumed any further.
+    #[inline]
+    #[must_use]
+    pub fn from_chars<I>(chars: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = char>,
+    {
+        let mut s = Self::new();
+        for c in chars {
+            s.push(c)?;
+        }
+        Some(s)
+    }
+
+    /// Appends the given character to the end of the string. Returns `None` if there is not
+    /// enough space left in the buffer, in which case the `CappedString` is left unchanged.
+    #[inline]
+    pub fn push(&mut self, c: char) -> Option<()> {
+        let encode_buf_end = self.len.checked_add(c.len_utf8())?;
+        let encode_buf = self.buf.get_mut(self.len..encode_buf_end)?;
+        c.encode_utf8(encode_buf);
+
+        // SAFETY:
+        // - `encode_buf_end <= N`, since `get_mut` returned a subslice of `buf` ending there.
+        // - The first `len` bytes of `buf` were already valid UTF-8, and they have been followed
+        //   by the bytes of a single `char` obtained from `char::encode_utf8`.
+        self.len = encode_buf_end;
+
+        Some(())
+    }
+
     /// Returns a new `CappedString` containing an uppercase conversion of the given string data.
     /// Returns `None` if the converted string is larger than `N` bytes.
     #[inline]
     #[must_use]
     pub fn uppercase_from_str(s: &str) -> Option<Self> {
-        let mut buf = [0u8; N];
+        let mut s_upper = Self::new();
+        s_upper.assign_uppercase(s)?;
+        Some(s_upper)
+    }
+
+    /// Replaces the contents of this `CappedString` with an uppercase conversion of the given
+    /// string data, reusing the existing buffer, and returns the converted string. Returns `None`
+    /// if the converted string is larger than `N` bytes, in which case the `CappedString` is left
+    /// empty.
+    #[inline]
+    pub fn assign_uppercase(&mut self, s: &str) -> Option<&str> {
+        // Clear the string first so that the invariants hold if we return early.
+        self.len = 0;
+
         let mut cursor = 0usize;
 
         for c_orig in s.chars() {
             for c_upper in c_orig.to_uppercase() {
                 let encode_buf = cursor
                     .checked_add(c_upper.len_utf8())
-                    .and_then(|encode_buf_end| buf.get_mut(cursor..encode_buf_end))?;
+                    .and_then(|encode_buf_end| self.buf.get_mut(cursor..encode_buf_end))?;
 
                 // FIXME: avoid the panic asm that gets generated for this encode (can never panic,
                 // as we always have at least `c_upper.len_utf8()` buffer space).
@@ -146,12 +258,14 @@ impl<const N: usize> CappedString<N> {
             }
         }
 
-        let filled_buf = buf.get(..cursor)?;
-
         // SAFETY:
-        // `filled_buf` has been filled with a sequence of bytes obtained from `char::encode_utf8`,
-        // so it is valid UTF-8.
-        unsafe { Self::from_utf8_unchecked(filled_buf) }
+        // - `cursor <= N`, since every byte before `cursor` was written using a subslice of
+        //   `buf` obtained from `get_mut`.
+        // - The first `cursor` bytes of `buf` have been filled with a sequence of bytes obtained
+        //   from `char::encode_utf8`, so they are valid UTF-8.
+        self.len = cursor;
+
+        Some(self.as_str())
     }
 
     /// Returns a new `CappedString` containing a copy of the given UTF-8 encoded string data.
@@ -214,6 +328,13 @@ impl<const N: usize> CappedString<N> {
     }
 }
 
+impl<const N: usize> Default for CappedString<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<const N: usize> Deref for CappedString<N> {
     type Target = str;
 
@@ -237,6 +358,27 @@ impl<const N: usize> Borrow<str> for CappedString<N> {
     }
 }
 
+impl<'a, const N: usize> TryFrom<&'a str> for CappedString<N> {
+    type Error = CapacityError;
+
+    #[inline]
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        Self::from_str(s).ok_or(CapacityError {
+            len: s.len(),
+            capacity: N,
+        })
+    }
+}
+
+impl<const N: usize> str::FromStr for CappedString<N> {
+    type Err = CapacityError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
 impl<const N: usize> PartialEq for CappedString<N> {
     fn eq(&self, other: &Self) -> bool {
         self.as_str() == other.as_str()
@@ -251,6 +393,42 @@ impl<const N: usize> PartialEq<str> for CappedString<N> {
     }
 }
 
+/// The error returned when string data is too large to fit in a [`CappedString`](CappedString).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct CapacityError {
+    len: usize,
+    capacity: usize,
+}
+
+impl CapacityError {
+    /// Returns the length in bytes of the string data which could not be stored.
+    #[inline]
+    #[must_use]
+    pub fn required_len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the capacity in bytes of the `CappedString` the data could not be stored in.
+    #[inline]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "string of {} bytes exceeds capacity of {} bytes",
+            self.len, self.capacity
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CapacityError {}
+
 #[cfg(feature = "serde")]
 impl<'de, const N: usize> serde::Deserialize<'de> for CappedString<N> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -359,6 +537,18 @@ mod tests {
             );
             assert!(s.is_err());
         }
+        {
+            use serde::{de::IntoDeserializer, Deserialize};
+
+            let deserializer: serde::de::value::StringDeserializer<serde::de::value::Error> =
+                "hello".to_owned().into_deserializer();
+            let DeOwnedOnly(s) = DeOwnedOnly::<5>::deserialize(deserializer).unwrap();
+            assert_eq!(s, "hello");
+
+            let deserializer: serde::de::value::StringDeserializer<serde::de::value::Error> =
+                "hello".to_owned().into_deserializer();
+            assert!(DeOwnedOnly::<4>::deserialize(deserializer).is_err());
+        }
     }
 
     #[cfg(feature = "serde")]
@@ -402,6 +592,43 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_capped_string_try_from() {
+        use core::convert::TryFrom;
+
+        {
+            let s = CappedString::<5>::try_from("hello").unwrap();
+            assert_eq!(s.as_str(), "hello");
+        }
+        {
+            let s = CappedString::<10>::try_from("hello").unwrap();
+            assert_eq!(s.as_str(), "hello");
+        }
+        {
+            let s = CappedString::<0>::try_from("").unwrap();
+            assert_eq!(s.as_str(), "");
+        }
+        {
+            let err = CappedString::<4>::try_from("hello").err().unwrap();
+            assert_eq!(err.required_len(), 5);
+            assert_eq!(err.capacity(), 4);
+        }
+        {
+            let err = CappedString::<2>::try_from("蟹").err().unwrap();
+            assert_eq!(err.required_len(), 3);
+            assert_eq!(err.capacity(), 2);
+        }
+        {
+            let s = "hello".parse::<CappedString<5>>().unwrap();
+            assert_eq!(s.as_str(), "hello");
+        }
+        {
+            let err = "hello".parse::<CappedString<3>>().err().unwrap();
+            assert_eq!(err.required_len(), 5);
+            assert_eq!(err.capacity(), 3);
+        }
+    }
+
     #[test]
     fn test_capped_string_uppercase() {
         {
@@ -439,4 +666,83 @@ mod tests {
             assert_eq!(s2.as_str(), "");
         }
     }
+
+    #[test]
+    fn test_capped_string_assign_uppercase() {
+        let mut buf = CappedString::<5>::new();
+        assert_eq!(buf.as_str(), "");
+
+        assert_eq!(buf.assign_uppercase("hello"), Some("HELLO"));
+        assert_eq!(buf.as_str(), "HELLO");
+
+        assert_eq!(buf.assign_uppercase("baa"), Some("BAA"));
+        assert_eq!(buf.as_str(), "BAA");
+
+        assert_eq!(buf.assign_uppercase("groß"), Some("GROSS"));
+        assert_eq!(buf.as_str(), "GROSS");
+
+        assert_eq!(buf.assign_uppercase("hello!"), None);
+        assert_eq!(buf.as_str(), "");
+
+        assert_eq!(buf.assign_uppercase("größ"), None);
+        assert_eq!(buf.as_str(), "");
+
+        assert_eq!(buf.assign_uppercase(""), Some(""));
+        assert_eq!(buf.as_str(), "");
+
+        let mut buf = CappedString::<0>::default();
+        assert_eq!(buf.assign_uppercase(""), Some(""));
+        assert_eq!(buf.assign_uppercase("a"), None);
+    }
+
+    #[test]
+    fn test_cow_capped_string_normalize_newlines() {
+        let s = CowCappedString::<8>::normalize_newlines("a\r\nb").unwrap();
+        assert!(matches!(s, CowCappedString::Owned(_)));
+        assert_eq!(s.as_str(), "a\nb");
+
+        let s = CowCappedString::<0>::normalize_newlines("a\nb\rc").unwrap();
+        assert!(matches!(s, CowCappedString::Borrowed(_)));
+        assert_eq!(s.as_str(), "a\nb\rc");
+
+        let s = CowCappedString::<8>::normalize_newlines("\r\r\n\r\n").unwrap();
+        assert_eq!(s.as_str(), "\r\n\n");
+
+        assert!(CowCappedString::<2>::normalize_newlines("ab\r\n").is_none());
+        assert!(CowCappedString::<3>::normalize_newlines("ab\r\n").is_some());
+    }
+
+    #[cfg(feature = "nfc")]
+    #[test]
+    fn test_cow_capped_string_nfc() {
+        let s = CowCappedString::<8>::nfc("cafe\u{301}").unwrap();
+        assert!(matches!(s, CowCappedString::Owned(_)));
+        assert_eq!(s.as_str(), "caf\u{e9}");
+
+        let s = CowCappedString::<0>::nfc("caf\u{e9}").unwrap();
+        assert!(matches!(s, CowCappedString::Borrowed(_)));
+        assert_eq!(s.as_str(), "caf\u{e9}");
+
+        assert!(CowCappedString::<4>::nfc("cafe\u{301}").is_none());
+        assert!(CowCappedString::<5>::nfc("cafe\u{301}").is_some());
+    }
+
+    #[test]
+    fn test_capped_string_push() {
+        let mut s = CappedString::<4>::new();
+        assert_eq!(s.push('a'), Some(()));
+        assert_eq!(s.push('蟹'), Some(()));
+        assert_eq!(s.as_str(), "a蟹");
+        assert_eq!(s.push('ß'), None);
+        assert_eq!(s.as_str(), "a蟹");
+
+        let mut s = CappedString::<0>::new();
+        assert_eq!(s.push('a'), None);
+        assert_eq!(s.as_str(), "");
+
+        assert_eq!(CappedString::<3>::from_chars("LHR".chars()).unwrap().as_str(), "LHR");
+        assert_eq!(CappedString::<3>::from_chars("".chars()).unwrap().as_str(), "");
+        assert!(CappedString::<3>::from_chars("LHRX".chars()).is_none());
+        assert!(CappedString::<3>::from_chars("蟹".chars().cycle()).is_none());
+    }
 }
@@ -0,0 +1,160 @@
+//! Module for the [`CowString`](CowString) type, which is a string type which is either borrowed
+//! or stored in an owned heap-allocated `String`.
+
+use alloc::string::String;
+#[cfg(feature = "serde")]
+use alloc::borrow::ToOwned;
+use core::{borrow::Borrow, ops::Deref};
+#[cfg(feature = "serde")]
+use core::{fmt, str};
+
+/// A string type which is either borrowed or stored in a `String`. Unlike
+/// [`CowCappedString`](super::capped_string::CowCappedString), there is no limit on the length of
+/// the owned string data, so it can be used when an arbitrary string must be captured (for
+/// example, by an `#[enumscribe(other)]` variant).
+pub enum CowString<'a> {
+    /// A reference to string data stored elsewhere.
+    Borrowed(&'a str),
+    /// The string data is stored in a `String`.
+    Owned(String),
+}
+
+impl<'a> CowString<'a> {
+    /// Returns the string data contained by this `CowString`.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            CowString::Borrowed(s) => s,
+            CowString::Owned(s) => s,
+        }
+    }
+}
+
+impl<'a> Deref for CowString<'a> {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<'a> AsRef<str> for CowString<'a> {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self
+    }
+}
+
+impl<'a> Borrow<str> for CowString<'a> {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for CowString<'de> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        deserializer.deserialize_str(CowStringVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+struct CowStringVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for CowStringVisitor {
+    type Value = CowString<'de>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a string")
+    }
+
+    // Strings which borrow from the input are kept as `Borrowed` by `visit_borrowed_str`, and
+    // strings which the format has already allocated are moved into `Owned` by `visit_string`.
+    // Only strings which live no longer than this call (for example, ones unescaped into a
+    // scratch buffer) are copied here.
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(CowString::Owned(v.to_owned()))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(CowString::Owned(v))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        str::from_utf8(v)
+            .map_err(|_| E::invalid_value(serde::de::Unexpected::Bytes(v), &self))
+            .and_then(|v| self.visit_str(v))
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(CowString::Borrowed(v))
+    }
+
+    fn visit_borrowed_bytes<E>(self, v: &'de [u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        str::from_utf8(v)
+            .map_err(|_| E::invalid_value(serde::de::Unexpected::Bytes(v), &self))
+            .and_then(|v| self.visit_borrowed_str(v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_cow_string_deserialize() {
+        use super::CowString;
+        use alloc::borrow::ToOwned;
+        use serde::de::value::{BorrowedStrDeserializer, Error, StrDeserializer, StringDeserializer};
+        use serde::Deserialize;
+
+        match serde_json::from_str::<CowString>(r#""hello""#).unwrap() {
+            CowString::Borrowed(s) => assert_eq!(s, "hello"),
+            CowString::Owned(_) => panic!("expected borrowed CowString"),
+        }
+
+        match serde_json::from_str::<CowString>(r#""hello\nworld""#).unwrap() {
+            CowString::Borrowed(_) => panic!("expected owned CowString"),
+            CowString::Owned(s) => assert_eq!(s, "hello\nworld"),
+        }
+
+        match CowString::deserialize(BorrowedStrDeserializer::<Error>::new("hello")).unwrap() {
+            CowString::Borrowed(s) => assert_eq!(s, "hello"),
+            CowString::Owned(_) => panic!("expected borrowed CowString"),
+        }
+
+        match CowString::deserialize(StrDeserializer::<Error>::new("hello")).unwrap() {
+            CowString::Borrowed(_) => panic!("expected owned CowString"),
+            CowString::Owned(s) => assert_eq!(s, "hello"),
+        }
+
+        // An owned string should be moved into the `CowString` rather than copied.
+        let owned = "hello".to_owned();
+        let owned_ptr = owned.as_ptr();
+        match CowString::deserialize(StringDeserializer::<Error>::new(owned)).unwrap() {
+            CowString::Borrowed(_) => panic!("expected owned CowString"),
+            CowString::Owned(s) => assert_eq!(s.as_ptr(), owned_ptr),
+        }
+    }
+}
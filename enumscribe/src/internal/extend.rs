@@ -0,0 +1,68 @@
+//! Utilities for checking that the variant names of one enum are all names of another.
+
+/// Returns whether every string in `names` is also in `targets`. This may be used in constant
+/// expressions, so that a missing name is reported at compile time.
+#[must_use]
+pub const fn names_subset(names: &[&str], targets: &[&str]) -> bool {
+    let mut i = 0;
+    while i < names.len() {
+        let mut found = false;
+        let mut j = 0;
+        while j < targets.len() {
+            if str_eq(names[i], targets[j]) {
+                found = true;
+                break;
+            }
+            j += 1;
+        }
+        if !found {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let a = a.as_bytes();
+    let b = b.as_bytes();
+
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::names_subset;
+
+    #[test]
+    fn test_names_subset() {
+        assert!(names_subset(&[], &[]));
+        assert!(names_subset(&[], &["LHR"]));
+        assert!(names_subset(&["LHR"], &["LHR", "LGW"]));
+        assert!(names_subset(&["LGW", "LHR"], &["LHR", "LGW"]));
+        assert!(!names_subset(&["LHR"], &[]));
+        assert!(!names_subset(&["STN"], &["LHR", "LGW"]));
+        assert!(!names_subset(&["lhr"], &["LHR"]));
+        assert!(!names_subset(&["LH"], &["LHR"]));
+    }
+
+    #[test]
+    fn test_names_subset_const() {
+        const SUBSET: bool = names_subset(&["LHR"], &["LHR", "LGW"]);
+        const NOT_SUBSET: bool = names_subset(&["STN"], &["LHR", "LGW"]);
+        assert_eq!([SUBSET, NOT_SUBSET], [true, false]);
+    }
+}
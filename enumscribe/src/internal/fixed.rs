@@ -0,0 +1,36 @@
+//! Utilities for writing variant names into fixed-width byte arrays.
+
+/// Copies `s` into an array of `W` bytes, filling the rest of the array with `pad` if `s` is
+/// shorter than `W` bytes, or cutting `s` short if it is longer.
+///
+/// `s` is only cut short at a character boundary, so if `pad` is ASCII the array always holds
+/// valid UTF-8. If the character which would straddle the end of the array is multibyte, it is
+/// dropped and its bytes are filled with `pad` instead.
+#[inline]
+#[must_use]
+pub fn pad_or_truncate<const W: usize>(s: &str, pad: u8) -> [u8; W] {
+    let mut len = s.len().min(W);
+    while !s.is_char_boundary(len) {
+        len -= 1;
+    }
+
+    let mut buf = [pad; W];
+    buf[..len].copy_from_slice(&s.as_bytes()[..len]);
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pad_or_truncate;
+
+    #[test]
+    fn test_pad_or_truncate() {
+        assert_eq!(pad_or_truncate::<5>("LHR", b' '), *b"LHR  ");
+        assert_eq!(pad_or_truncate::<3>("LHR", b' '), *b"LHR");
+        assert_eq!(pad_or_truncate::<2>("LHR", b' '), *b"LH");
+        assert_eq!(pad_or_truncate::<0>("LHR", b' '), *b"");
+        assert_eq!(pad_or_truncate::<4>("", b'_'), *b"____");
+        assert_eq!(pad_or_truncate::<3>("aé", b' '), *b"a\xc3\xa9");
+        assert_eq!(pad_or_truncate::<2>("aé", b' '), *b"a ");
+    }
+}
@@ -0,0 +1,36 @@
+//! Traits used by `ScribeStaticStr` to look up the string of a variant by its index, if the enum
+//! implements `Copy`.
+//!
+//! This uses autoref specialization in the same way as [autoref](super::autoref): [ViaCopy] is
+//! implemented for [Wrap] when the enum is `Copy`, and [ViaMatch] for `&Wrap`, so calling the
+//! method on a `&Wrap` only finds the [ViaMatch] implementation if the enum is not `Copy`.
+
+/// A reference to the variant being scribed.
+pub struct Wrap<'a, T>(pub &'a T);
+
+/// Gets the index of a variant of a `Copy` enum.
+pub trait ViaCopy<T> {
+    /// Returns the index of the variant, which is given by `to_index`.
+    fn __enumscribe_index(&self, to_index: fn(T) -> usize) -> Option<usize>;
+}
+
+impl<T: Copy> ViaCopy<T> for Wrap<'_, T> {
+    #[inline]
+    fn __enumscribe_index(&self, to_index: fn(T) -> usize) -> Option<usize> {
+        Some(to_index(*self.0))
+    }
+}
+
+/// Falls back to matching on a variant of an enum which is not `Copy`.
+pub trait ViaMatch<T> {
+    /// Returns `None`, since the variant cannot be passed to `to_index` by value.
+    fn __enumscribe_index(&self, to_index: fn(T) -> usize) -> Option<usize>;
+}
+
+impl<T> ViaMatch<T> for &Wrap<'_, T> {
+    #[inline]
+    fn __enumscribe_index(&self, to_index: fn(T) -> usize) -> Option<usize> {
+        let _ = to_index;
+        None
+    }
+}
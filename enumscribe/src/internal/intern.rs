@@ -0,0 +1,48 @@
+//! A process-global string interner, used by generated
+//! [`ScribeInterned`](crate::ScribeInterned) implementations.
+
+use std::collections::HashSet;
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+static INTERNED: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+
+/// Returns a `&'static str` equal to the given string. The first time a string is interned, a copy
+/// of it is leaked; every later call with an equal string returns a reference to that same copy.
+#[must_use]
+pub fn intern(s: &str) -> &'static str {
+    // Nothing can panic while the lock is held except allocation failure, which leaves the set
+    // unchanged, so a poisoned lock is still safe to use.
+    let mut interned = INTERNED
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    if let Some(existing) = interned.get(s) {
+        return existing;
+    }
+
+    let leaked: &'static str = Box::leak(Box::from(s));
+    interned.insert(leaked);
+    leaked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::intern;
+
+    #[test]
+    fn test_intern() {
+        let owned = String::from("enumscribe");
+        let s1 = intern(&owned);
+        let s2 = intern("enumscribe");
+        assert_eq!(s1, "enumscribe");
+        assert!(std::ptr::eq(s1, s2));
+        assert!(!std::ptr::eq(s1, owned.as_str()));
+
+        let s3 = intern("enumscribe_derive");
+        assert_eq!(s3, "enumscribe_derive");
+        assert!(!std::ptr::eq(s1, s3));
+
+        assert_eq!(intern(""), "");
+    }
+}
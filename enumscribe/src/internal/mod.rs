@@ -1,3 +1,29 @@
 //! Utilities for use by code generated by `enumscribe_derive`.
 
+pub mod ascii;
+pub mod autoref;
 pub mod capped_string;
+pub mod extend;
+pub mod index;
+pub mod prefix;
+
+#[cfg(feature = "alloc")]
+pub mod alloc;
+
+#[cfg(feature = "alloc")]
+pub mod cow_string;
+
+#[cfg(feature = "alloc")]
+pub mod rename;
+
+#[cfg(feature = "std")]
+pub mod intern;
+
+#[cfg(feature = "fixed")]
+pub mod fixed;
+
+#[cfg(feature = "phf")]
+pub use phf;
+
+#[cfg(feature = "nfc")]
+pub use unicode_normalization;
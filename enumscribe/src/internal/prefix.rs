@@ -0,0 +1,76 @@
+//! Utilities for matching the start of a string against a variant name.
+
+/// Returns the remainder of `s` after a prefix which is equal to `prefix_upper` when converted to
+/// uppercase, or `None` if there is no such prefix. `prefix_upper` must already be uppercase.
+///
+/// The conversion is performed one character at a time, in the same way as
+/// [`CappedString::uppercase_from_str`](super::capped_string::CappedString::uppercase_from_str),
+/// so the prefix must end on a character boundary of `s` whose uppercase conversion ends exactly
+/// at the end of `prefix_upper`.
+#[inline]
+#[must_use]
+pub fn strip_uppercase_prefix<'a>(s: &'a str, prefix_upper: &str) -> Option<&'a str> {
+    let mut prefix_chars = prefix_upper.chars();
+
+    for (i, c_orig) in s.char_indices() {
+        // Check if the whole prefix has been matched before consuming the next character.
+        if prefix_chars.as_str().is_empty() {
+            return s.get(i..);
+        }
+
+        for c_upper in c_orig.to_uppercase() {
+            if prefix_chars.next() != Some(c_upper) {
+                return None;
+            }
+        }
+    }
+
+    if prefix_chars.as_str().is_empty() {
+        Some("")
+    } else {
+        None
+    }
+}
+
+/// Returns the remainder of `s` after a prefix which is equal to `prefix` when the case of ASCII
+/// letters is ignored, or `None` if there is no such prefix.
+#[inline]
+#[must_use]
+pub fn strip_ascii_prefix<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    match s.get(..prefix.len()) {
+        Some(s_prefix) if s_prefix.eq_ignore_ascii_case(prefix) => Some(&s[prefix.len()..]),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{strip_ascii_prefix, strip_uppercase_prefix};
+
+    #[test]
+    fn test_strip_uppercase_prefix() {
+        assert_eq!(strip_uppercase_prefix("lhr extra", "LHR"), Some(" extra"));
+        assert_eq!(strip_uppercase_prefix("LhR", "LHR"), Some(""));
+        assert_eq!(strip_uppercase_prefix("lh", "LHR"), None);
+        assert_eq!(strip_uppercase_prefix("lgw", "LHR"), None);
+        assert_eq!(strip_uppercase_prefix("", "LHR"), None);
+        assert_eq!(strip_uppercase_prefix("lhr", ""), Some("lhr"));
+        assert_eq!(strip_uppercase_prefix("", ""), Some(""));
+        assert_eq!(strip_uppercase_prefix("großartig", "GROSS"), Some("artig"));
+        assert_eq!(strip_uppercase_prefix("gross", "GROSS"), Some(""));
+        assert_eq!(strip_uppercase_prefix("groß", "GROS"), None);
+        assert_eq!(strip_uppercase_prefix("蟹蟹", "蟹"), Some("蟹"));
+    }
+    #[test]
+    fn test_strip_ascii_prefix() {
+        assert_eq!(strip_ascii_prefix("lhr extra", "LHR"), Some(" extra"));
+        assert_eq!(strip_ascii_prefix("LhR", "lhr"), Some(""));
+        assert_eq!(strip_ascii_prefix("lh", "LHR"), None);
+        assert_eq!(strip_ascii_prefix("", ""), Some(""));
+        assert_eq!(strip_ascii_prefix("gross", "GROSS"), Some(""));
+        assert_eq!(strip_ascii_prefix("großartig", "GROSS"), None);
+        assert_eq!(strip_ascii_prefix("ÉTÉ", "été"), None);
+        assert_eq!(strip_ascii_prefix("été!", "été"), Some("!"));
+        assert_eq!(strip_ascii_prefix("蟹", "\u{e8}"), None);
+    }
+}
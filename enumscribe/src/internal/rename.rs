@@ -0,0 +1,115 @@
+//! Case conversions applied at runtime, matching those used by `rename_all` at compile time.
+
+use ::alloc::string::String;
+
+/// A case which a string can be converted to. Each corresponds to a `rename_all` value.
+#[derive(Clone, Copy, Debug)]
+pub enum Case {
+    /// `lowercase`
+    Lower,
+    /// `UPPERCASE`
+    Upper,
+    /// `PascalCase`
+    Pascal,
+    /// `camelCase`
+    Camel,
+    /// `snake_case`
+    Snake,
+    /// `SCREAMING_SNAKE_CASE`
+    ScreamingSnake,
+    /// `kebab-case`
+    Kebab,
+    /// `SCREAMING-KEBAB-CASE`
+    ScreamingKebab,
+}
+
+/// Converts `s` to the given case, in the same way that `rename_all` converts the name of a
+/// variant. Words are separated by underscores, and by a lowercase letter followed by an uppercase
+/// one.
+#[must_use]
+pub fn rename(s: &str, case: Case) -> String {
+    if let Case::Lower = case {
+        return s.to_lowercase();
+    }
+    if let Case::Upper = case {
+        return s.to_uppercase();
+    }
+
+    let mut converted = String::new();
+    let mut word_start = 0;
+    let mut prev_lower = false;
+
+    for (i, c) in s.char_indices() {
+        if c == '_' {
+            push_word(&mut converted, &s[word_start..i], case);
+            word_start = i + c.len_utf8();
+        } else if prev_lower && c.is_uppercase() {
+            push_word(&mut converted, &s[word_start..i], case);
+            word_start = i;
+        }
+        prev_lower = c.is_lowercase();
+    }
+
+    push_word(&mut converted, &s[word_start..], case);
+    converted
+}
+
+fn push_word(buf: &mut String, word: &str, case: Case) {
+    if word.is_empty() {
+        return;
+    }
+
+    match case {
+        Case::Camel if buf.is_empty() => buf.push_str(&word.to_lowercase()),
+        Case::Pascal | Case::Camel => {
+            let mut chars = word.chars();
+            if let Some(head) = chars.next() {
+                buf.extend(head.to_uppercase());
+                buf.push_str(&chars.as_str().to_lowercase());
+            }
+        },
+        Case::Snake | Case::Kebab | Case::ScreamingSnake | Case::ScreamingKebab => {
+            if !buf.is_empty() {
+                buf.push(if let Case::Snake | Case::ScreamingSnake = case { '_' } else { '-' });
+            }
+            if let Case::Snake | Case::Kebab = case {
+                buf.push_str(&word.to_lowercase());
+            } else {
+                buf.push_str(&word.to_uppercase());
+            }
+        },
+        Case::Lower | Case::Upper => buf.push_str(word),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{rename, Case};
+
+    #[test]
+    fn test_rename() {
+        for s in &["", "foo", "fooBaa", "FooBaa", "foo_baa", "FOO_BAA"] {
+            assert_eq!(rename(s, Case::Lower), s.to_lowercase());
+            assert_eq!(rename(s, Case::Upper), s.to_uppercase());
+        }
+
+        let cases = [
+            ("", "", "", "", "", "", ""),
+            ("foo", "Foo", "foo", "foo", "FOO", "foo", "FOO"),
+            ("fooBaa", "FooBaa", "fooBaa", "foo_baa", "FOO_BAA", "foo-baa", "FOO-BAA"),
+            ("FooBaa", "FooBaa", "fooBaa", "foo_baa", "FOO_BAA", "foo-baa", "FOO-BAA"),
+            ("foo_baa", "FooBaa", "fooBaa", "foo_baa", "FOO_BAA", "foo-baa", "FOO-BAA"),
+            ("FOO_BAA", "FooBaa", "fooBaa", "foo_baa", "FOO_BAA", "foo-baa", "FOO-BAA"),
+            ("_foo__Baa_", "FooBaa", "fooBaa", "foo_baa", "FOO_BAA", "foo-baa", "FOO-BAA"),
+        ];
+
+        for (s, pascal, camel, snake, screaming_snake, kebab, screaming_kebab) in &cases {
+            assert_eq!(rename(s, Case::Pascal), *pascal);
+            assert_eq!(rename(s, Case::Camel), *camel);
+            assert_eq!(rename(s, Case::Snake), *snake);
+            assert_eq!(rename(s, Case::ScreamingSnake), *screaming_snake);
+            assert_eq!(rename(s, Case::Kebab), *kebab);
+            assert_eq!(rename(s, Case::ScreamingKebab), *screaming_kebab);
+        }
+    }
+}
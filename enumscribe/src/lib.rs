@@ -24,7 +24,10 @@
 //! ```
 //!
 //! The `#[enumscribe(str = "...")]` allows us to specify what string should be used to represent a
-//! particular variant. If this is omitted, the name of the variant will be used instead.
+//! particular variant. If this is omitted, the name of the variant will be used instead. Any
+//! string literal given to an attribute may also be written as a call to `concat!` or
+//! `stringify!`, such as `#[enumscribe(str = concat!("L", "HR"))]`, which is evaluated when the
+//! derive macro is expanded. This is useful when the enum is generated by another macro.
 //!
 //! The `#[enumscribe(case_insensitive)]` attribute can be used to make the "Unscribe" traits
 //! perform case-insensitive matching for a variant:
@@ -61,6 +64,9 @@
 //! assert_eq!(Website::try_unscribe("CrAtEs.Io"), Some(Website::CratesDotIo));
 //! ```
 //!
+//! If the names are all ASCII, `#[enumscribe(case_insensitive = "ascii")]` on the enum compares
+//! strings with `str::eq_ignore_ascii_case` instead, which avoids converting them to uppercase.
+//!
 //! You can also have a variant which stores strings that could not be matched to any other
 //! variant. This is done using the `#[enumscribe(other)]` attribute. The variant should have a
 //! single field, which is a `String`.
@@ -122,6 +128,10 @@
 //! assert_eq!(Airport::Luton.try_scribe(), Some("LTN"));
 //! ```
 //!
+//! Fields of type `PhantomData` hold no data, so they do not need to be ignored. A variant whose
+//! only fields are `PhantomData` is scribed like a unit variant, and is constructed with
+//! `PhantomData` when unscribing, which is useful for generic marker enums.
+//!
 //! You can derive [`serde::Serialize`](https://docs.serde.rs/serde/trait.Serialize.html) and
 //! [`serde::Deserialize`](https://docs.serde.rs/serde/trait.Deserialize.html) using the same
 //! syntax:
@@ -165,6 +175,52 @@
 //! # }
 //! ```
 //!
+//! If the names of an enum's variants must match an external specification, you can list the
+//! expected names with `#[enumscribe(expect_names("...", ...))]`. Deriving any of the traits will
+//! then cause a compile-time error if the names of the variants (other than `ignore`, `other` and
+//! `fmt_fields` variants) are not exactly the listed names, in any order. This catches the enum and the
+//! specification drifting apart.
+//!
+//! ```
+//! use enumscribe::ScribeStaticStr;
+//!
+//! #[derive(ScribeStaticStr)]
+//! #[enumscribe(expect_names("LGW", "LHR", "LTN"))]
+//! enum Airport {
+//!     #[enumscribe(str = "LHR")]
+//!     Heathrow,
+//!     #[enumscribe(str = "LGW")]
+//!     Gatwick,
+//!     #[enumscribe(str = "LTN")]
+//!     Luton,
+//! }
+//! ```
+//!
+//! Similarly, `#[enumscribe(require_str)]` causes a compile-time error if any variant (other than
+//! `ignore` and `other` variants) does not have an explicit `#[enumscribe(str = "...")]`, so that
+//! renaming a variant in Rust cannot silently change its string. Variants of an enum with
+//! `rename_all`, or with their own `rename`, are exempt, since their names are still derived
+//! deliberately.
+//!
+//! `#[enumscribe(lint)]` turns attributes which are likely to be mistakes into compile-time
+//! errors. Since derive macros cannot emit warnings, these are opt-in. Currently, this rejects a
+//! variant marked `#[enumscribe(case_insensitive)]` when none of its names have any cased
+//! characters, such as `"404"` or `"->"`, as matching them case-insensitively has no effect. With
+//! `case_insensitive = "ascii"`, names must have an ASCII letter, so `"é"` is also rejected. It
+//! also rejects case-sensitive variants with cased characters in their names when the `other`
+//! variant has a `norm_field`, since strings which only differ from such a name by case are stored
+//! in `other` with the same lowercase form as the name.
+//!
+//! The derive macros may be used on enums marked `#[non_exhaustive]`. Since a derive macro is
+//! always expanded in the crate which defines the enum, the generated code can match on every
+//! variant without needing a wildcard arm. Note that it is not possible to derive these traits for
+//! an enum from another crate, `#[non_exhaustive]` or otherwise.
+//!
+//! The generated code refers to this crate as `::enumscribe`. If you depend on it under a
+//! different name (for example with `package = "enumscribe"` in `Cargo.toml`), or re-export it
+//! from another crate, annotate the enum with `#[enumscribe(crate = "path::to::enumscribe")]` to
+//! use that path instead.
+//!
 //! Here is a table to show which traits you should derive for your enum:
 //!
 //! | `ignore` used? | `other` used? | Conversion to string | Conversion from string |
@@ -179,19 +235,27 @@
 //! `String` rather than a `Cow<'static, str>`, so they will always perform an allocation.
 //! Therefore, you should prefer the `ScribeCowStr` traits over the `ScribeString` traits, unless
 //! you *really* don't want to use a `Cow` for whatever reason.
+//! Likewise, [ScribeBoxStr] and [TryScribeBoxStr] produce a `Box<str>`, which is smaller than a
+//! `String` and so may be preferable for strings which are stored for a long time.
 
 #![deny(missing_docs)]
 #![deny(unsafe_op_in_unsafe_fn)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub mod internal;
 
+pub use internal::capped_string::CappedString;
+
 extern crate enumscribe_derive;
 
 pub use enumscribe_derive::*;
 
-#[cfg(feature = "std")]
-use std::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, boxed::Box, string::String, vec::Vec};
+use core::fmt;
 
 /// Trait for converting an enum to a static string slice.
 ///
@@ -230,6 +294,139 @@ pub trait ScribeStaticStr {
     /// `#[enumscribe(str = "...")]` attribute, or the name of the variant if the attribute
     /// is omitted.
     fn scribe(&self) -> &'static str;
+
+    /// Converts this enum to a `&'static str` in the same way as [ScribeStaticStr::scribe], and
+    /// also returns whether the variant is matched case-insensitively when unscribing, i.e.
+    /// whether it is annotated with `#[enumscribe(case_insensitive)]` (either directly or through
+    /// the enum).
+    ///
+    /// This can be used to document which names are case-insensitive. Since this trait cannot be
+    /// derived for enums with `ignore` or `other` variants, every variant has a name and a flag.
+    ///
+    /// ```
+    /// use enumscribe::ScribeStaticStr;
+    ///
+    /// #[derive(ScribeStaticStr)]
+    /// enum Airport {
+    ///     #[enumscribe(str = "LHR", case_insensitive)]
+    ///     Heathrow,
+    ///     #[enumscribe(str = "LGW")]
+    ///     Gatwick,
+    /// }
+    ///
+    /// assert_eq!(Airport::Heathrow.scribe_with_ci(), ("LHR", true));
+    /// assert_eq!(Airport::Gatwick.scribe_with_ci(), ("LGW", false));
+    /// ```
+    fn scribe_with_ci(&self) -> (&'static str, bool);
+}
+
+/// Trait for converting an enum to a static byte slice.
+///
+/// Like all of the traits provided by enumscribe, this should not be implemented manually; use
+/// [`#[derive(ScribeStaticBytes)]`](derive.ScribeStaticBytes.html) provided by the
+/// [enumscribe_derive] crate instead.
+///
+/// The bytes returned for a variant are the UTF-8 encoding of the string [ScribeStaticStr] would
+/// return for it, which is useful when writing the enum to a byte buffer. The same restrictions
+/// as [ScribeStaticStr] apply, so none of the enum's variants may use `ignore` or `other`.
+///
+/// ```
+/// use enumscribe::ScribeStaticBytes;
+///
+/// #[derive(ScribeStaticBytes, PartialEq, Eq, Debug)]
+/// enum Airport {
+///     #[enumscribe(str = "LHR")]
+///     Heathrow,
+///     #[enumscribe(str = "LGW")]
+///     Gatwick,
+///     UnnamedAirport,
+/// }
+///
+/// assert_eq!(Airport::Heathrow.scribe_bytes(), b"LHR");
+/// assert_eq!(Airport::Gatwick.scribe_bytes(), b"LGW");
+/// assert_eq!(Airport::UnnamedAirport.scribe_bytes(), b"UnnamedAirport");
+/// ```
+pub trait ScribeStaticBytes {
+    /// Converts this enum to a `&'static [u8]`.
+    ///
+    /// The bytes returned for a particular variant are the UTF-8 encoding of the string given by
+    /// the `#[enumscribe(str = "...")]` attribute, or the name of the variant if the attribute is
+    /// omitted.
+    fn scribe_bytes(&self) -> &'static [u8];
+}
+
+/// Trait for converting an enum to a byte slice, which may be borrowed from the enum's `other`
+/// variant.
+///
+/// Like all of the traits provided by enumscribe, this should not be implemented manually; use
+/// [`#[derive(ScribeBytes)]`](derive.ScribeBytes.html) provided by the [enumscribe_derive]
+/// crate instead.
+///
+/// This is the counterpart of [UnscribeBytes] for binary protocols. Named variants are converted
+/// to the UTF-8 encoding of their names, as with [ScribeStaticBytes], but the enum may also have a
+/// variant marked with `#[enumscribe(other)]`, whose field is returned as it is. The field must
+/// implement `AsRef<[u8]>`, so it may be a `Vec<u8>` holding bytes which are not valid UTF-8.
+///
+/// ```
+/// use enumscribe::ScribeBytes;
+///
+/// #[derive(ScribeBytes, PartialEq, Eq, Debug)]
+/// enum Airport {
+///     #[enumscribe(str = "LHR")]
+///     Heathrow,
+///     #[enumscribe(other)]
+///     Other(Vec<u8>),
+/// }
+///
+/// assert_eq!(Airport::Heathrow.scribe_bytes(), b"LHR");
+/// assert_eq!(Airport::Other(b"\xffSTN".to_vec()).scribe_bytes(), b"\xffSTN");
+/// ```
+pub trait ScribeBytes {
+    /// Converts this enum to a `&[u8]`.
+    ///
+    /// The bytes returned for a named variant are the UTF-8 encoding of the string given by the
+    /// `#[enumscribe(str = "...")]` attribute, or the name of the variant if the attribute is
+    /// omitted. The bytes returned for the `other` variant are the bytes stored in its field.
+    fn scribe_bytes(&self) -> &[u8];
+}
+
+/// Trait for converting from bytes to an enum, storing any bytes which do not match a named
+/// variant in its `other` variant.
+///
+/// Like all of the traits provided by enumscribe, this should not be implemented manually; use
+/// [`#[derive(UnscribeBytes)]`](derive.UnscribeBytes.html) provided by the [enumscribe_derive]
+/// crate instead.
+///
+/// Unlike [TryUnscribe::try_unscribe_bytes], the bytes never need to be valid UTF-8, so the enum
+/// must have a variant marked with `#[enumscribe(other)]` whose field can be created from a
+/// `&[u8]`, such as a `Vec<u8>`. Bytes which do not match any named variant, including bytes which
+/// are not valid UTF-8, are stored in it.
+///
+/// ```
+/// use enumscribe::UnscribeBytes;
+///
+/// #[derive(UnscribeBytes, PartialEq, Eq, Debug)]
+/// enum Airport {
+///     #[enumscribe(str = "LHR", case_insensitive)]
+///     Heathrow,
+///     #[enumscribe(str = "LGW")]
+///     Gatwick,
+///     #[enumscribe(other)]
+///     Other(Vec<u8>),
+/// }
+///
+/// assert_eq!(Airport::unscribe_bytes(b"lhr"), Airport::Heathrow);
+/// assert_eq!(Airport::unscribe_bytes(b"LGW"), Airport::Gatwick);
+/// assert_eq!(Airport::unscribe_bytes(b"lgw"), Airport::Other(b"lgw".to_vec()));
+/// assert_eq!(Airport::unscribe_bytes(b"\xff\xfe"), Airport::Other(vec![0xff, 0xfe]));
+/// ```
+pub trait UnscribeBytes: Sized {
+    /// Converts the given bytes to an enum variant.
+    ///
+    /// The bytes are matched against the UTF-8 encoding of the `#[enumscribe(str = "...")]`
+    /// attribute for each variant to determine which variant to return. If there was no successful
+    /// match, the variant marked with `#[enumscribe(other)]` will be returned instead.
+    fn unscribe_bytes(to_unscribe: &[u8]) -> Self;
 }
 
 /// Trait for converting an enum to a static string slice, or `None` if the conversion fails.
@@ -243,7 +440,8 @@ pub trait ScribeStaticStr {
 /// of the variant will be used instead.
 ///
 /// You may also annotate a variant with `#[enumscribe(ignore)]`, in which case attempting to
-/// convert the variant to a string will always result in `None`.
+/// convert the variant to a string will always result in `None`, unless it is given a placeholder
+/// string with `#[enumscribe(ignore, placeholder = "...")]`.
 ///
 /// This trait can only be used if none of the enum's variants use `other`. If you have variants
 /// that use `other`, use [TryScribeCowStr] instead.
@@ -299,7 +497,7 @@ pub trait TryScribeStaticStr {
 /// assert_eq!(Airport::Gatwick.scribe(), "LGW".to_owned());
 /// assert_eq!(Airport::Other("STN".to_owned()).scribe(), "STN".to_owned());
 /// ```
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 pub trait ScribeString {
     /// Converts this enum to an allocated `String`.
     ///
@@ -335,7 +533,7 @@ pub trait ScribeString {
 /// assert_eq!(Airport::Gatwick.try_scribe(), Some("LGW".to_owned()));
 /// assert_eq!(Airport::Other("STN".to_owned()).try_scribe(), Some("STN".to_owned()));
 /// ```
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 pub trait TryScribeString {
     /// Converts this enum to an allocated `String`.
     ///
@@ -348,6 +546,83 @@ pub trait TryScribeString {
     fn try_scribe(&self) -> Option<String>;
 }
 
+/// Trait for converting an enum to a boxed string slice. This is useful for storing the string
+/// for a long time, since a `Box<str>` is smaller than a `String` and does not need to be matched
+/// on like a `Cow`, but [ScribeCowStr] should otherwise be preferred because it avoids
+/// unnecessary allocations.
+///
+/// Like all of the traits provided by enumscribe, this should not be implemented manually; use
+/// [`#[derive(ScribeBoxStr)]`](derive.ScribeBoxStr.html) provided by the
+/// [enumscribe_derive] crate instead.
+///
+/// This trait can only be used if none of the enum's variants use `ignore`.
+///
+/// ```
+/// use enumscribe::ScribeBoxStr;
+///
+/// #[derive(ScribeBoxStr, PartialEq, Eq, Debug)]
+/// enum Airport {
+///     #[enumscribe(str = "LHR")]
+///     Heathrow,
+///     #[enumscribe(str = "LGW")]
+///     Gatwick,
+///     #[enumscribe(other)]
+///     Other(String),
+/// }
+///
+/// assert_eq!(Airport::Heathrow.scribe(), Box::from("LHR"));
+/// assert_eq!(Airport::Gatwick.scribe(), Box::from("LGW"));
+/// assert_eq!(Airport::Other("STN".to_owned()).scribe(), Box::from("STN"));
+/// ```
+#[cfg(feature = "alloc")]
+pub trait ScribeBoxStr {
+    /// Converts this enum to a `Box<str>`.
+    ///
+    /// When called on a variant marked with `#[enumscribe(other)]`, the variant's field will be
+    /// returned. For other variants, the string returned is determined by the
+    /// `#[enumscribe(str = "...")]` attribute, or the name of the variant if the attribute is
+    /// omitted.
+    fn scribe(&self) -> Box<str>;
+}
+
+/// Trait for converting an enum to a boxed string slice, or `None` if the conversion fails.
+/// Generally, [TryScribeCowStr] should be preferred over this trait because it avoids unnecessary
+/// allocations.
+///
+/// Like all of the traits provided by enumscribe, this should not be implemented manually; use
+/// [`#[derive(TryScribeBoxStr)]`](derive.TryScribeBoxStr.html) provided by the
+/// [enumscribe_derive] crate instead.
+///
+/// ```
+/// use enumscribe::TryScribeBoxStr;
+///
+/// #[derive(TryScribeBoxStr, PartialEq, Eq, Debug)]
+/// enum Airport {
+///     #[enumscribe(ignore)]
+///     Heathrow,
+///     #[enumscribe(str = "LGW")]
+///     Gatwick,
+///     #[enumscribe(other)]
+///     Other(String),
+/// }
+///
+/// assert_eq!(Airport::Heathrow.try_scribe(), None);
+/// assert_eq!(Airport::Gatwick.try_scribe(), Some(Box::from("LGW")));
+/// assert_eq!(Airport::Other("STN".to_owned()).try_scribe(), Some(Box::from("STN")));
+/// ```
+#[cfg(feature = "alloc")]
+pub trait TryScribeBoxStr {
+    /// Converts this enum to a `Box<str>`.
+    ///
+    /// Calling this method on a variant marked with `#[enumscribe(ignore)]` will return `None`.
+    ///
+    /// When called on a variant marked with `#[enumscribe(other)]`, the variant's field will be
+    /// returned. For other variants, the string returned is determined by the
+    /// `#[enumscribe(str = "...")]` attribute, or the name of the variant if the attribute is
+    /// omitted.
+    fn try_scribe(&self) -> Option<Box<str>>;
+}
+
 /// Trait for converting an enum to a clone-on-write string.
 ///
 /// Like all of the traits provided by enumscribe, this should not be implemented manually; use
@@ -387,7 +662,7 @@ pub trait TryScribeString {
 /// assert_eq!(Airport::Other("STN".to_owned()).scribe(),
 ///            Cow::Owned::<'static, str>("STN".to_owned()));
 /// ```
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 pub trait ScribeCowStr {
     /// Converts this enum to a `Cow<'static, str>`.
     ///
@@ -398,6 +673,225 @@ pub trait ScribeCowStr {
     fn scribe(&self) -> Cow<'static, str>;
 }
 
+/// Trait for converting an enum to a lowercase or uppercase clone-on-write string.
+///
+/// Like all of the traits provided by enumscribe, this should not be implemented manually; use
+/// [`#[derive(ScribeCasedCowStr)]`](derive.ScribeCasedCowStr.html) provided by the
+/// [enumscribe_derive] crate instead.
+///
+/// This produces the same strings as [ScribeCowStr], converted to lowercase or uppercase. This
+/// avoids having to choose a single casing for each variant's `#[enumscribe(str = "...")]`. The
+/// conversion is performed at compile-time for named variants, so they will always return a
+/// `Cow::Borrowed`. The field of an `other` variant is converted at runtime, so it will return a
+/// `Cow::Owned`; the field must implement `AsRef<str>`.
+///
+/// This trait can only be used if none of the enum's variants use `ignore`.
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// use enumscribe::ScribeCasedCowStr;
+///
+/// #[derive(ScribeCasedCowStr, PartialEq, Eq, Debug)]
+/// enum Airport {
+///     #[enumscribe(str = "Heathrow")]
+///     Heathrow,
+///     #[enumscribe(other)]
+///     Other(String),
+/// }
+///
+/// assert_eq!(Airport::Heathrow.scribe_lower(), Cow::Borrowed("heathrow"));
+/// assert_eq!(Airport::Heathrow.scribe_upper(), Cow::Borrowed("HEATHROW"));
+/// assert_eq!(Airport::Other("Stansted".to_owned()).scribe_upper(),
+///            Cow::Owned::<'static, str>("STANSTED".to_owned()));
+/// ```
+#[cfg(feature = "alloc")]
+pub trait ScribeCasedCowStr {
+    /// Converts this enum to a lowercase `Cow<'static, str>`.
+    ///
+    /// The lowercase conversion is the same as the one performed by `str::to_lowercase`.
+    fn scribe_lower(&self) -> Cow<'static, str>;
+
+    /// Converts this enum to an uppercase `Cow<'static, str>`.
+    ///
+    /// The uppercase conversion is the same as the one performed by `str::to_uppercase`.
+    fn scribe_upper(&self) -> Cow<'static, str>;
+}
+
+/// Object-safe trait for converting a value to a clone-on-write string, which allows different
+/// enums to be scribed through a trait object such as `Box<dyn DynScribe>`.
+///
+/// This trait is implemented for every type implementing [ScribeCowStr], so it should not be
+/// implemented or derived manually.
+///
+/// ```
+/// use enumscribe::{DynScribe, ScribeCowStr};
+///
+/// #[derive(ScribeCowStr)]
+/// enum Airport {
+///     #[enumscribe(str = "LHR")]
+///     Heathrow,
+/// }
+///
+/// #[derive(ScribeCowStr)]
+/// enum Website {
+///     #[enumscribe(str = "github.com")]
+///     Github,
+///     #[enumscribe(other)]
+///     Other(String),
+/// }
+///
+/// let places: Vec<Box<dyn DynScribe>> = vec![
+///     Box::new(Airport::Heathrow),
+///     Box::new(Website::Github),
+///     Box::new(Website::Other("crates.io".to_owned())),
+/// ];
+///
+/// let scribed = places.iter().map(|place| place.scribe_dyn()).collect::<Vec<_>>();
+/// assert_eq!(scribed, ["LHR", "github.com", "crates.io"]);
+/// ```
+#[cfg(feature = "alloc")]
+pub trait DynScribe {
+    /// Converts this value to a `Cow<'static, str>`, in the same way as [ScribeCowStr::scribe].
+    fn scribe_dyn(&self) -> Cow<'static, str>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T> DynScribe for T
+where
+    T: ScribeCowStr + ?Sized,
+{
+    #[inline]
+    fn scribe_dyn(&self) -> Cow<'static, str> {
+        self.scribe()
+    }
+}
+
+/// Converts each of the given enums to a string with [ScribeCowStr], and joins the strings
+/// together with `sep` between each of them.
+///
+/// This is useful for building delimiter-separated lists of enums without having to collect the
+/// intermediate strings first.
+///
+/// ```
+/// use enumscribe::{scribe_join, ScribeCowStr};
+///
+/// #[derive(ScribeCowStr)]
+/// enum Airport {
+///     #[enumscribe(str = "LHR")]
+///     Heathrow,
+///     #[enumscribe(str = "LGW")]
+///     Gatwick,
+///     #[enumscribe(str = "LTN")]
+///     Luton,
+/// }
+///
+/// let airports = [Airport::Heathrow, Airport::Gatwick, Airport::Luton];
+/// assert_eq!(scribe_join(&airports, ","), "LHR,LGW,LTN");
+/// ```
+#[cfg(feature = "std")]
+pub fn scribe_join<T>(items: &[T], sep: &str) -> String
+where
+    T: ScribeCowStr,
+{
+    let mut buf = String::new();
+    for (i, item) in items.iter().enumerate() {
+        if i > 0 {
+            buf.push_str(sep);
+        }
+        buf.push_str(&item.scribe());
+    }
+    buf
+}
+
+/// Converts an enum to a string, using either [ScribeStaticStr] or [ScribeCowStr] without needing
+/// either trait to be imported.
+///
+/// `scribe!(value)` borrows `value` and expands to the equivalent of `value.scribe()`, where the
+/// trait is chosen as follows:
+///
+/// - If the type of `value` implements [ScribeStaticStr], it is used and the result is a
+///   `&'static str`. This is the case even if the type also implements [ScribeCowStr].
+/// - Otherwise, if it implements [ScribeCowStr], it is used and the result is a
+///   `Cow<'static, str>`. This requires the `alloc` feature.
+/// - Otherwise, compilation fails.
+///
+/// The choice is made where the macro is used, so the type of `value` must be known there; it
+/// cannot be used on a value of a generic type `T` to pick a trait based on the bounds of `T`.
+/// Unlike a method call, `value` is not automatically dereferenced, so it must be the enum itself
+/// rather than a reference to it (use `scribe!(*value)` for a reference).
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// use enumscribe::{scribe, ScribeCowStr, ScribeStaticStr};
+///
+/// #[derive(ScribeStaticStr)]
+/// enum Airport {
+///     #[enumscribe(str = "LHR")]
+///     Heathrow,
+/// }
+///
+/// #[derive(ScribeCowStr)]
+/// enum Website {
+///     #[enumscribe(str = "github.com")]
+///     Github,
+///     #[enumscribe(other)]
+///     Other(String),
+/// }
+///
+/// let airport: &'static str = scribe!(Airport::Heathrow);
+/// let website: Cow<'static, str> = scribe!(Website::Other("crates.io".to_owned()));
+/// assert_eq!(airport, "LHR");
+/// assert_eq!(website, "crates.io");
+/// assert_eq!(format!("{} {}", scribe!(Airport::Heathrow), scribe!(Website::Github)),
+///            "LHR github.com");
+/// ```
+#[macro_export]
+macro_rules! scribe {
+    ($value:expr $(,)?) => {{
+        #[allow(unused_imports)]
+        use $crate::internal::autoref::{ViaScribeCowStr as _, ViaScribeStaticStr as _};
+        (&$crate::internal::autoref::Wrap(&$value)).__enumscribe_scribe()
+    }};
+}
+
+/// Trait for converting an enum to a clone-on-write string, checking that the string would be
+/// unscribed to the same variant.
+///
+/// This trait is implemented by [`#[derive(ScribeCowStr)]`](derive.ScribeCowStr.html) when the
+/// enum is annotated with `#[enumscribe(scribe_checked)]`, so it should not be implemented or
+/// derived manually.
+///
+/// If the string stored in an `other` variant is equal to the string of a named variant, it will
+/// be unscribed to the named variant rather than the `other` variant, so the enum would not
+/// survive being converted to a string and back. [ScribeChecked::scribe_checked] detects this.
+///
+/// ```
+/// use enumscribe::{OtherShadowsNamed, ScribeChecked, ScribeCowStr};
+///
+/// #[derive(ScribeCowStr)]
+/// #[enumscribe(scribe_checked)]
+/// enum Airport {
+///     #[enumscribe(str = "LHR", case_insensitive)]
+///     Heathrow,
+///     #[enumscribe(other)]
+///     Other(String),
+/// }
+///
+/// assert_eq!(Airport::Heathrow.scribe_checked().as_deref(), Ok("LHR"));
+/// assert_eq!(Airport::Other("STN".to_owned()).scribe_checked().as_deref(), Ok("STN"));
+/// assert_eq!(Airport::Other("lhr".to_owned()).scribe_checked(), Err(OtherShadowsNamed));
+/// ```
+#[cfg(feature = "alloc")]
+pub trait ScribeChecked: ScribeCowStr {
+    /// Converts this enum to a `Cow<'static, str>` in the same way as [ScribeCowStr::scribe].
+    ///
+    /// If this is a variant marked with `#[enumscribe(other)]` and the resulting string would be
+    /// unscribed to one of the named variants, [OtherShadowsNamed] is returned instead.
+    fn scribe_checked(&self) -> Result<Cow<'static, str>, OtherShadowsNamed>;
+}
+
 /// Trait for converting an enum to a clone-on-write string, or `None` if the conversion fails.
 ///
 /// Like all of the traits provided by enumscribe, this should not be implemented manually; use
@@ -413,7 +907,8 @@ pub trait ScribeCowStr {
 /// will result in whatever the value of its field is.
 ///
 /// You may also annotate a variant with `#[enumscribe(ignore)]`, in which case attempting to
-/// convert the variant to a string will always result in `None`.
+/// convert the variant to a string will always result in `None`, unless it is given a placeholder
+/// string with `#[enumscribe(ignore, placeholder = "...")]`.
 ///
 /// ```
 /// use std::borrow::Cow;
@@ -437,7 +932,7 @@ pub trait ScribeCowStr {
 /// assert_eq!(Airport::Other("STN".to_owned()).try_scribe(),
 ///            Some(Cow::Owned::<'static, str>("STN".to_owned())));
 /// ```
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 pub trait TryScribeCowStr {
     /// Converts this enum to a `Option<Cow<'static, str>>`.
     ///
@@ -448,34 +943,157 @@ pub trait TryScribeCowStr {
     /// a static string slice determined by the `#[enumscribe(str = "...")]` attribute, or the name
     /// of the variant if the attribute is omitted.
     fn try_scribe(&self) -> Option<Cow<'static, str>>;
+
+    /// Converts this enum to a `Cow<'static, str>` in the same way as
+    /// [try_scribe](TryScribeCowStr::try_scribe), but returns a [ScribeError] describing why the
+    /// variant could not be converted instead of `None`.
+    ///
+    /// Currently the only reason is that the variant is marked with `#[enumscribe(ignore)]`, in
+    /// which case [ScribeError::Ignored] is returned.
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    ///
+    /// use enumscribe::{ScribeError, TryScribeCowStr};
+    ///
+    /// #[derive(TryScribeCowStr)]
+    /// enum Airport {
+    ///     #[enumscribe(ignore)]
+    ///     Heathrow,
+    ///     #[enumscribe(str = "LGW")]
+    ///     Gatwick,
+    /// }
+    ///
+    /// assert_eq!(Airport::Heathrow.try_scribe_result(), Err(ScribeError::Ignored));
+    /// assert_eq!(Airport::Gatwick.try_scribe_result(), Ok(Cow::Borrowed("LGW")));
+    /// ```
+    fn try_scribe_result(&self) -> Result<Cow<'static, str>, ScribeError> {
+        self.try_scribe().ok_or(ScribeError::Ignored)
+    }
 }
 
-/// Trait for converting from a string to an enum.
+/// Trait for converting an enum to a static string slice, including variants whose string is
+/// only known at runtime.
 ///
 /// Like all of the traits provided by enumscribe, this should not be implemented manually; use
-/// [`#[derive(Unscribe)]`](derive.Unscribe.html) provided by the
+/// [`#[derive(ScribeInterned)]`](derive.ScribeInterned.html) provided by the
 /// [enumscribe_derive] crate instead.
 ///
-/// When deriving this trait, you may specify the string that should map to a particular variant
-/// by annotating it with `#[enumscribe(str = "foo")]`. If this is omitted, the name of the variant
-/// will be used instead.
-///
-/// Annotating a variant with `#[enumscribe(case_insensitive)]` will cause case insensitive matching
-/// to be used for that variant. If it is omitted, matching will be case sensitive.
-///
-/// For this trait to be derived, there must be a variant marked with `#[enumscribe(other)]`. This
-/// variant will be used to store strings that could not be matched to any other variant. It must
-/// have a single field, which should have type `String`. If you do not have such a variant, try
-/// deriving [TryUnscribe] instead.
+/// Unlike [ScribeStaticStr], this may be derived for enums with a variant marked with
+/// `#[enumscribe(other)]`. The string stored in such a variant is interned in a table shared by
+/// the whole process, which is never freed. **Every distinct string which is interned is leaked
+/// for the rest of the life of the process**, so this should only be used when the number of
+/// distinct strings is known to be small, and never for strings from untrusted input.
 ///
 /// ```
-/// use enumscribe::Unscribe;
+/// use enumscribe::ScribeInterned;
 ///
-/// #[derive(Unscribe, PartialEq, Eq, Debug)]
+/// #[derive(ScribeInterned)]
 /// enum Airport {
-///     #[enumscribe(str = "LHR", case_insensitive)]
+///     #[enumscribe(str = "LHR")]
 ///     Heathrow,
-///     #[enumscribe(str = "LGW")]
+///     #[enumscribe(other)]
+///     Other(String),
+/// }
+///
+/// assert_eq!(Airport::Heathrow.scribe_interned(), "LHR");
+///
+/// let stn_1: &'static str = Airport::Other("STN".to_owned()).scribe_interned();
+/// let stn_2: &'static str = Airport::Other("STN".to_owned()).scribe_interned();
+/// assert_eq!(stn_1, "STN");
+/// assert!(std::ptr::eq(stn_1, stn_2));
+/// ```
+#[cfg(feature = "std")]
+pub trait ScribeInterned {
+    /// Converts this enum to a `&'static str`.
+    ///
+    /// When called on a variant marked with `#[enumscribe(other)]`, the variant's field is
+    /// interned, and a reference to the interned copy is returned. Equal strings always return
+    /// the same reference. For other variants, the string is determined by the
+    /// `#[enumscribe(str = "...")]` attribute, or the name of the variant if the attribute is
+    /// omitted, and nothing is interned.
+    fn scribe_interned(&self) -> &'static str;
+}
+
+/// Trait for writing an enum's name into a fixed-width byte array, for use in formats made of
+/// fixed-width records. This requires the `fixed` feature.
+///
+/// Like all of the traits provided by enumscribe, this should not be implemented manually; use
+/// [`#[derive(ScribeFixed)]`](derive.ScribeFixed.html) provided by the
+/// [enumscribe_derive] crate instead.
+///
+/// The name of each variant is determined in the same way as for [ScribeStaticStr], and variants
+/// marked with `#[enumscribe(other)]` use their field. Names shorter than the array are padded
+/// with spaces, or with the ASCII character given by `#[enumscribe(pad = '...')]` on the enum.
+/// Names longer than the array are cut short, but never in the middle of a character.
+///
+/// ```
+/// use enumscribe::ScribeFixed;
+///
+/// #[derive(ScribeFixed)]
+/// #[enumscribe(pad = '_')]
+/// enum Airport {
+///     #[enumscribe(str = "LHR")]
+///     Heathrow,
+///     #[enumscribe(other)]
+///     Other(String),
+/// }
+///
+/// assert_eq!(&Airport::Heathrow.scribe_fixed::<5>(), b"LHR__");
+/// assert_eq!(&Airport::Other("Stansted".to_owned()).scribe_fixed::<5>(), b"Stans");
+/// ```
+#[cfg(feature = "fixed")]
+pub trait ScribeFixed {
+    /// Writes the name of this enum into an array of `W` bytes, padding or truncating it to fit.
+    fn scribe_fixed<const W: usize>(&self) -> [u8; W];
+}
+
+/// Trait for converting from a string to an enum.
+///
+/// Like all of the traits provided by enumscribe, this should not be implemented manually; use
+/// [`#[derive(Unscribe)]`](derive.Unscribe.html) provided by the
+/// [enumscribe_derive] crate instead.
+///
+/// When deriving this trait, you may specify the string that should map to a particular variant
+/// by annotating it with `#[enumscribe(str = "foo")]`. If this is omitted, the name of the variant
+/// will be used instead.
+///
+/// Annotating a variant with `#[enumscribe(case_insensitive)]` will cause case insensitive matching
+/// to be used for that variant. If it is omitted, matching will be case sensitive.
+///
+/// For this trait to be derived, there must be a variant marked with `#[enumscribe(other)]`. This
+/// variant will be used to store strings that could not be matched to any other variant. It must
+/// have a single field, which should have type `String`. If you do not have such a variant, try
+/// deriving [TryUnscribe] instead.
+///
+/// Alternatively, the `other` variant may have two named `String` fields, specified with
+/// `#[enumscribe(other, raw_field = "...", norm_field = "...")]`. The `raw_field` will store the
+/// string verbatim, and the `norm_field` will store a lowercase copy of it. When scribing, the
+/// `raw_field` is used.
+///
+/// ```
+/// use enumscribe::Unscribe;
+///
+/// #[derive(Unscribe, PartialEq, Eq, Debug)]
+/// enum Airport {
+///     #[enumscribe(str = "LHR")]
+///     Heathrow,
+///     #[enumscribe(other, raw_field = "raw", norm_field = "norm")]
+///     Other { raw: String, norm: String },
+/// }
+///
+/// assert_eq!(Airport::unscribe("Stn"),
+///            Airport::Other { raw: "Stn".to_owned(), norm: "stn".to_owned() });
+/// ```
+///
+/// ```
+/// use enumscribe::Unscribe;
+///
+/// #[derive(Unscribe, PartialEq, Eq, Debug)]
+/// enum Airport {
+///     #[enumscribe(str = "LHR", case_insensitive)]
+///     Heathrow,
+///     #[enumscribe(str = "LGW")]
 ///     Gatwick,
 ///     #[enumscribe(other)]
 ///     Other(String),
@@ -497,6 +1115,94 @@ pub trait Unscribe: Sized {
     /// variant to determine which variant to return. If there was no successful match, the
     /// variant marked with `#[enumscribe(other)]` will be returned instead.
     fn unscribe(to_unscribe: &str) -> Self;
+
+    /// Converts the given string to an enum variant in the same way as [Unscribe::unscribe], but
+    /// accepts any type which can be borrowed as a `&str`, such as `String`, `Cow<str>` or
+    /// [CappedString]. This avoids needing to call `.as_str()` or `.as_ref()` at the call site.
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use enumscribe::Unscribe;
+    ///
+    /// #[derive(Unscribe, PartialEq, Eq, Debug)]
+    /// enum Airport {
+    ///     #[enumscribe(str = "LHR")]
+    ///     Heathrow,
+    ///     #[enumscribe(other)]
+    ///     Other(String),
+    /// }
+    ///
+    /// assert_eq!(Airport::unscribe_from(String::from("LHR")), Airport::Heathrow);
+    /// assert_eq!(Airport::unscribe_from(&String::from("LHR")), Airport::Heathrow);
+    ///
+    /// let borrowed: Cow<str> = Cow::Borrowed("LHR");
+    /// let owned: Cow<str> = Cow::Owned("STN".to_owned());
+    /// assert_eq!(Airport::unscribe_from(borrowed), Airport::Heathrow);
+    /// assert_eq!(Airport::unscribe_from(owned), Airport::Other("STN".to_owned()));
+    /// ```
+    #[inline]
+    fn unscribe_from(to_unscribe: impl AsRef<str>) -> Self {
+        Self::unscribe(to_unscribe.as_ref())
+    }
+
+    /// Converts the given string to an enum variant, and also returns whether the variant marked
+    /// with `#[enumscribe(other)]` was used.
+    ///
+    /// The returned boolean is `true` if the string could not be matched to any named variant, so
+    /// the `other` variant was returned, and `false` otherwise.
+    ///
+    /// ```
+    /// use enumscribe::Unscribe;
+    ///
+    /// #[derive(Unscribe, PartialEq, Eq, Debug)]
+    /// enum Airport {
+    ///     #[enumscribe(str = "LHR")]
+    ///     Heathrow,
+    ///     #[enumscribe(other)]
+    ///     Other(String),
+    /// }
+    ///
+    /// assert_eq!(Airport::unscribe_tagged("LHR"), (Airport::Heathrow, false));
+    /// assert_eq!(Airport::unscribe_tagged("STN"), (Airport::Other("STN".to_owned()), true));
+    /// ```
+    fn unscribe_tagged(to_unscribe: &str) -> (Self, bool);
+
+    /// Converts the given string to an enum variant, in the same way as [Unscribe::unscribe], but
+    /// uses the given buffer to store the uppercase conversion of the string needed for
+    /// case-insensitive matching. This allows a single buffer to be reused across many calls.
+    ///
+    /// `N` should be at least the length in bytes of the longest case-insensitive variant name
+    /// after it is converted to uppercase. If the buffer is any smaller than this, it will not be
+    /// used, and the conversion will be performed exactly like [Unscribe::unscribe]. For enums
+    /// without any case-insensitive variants, the buffer is never used.
+    ///
+    /// ```
+    /// use enumscribe::{CappedString, Unscribe};
+    ///
+    /// #[derive(Unscribe, PartialEq, Eq, Debug)]
+    /// enum Airport {
+    ///     #[enumscribe(str = "LHR", case_insensitive)]
+    ///     Heathrow,
+    ///     #[enumscribe(str = "LGW", case_insensitive)]
+    ///     Gatwick,
+    ///     #[enumscribe(other)]
+    ///     Other(String),
+    /// }
+    ///
+    /// let mut buf = CappedString::<3>::new();
+    ///
+    /// let airports = ["lhr", "LGW", "stn"]
+    ///     .iter()
+    ///     .map(|s| Airport::unscribe_with_buf(s, &mut buf))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(airports, [Airport::Heathrow, Airport::Gatwick, Airport::Other("stn".to_owned())]);
+    /// ```
+    #[inline]
+    fn unscribe_with_buf<const N: usize>(to_unscribe: &str, buf: &mut CappedString<N>) -> Self {
+        let _ = buf;
+        Self::unscribe(to_unscribe)
+    }
 }
 
 /// Trait for converting from a string to an enum, or `None` if the conversion fails.
@@ -541,4 +1247,695 @@ pub trait TryUnscribe: Sized {
     /// variant marked with `#[enumscribe(other)]` will be returned instead. If there is no
     /// variant marked with `#[enumscribe(other)]`, then `None` will be returned.
     fn try_unscribe(to_unscribe: &str) -> Option<Self>;
+
+    /// Converts the given string to an enum variant in the same way as
+    /// [TryUnscribe::try_unscribe], but accepts any type which can be borrowed as a `&str`, such
+    /// as `String`, `Cow<str>` or [CappedString]. This avoids needing to call `.as_str()` or
+    /// `.as_ref()` at the call site.
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    /// use enumscribe::TryUnscribe;
+    ///
+    /// #[derive(TryUnscribe, PartialEq, Eq, Debug)]
+    /// enum Airport {
+    ///     #[enumscribe(str = "LHR")]
+    ///     Heathrow,
+    ///     #[enumscribe(str = "LGW")]
+    ///     Gatwick,
+    /// }
+    ///
+    /// assert_eq!(Airport::try_unscribe_from(String::from("LHR")), Some(Airport::Heathrow));
+    /// assert_eq!(Airport::try_unscribe_from(&String::from("STN")), None);
+    ///
+    /// let borrowed: Cow<str> = Cow::Borrowed("LGW");
+    /// let owned: Cow<str> = Cow::Owned("LHR".to_owned());
+    /// assert_eq!(Airport::try_unscribe_from(borrowed), Some(Airport::Gatwick));
+    /// assert_eq!(Airport::try_unscribe_from(owned), Some(Airport::Heathrow));
+    /// ```
+    #[inline]
+    fn try_unscribe_from(to_unscribe: impl AsRef<str>) -> Option<Self> {
+        Self::try_unscribe(to_unscribe.as_ref())
+    }
+
+    /// Returns whether the given string does not match any named variant, so that
+    /// [TryUnscribe::try_unscribe] would return the variant marked with `#[enumscribe(other)]`, or
+    /// `None` if there is no such variant.
+    ///
+    /// Strings are matched in exactly the same way as [TryUnscribe::try_unscribe], but no variant
+    /// is constructed, so the string is never copied into the `other` variant. This is useful for
+    /// validating input, for example to highlight unrecognised values in a user interface. The
+    /// function given by `#[enumscribe(on_deprecated = "...")]` is not called.
+    ///
+    /// ```
+    /// use enumscribe::TryUnscribe;
+    ///
+    /// #[derive(TryUnscribe, PartialEq, Eq, Debug)]
+    /// enum Airport {
+    ///     #[enumscribe(str = "LHR", case_insensitive)]
+    ///     Heathrow,
+    ///     #[enumscribe(str = "LGW")]
+    ///     Gatwick,
+    ///     #[enumscribe(other)]
+    ///     Other(String),
+    /// }
+    ///
+    /// assert!(!Airport::is_unknown("LHR"));
+    /// assert!(!Airport::is_unknown("lhr"));
+    /// assert!(!Airport::is_unknown("LGW"));
+    /// assert!(Airport::is_unknown("lgw"));
+    /// assert!(Airport::is_unknown("STN"));
+    /// ```
+    fn is_unknown(to_unscribe: &str) -> bool;
+
+    /// Converts the characters yielded by the given iterator to an enum variant, in the same way
+    /// as [TryUnscribe::try_unscribe], without allocating a `String` to hold them.
+    ///
+    /// The characters are collected into a fixed-size buffer on the stack, which is large enough
+    /// to hold the longest variant name. If there are more characters than fit in the buffer,
+    /// `None` is returned without consuming the rest of the iterator, even if the enum has a
+    /// variant marked with `#[enumscribe(other)]`.
+    ///
+    /// ```
+    /// use enumscribe::TryUnscribe;
+    ///
+    /// #[derive(TryUnscribe, PartialEq, Eq, Debug)]
+    /// enum Airport {
+    ///     #[enumscribe(str = "LHR", case_insensitive)]
+    ///     Heathrow,
+    ///     #[enumscribe(str = "LGW")]
+    ///     Gatwick,
+    /// }
+    ///
+    /// assert_eq!(Airport::unscribe_chars("lhr".chars()), Some(Airport::Heathrow));
+    /// assert_eq!(Airport::unscribe_chars("LGW".chars()), Some(Airport::Gatwick));
+    /// assert_eq!(Airport::unscribe_chars("LGWX".chars()), None);
+    /// ```
+    fn unscribe_chars<I>(chars: I) -> Option<Self>
+    where
+        I: Iterator<Item = char>;
+
+    /// Converts the given bytes to an enum variant, in the same way as
+    /// [TryUnscribe::try_unscribe], without first checking that they are valid UTF-8.
+    ///
+    /// The bytes are compared directly against the names of case-sensitive variants. They are
+    /// only checked to be valid UTF-8 if they do not match any of those names and the enum has
+    /// case-insensitive variants or a variant marked with `#[enumscribe(other)]`, which need
+    /// them as a string. `None` is returned if they are not valid UTF-8 in that case.
+    ///
+    /// ```
+    /// use enumscribe::TryUnscribe;
+    ///
+    /// #[derive(TryUnscribe, PartialEq, Eq, Debug)]
+    /// enum Airport {
+    ///     #[enumscribe(str = "LHR", case_insensitive)]
+    ///     Heathrow,
+    ///     #[enumscribe(str = "LGW")]
+    ///     Gatwick,
+    ///     #[enumscribe(other)]
+    ///     Other(String),
+    /// }
+    ///
+    /// assert_eq!(Airport::try_unscribe_bytes(b"LGW"), Some(Airport::Gatwick));
+    /// assert_eq!(Airport::try_unscribe_bytes(b"lhr"), Some(Airport::Heathrow));
+    /// assert_eq!(Airport::try_unscribe_bytes(b"STN"), Some(Airport::Other("STN".to_owned())));
+    /// assert_eq!(Airport::try_unscribe_bytes(b"\xff"), None);
+    /// ```
+    fn try_unscribe_bytes(to_unscribe: &[u8]) -> Option<Self>;
+
+    /// Returns the length in bytes of the longest string which could be converted to a variant
+    /// other than the one marked with `#[enumscribe(other)]`, which is useful for sizing buffers
+    /// to read such strings into.
+    ///
+    /// This is the length of the longest name or alias, unless the enum uses case-insensitive
+    /// matching, `normalize_newlines` or `nfc`, which allow longer strings to match a name. In
+    /// that case, it is an upper bound which is large enough for any matching string. It is also
+    /// the size of the buffer used by [TryUnscribe::unscribe_chars].
+    ///
+    /// ```
+    /// use enumscribe::TryUnscribe;
+    ///
+    /// #[derive(TryUnscribe)]
+    /// enum Airport {
+    ///     #[enumscribe(str = "LHR", alias = "Heathrow")]
+    ///     Heathrow,
+    ///     #[enumscribe(str = "LGW")]
+    ///     Gatwick,
+    /// }
+    ///
+    /// assert_eq!(Airport::max_match_len(), 8);
+    /// ```
+    fn max_match_len() -> usize;
+
+    /// Converts the given string to an enum variant in the same way as
+    /// [TryUnscribe::try_unscribe], returning the given string as the error if the conversion was
+    /// not successful.
+    ///
+    /// This avoids constructing an owned error, and is convenient for use with `map_err`. If the
+    /// enum has a variant marked with `#[enumscribe(other)]`, this always returns `Ok`.
+    ///
+    /// ```
+    /// use enumscribe::TryUnscribe;
+    ///
+    /// #[derive(TryUnscribe, PartialEq, Eq, Debug)]
+    /// enum Airport {
+    ///     #[enumscribe(str = "LHR")]
+    ///     Heathrow,
+    ///     #[enumscribe(str = "LGW")]
+    ///     Gatwick,
+    /// }
+    ///
+    /// assert_eq!(Airport::try_unscribe_echo("LHR"), Ok(Airport::Heathrow));
+    /// assert_eq!(Airport::try_unscribe_echo("STN"), Err("STN"));
+    ///
+    /// let message = Airport::try_unscribe_echo("STN")
+    ///     .map_err(|code| format!("unknown airport {}", code));
+    /// assert_eq!(message, Err("unknown airport STN".to_owned()));
+    /// ```
+    #[inline]
+    fn try_unscribe_echo(to_unscribe: &str) -> Result<Self, &str> {
+        Self::try_unscribe(to_unscribe).ok_or(to_unscribe)
+    }
+
+    /// Converts the given string to an enum variant after passing it through the given
+    /// normalization function, or `None` if the conversion was not successful.
+    ///
+    /// The normalized string is matched in exactly the same way as [TryUnscribe::try_unscribe],
+    /// so it is also the string stored in the variant marked with `#[enumscribe(other)]` if no
+    /// other variant matches. This allows callers to strip punctuation, collapse whitespace and
+    /// so on without baking it into the enum's definition.
+    ///
+    /// ```
+    /// use std::borrow::Cow;
+    ///
+    /// use enumscribe::TryUnscribe;
+    ///
+    /// #[derive(TryUnscribe, PartialEq, Eq, Debug)]
+    /// enum Airport {
+    ///     #[enumscribe(str = "LHR")]
+    ///     Heathrow,
+    ///     #[enumscribe(str = "LGW")]
+    ///     Gatwick,
+    /// }
+    ///
+    /// fn strip_dots(s: &str) -> Cow<'_, str> {
+    ///     s.replace('.', "").into()
+    /// }
+    ///
+    /// assert_eq!(Airport::unscribe_normalized("L.H.R.", strip_dots), Some(Airport::Heathrow));
+    /// assert_eq!(Airport::unscribe_normalized("LGW", strip_dots), Some(Airport::Gatwick));
+    /// assert_eq!(Airport::unscribe_normalized("L.H.R.", |s| Cow::Borrowed(s)), None);
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn unscribe_normalized(
+        to_unscribe: &str,
+        normalize: impl Fn(&str) -> Cow<'_, str>,
+    ) -> Option<Self> {
+        Self::try_unscribe(&normalize(to_unscribe))
+    }
+
+    /// Converts each of the given strings to an enum variant in the same way as
+    /// [TryUnscribe::try_unscribe], discarding any strings which could not be converted. The
+    /// variants are returned in the same order as the strings they were converted from.
+    ///
+    /// This is useful for lists from external sources, where unknown entries should be dropped
+    /// rather than causing an error. If the enum has a variant marked with
+    /// `#[enumscribe(other)]`, no strings are discarded.
+    ///
+    /// ```
+    /// use enumscribe::TryUnscribe;
+    ///
+    /// #[derive(TryUnscribe, PartialEq, Eq, Debug)]
+    /// enum Airport {
+    ///     #[enumscribe(str = "LHR")]
+    ///     Heathrow,
+    ///     #[enumscribe(str = "LGW")]
+    ///     Gatwick,
+    /// }
+    ///
+    /// assert_eq!(
+    ///     Airport::unscribe_filter(&["LGW", "STN", "LHR", "LGW"]),
+    ///     vec![Airport::Gatwick, Airport::Heathrow, Airport::Gatwick]
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn unscribe_filter(to_unscribe: &[&str]) -> Vec<Self> {
+        to_unscribe
+            .iter()
+            .filter_map(|item| Self::try_unscribe(item))
+            .collect()
+    }
+}
+
+/// Tries to unscribe a string as each of several enums in turn, returning the first match
+/// converted into a wrapper type.
+///
+/// `unscribe_any!(s => A | B | C)` calls [TryUnscribe::try_unscribe] for `A`, then `B`, then `C`,
+/// stopping at the first which matches `s`. The matched value is converted with
+/// [`Into::into`](core::convert::Into::into), so the result is an `Option<W>` for a wrapper type
+/// `W` implementing `From<A>`, `From<B>` and `From<C>`, which is usually an enum with a variant
+/// for each of them. `W` is inferred from how the result is used, so it often needs to be written
+/// out as a type annotation. If none of the enums match, the result is `None`.
+///
+/// `s` is evaluated exactly once, and must be a `&str`. The example below expands to roughly:
+///
+/// ```ignore
+/// {
+///     let s: &str = "LHR";
+///     if let Some(value) = <Airport as TryUnscribe>::try_unscribe(s) {
+///         Some(Into::into(value))
+///     } else if let Some(value) = <Airline as TryUnscribe>::try_unscribe(s) {
+///         Some(Into::into(value))
+///     } else {
+///         None
+///     }
+/// }
+/// ```
+///
+/// ```
+/// use enumscribe::{unscribe_any, TryUnscribe};
+///
+/// #[derive(TryUnscribe, PartialEq, Eq, Debug)]
+/// enum Airport {
+///     #[enumscribe(str = "LHR")]
+///     Heathrow,
+/// }
+///
+/// #[derive(TryUnscribe, PartialEq, Eq, Debug)]
+/// enum Airline {
+///     #[enumscribe(str = "BA")]
+///     BritishAirways,
+/// }
+///
+/// #[derive(PartialEq, Eq, Debug)]
+/// enum Code {
+///     Airport(Airport),
+///     Airline(Airline),
+/// }
+///
+/// impl From<Airport> for Code {
+///     fn from(airport: Airport) -> Self {
+///         Code::Airport(airport)
+///     }
+/// }
+///
+/// impl From<Airline> for Code {
+///     fn from(airline: Airline) -> Self {
+///         Code::Airline(airline)
+///     }
+/// }
+///
+/// let lhr: Option<Code> = unscribe_any!("LHR" => Airport | Airline);
+/// let ba: Option<Code> = unscribe_any!("BA" => Airport | Airline);
+/// let stn: Option<Code> = unscribe_any!("STN" => Airport | Airline);
+/// assert_eq!(lhr, Some(Code::Airport(Airport::Heathrow)));
+/// assert_eq!(ba, Some(Code::Airline(Airline::BritishAirways)));
+/// assert_eq!(stn, None);
+/// ```
+#[macro_export]
+macro_rules! unscribe_any {
+    ($s:expr => $($ty:ty)|+ $(,)?) => {{
+        let __enumscribe_str: &str = $s;
+        $(
+            if let ::core::option::Option::Some(__enumscribe_value) =
+                <$ty as $crate::TryUnscribe>::try_unscribe(__enumscribe_str)
+            {
+                ::core::option::Option::Some(::core::convert::Into::into(__enumscribe_value))
+            } else
+        )+ {
+            ::core::option::Option::None
+        }
+    }};
+}
+
+/// Trait for converting the start of a string to an enum, returning the rest of the string
+/// alongside the matched variant.
+///
+/// Like all of the traits provided by enumscribe, this should not be implemented manually; use
+/// [`#[derive(UnscribePrefix)]`](derive.UnscribePrefix.html) provided by the [enumscribe_derive]
+/// crate instead.
+///
+/// Each variant name (and any of its aliases) is checked against the start of the string. When
+/// more than one name is a prefix of the string, the longest name is always chosen, so the result
+/// does not depend on the order the variants are declared in. The variant marked with
+/// `#[enumscribe(other)]` is never returned, as it does not have a name to match.
+///
+/// ```
+/// use enumscribe::UnscribePrefix;
+///
+/// #[derive(UnscribePrefix, PartialEq, Eq, Debug)]
+/// enum Operator {
+///     #[enumscribe(str = "<")]
+///     Less,
+///     #[enumscribe(str = "<=")]
+///     LessOrEqual,
+///     #[enumscribe(str = "<<")]
+///     ShiftLeft,
+/// }
+///
+/// assert_eq!(Operator::unscribe_prefix("< 1"), Some((Operator::Less, " 1")));
+/// assert_eq!(Operator::unscribe_prefix("<= 1"), Some((Operator::LessOrEqual, " 1")));
+/// assert_eq!(Operator::unscribe_prefix("<<1"), Some((Operator::ShiftLeft, "1")));
+/// assert_eq!(Operator::unscribe_prefix("> 1"), None);
+/// ```
+pub trait UnscribePrefix: Sized {
+    /// Converts the start of the given string to an enum variant, and returns it along with the
+    /// remainder of the string which was not consumed. If none of the variant names are a prefix
+    /// of the string, `None` is returned.
+    ///
+    /// If several names are a prefix of the string, the longest one (in bytes) is used. For
+    /// variants marked with `#[enumscribe(case_insensitive)]`, the length of the uppercase name
+    /// is used.
+    fn unscribe_prefix(to_unscribe: &str) -> Option<(Self, &str)>;
+}
+
+/// Trait for finding every enum variant whose string starts with a given prefix, for example to
+/// provide autocompletion in a shell.
+///
+/// Like all of the traits provided by enumscribe, this should not be implemented manually; use
+/// [`#[derive(UnscribeCandidates)]`](derive.UnscribeCandidates.html) provided by the
+/// [enumscribe_derive] crate instead.
+///
+/// ```
+/// use enumscribe::UnscribeCandidates;
+///
+/// #[derive(UnscribeCandidates, PartialEq, Eq, Debug)]
+/// enum Airport {
+///     #[enumscribe(str = "LHR")]
+///     Heathrow,
+///     #[enumscribe(str = "LGW")]
+///     Gatwick,
+///     #[enumscribe(str = "STN")]
+///     Stansted,
+/// }
+///
+/// assert_eq!(Airport::unscribe_candidates("L"), vec![Airport::Heathrow, Airport::Gatwick]);
+/// assert_eq!(Airport::unscribe_candidates("LH"), vec![Airport::Heathrow]);
+/// assert_eq!(Airport::unscribe_candidates("X"), vec![]);
+/// ```
+#[cfg(feature = "std")]
+pub trait UnscribeCandidates: Sized {
+    /// Returns every variant which has a string starting with `prefix`, in the order they are
+    /// declared. A variant is included if its name or any of its aliases start with `prefix`,
+    /// ignoring case for variants marked with `#[enumscribe(case_insensitive)]`. Variants marked
+    /// with `#[enumscribe(other)]` or `#[enumscribe(ignore)]` are never included.
+    ///
+    /// Every variant which can be unscribed is returned for an empty prefix.
+    fn unscribe_candidates(prefix: &str) -> Vec<Self>;
+}
+
+/// Trait for converting a list of strings to a list of enum variants, for enums used as a set of
+/// flags.
+///
+/// Like all of the traits provided by enumscribe, this should not be implemented manually; use
+/// [`#[derive(EnumFlags)]`](derive.EnumFlags.html) provided by the [enumscribe_derive] crate
+/// instead. The enum must also implement [TryUnscribe].
+///
+/// ```
+/// use enumscribe::{EnumFlags, TryUnscribe, UnscribeError};
+///
+/// #[derive(EnumFlags, TryUnscribe, PartialEq, Eq, Debug)]
+/// enum Permission {
+///     #[enumscribe(str = "read")]
+///     Read,
+///     #[enumscribe(str = "write")]
+///     Write,
+/// }
+///
+/// assert_eq!(
+///     Permission::parse_set(&["write", "read"]),
+///     Ok(vec![Permission::Write, Permission::Read])
+/// );
+/// assert_eq!(Permission::parse_set(&["read", "execute"]), Err(UnscribeError));
+/// ```
+#[cfg(feature = "alloc")]
+pub trait EnumFlags: Sized {
+    /// Converts each of the given strings to an enum variant using [TryUnscribe], in order.
+    /// [UnscribeError] is returned as soon as a string could not be converted, without trying
+    /// any of the strings after it.
+    fn parse_set(items: &[&str]) -> Result<Vec<Self>, UnscribeError>;
+}
+
+/// Trait for getting the number of variants an enum has.
+///
+/// Like all of the traits provided by enumscribe, this should not be implemented manually; use
+/// [`#[derive(EnumCount)]`](derive.EnumCount.html) provided by the [enumscribe_derive] crate
+/// instead.
+///
+/// ```
+/// use enumscribe::EnumCount;
+///
+/// #[derive(EnumCount)]
+/// enum Airport {
+///     #[enumscribe(str = "LHR")]
+///     Heathrow,
+///     #[enumscribe(str = "LGW")]
+///     Gatwick,
+///     #[enumscribe(ignore)]
+///     Secret(i32),
+///     #[enumscribe(other)]
+///     Other(String),
+/// }
+///
+/// assert_eq!(Airport::NUM_VARIANTS, 4);
+/// assert_eq!(Airport::NUM_NAMED_VARIANTS, 2);
+///
+/// let visits = [0u32; Airport::NUM_NAMED_VARIANTS];
+/// ```
+pub trait EnumCount {
+    /// The total number of variants the enum has.
+    const NUM_VARIANTS: usize;
+
+    /// The number of variants which have a fixed string associated with them, i.e. variants not
+    /// marked with `#[enumscribe(ignore)]`, `#[enumscribe(other)]` or
+    /// `#[enumscribe(fmt_fields)]`.
+    const NUM_NAMED_VARIANTS: usize;
+}
+
+/// Trait for getting a description associated with each variant of an enum.
+///
+/// Like all of the traits provided by enumscribe, this should not be implemented manually; use
+/// [`#[derive(EnumMessage)]`](derive.EnumMessage.html) provided by the [enumscribe_derive] crate
+/// instead.
+///
+/// ```
+/// use enumscribe::EnumMessage;
+///
+/// #[derive(EnumMessage)]
+/// enum Airport {
+///     #[enumscribe(str = "LHR", message = "London Heathrow Airport")]
+///     Heathrow,
+///     #[enumscribe(str = "LGW")]
+///     Gatwick,
+///     #[enumscribe(other, message = "Some other airport")]
+///     Other(String),
+/// }
+///
+/// assert_eq!(Airport::Heathrow.message(), Some("London Heathrow Airport"));
+/// assert_eq!(Airport::Gatwick.message(), None);
+/// assert_eq!(Airport::Other("STN".to_owned()).message(), Some("Some other airport"));
+/// ```
+pub trait EnumMessage {
+    /// Returns the message given to this variant with `#[enumscribe(message = "...")]`, or `None`
+    /// if the variant does not have a message.
+    fn message(&self) -> Option<&'static str>;
+}
+
+/// Trait for getting the Rust identifier of each variant of an enum, regardless of the string it
+/// is scribed to.
+///
+/// Like all of the traits provided by enumscribe, this should not be implemented manually; use
+/// [`#[derive(EnumRustName)]`](derive.EnumRustName.html) provided by the [enumscribe_derive] crate
+/// instead.
+///
+/// ```
+/// use enumscribe::{EnumRustName, ScribeCowStr};
+///
+/// #[derive(EnumRustName, ScribeCowStr)]
+/// enum Airport {
+///     #[enumscribe(str = "LHR")]
+///     Heathrow,
+///     #[enumscribe(other)]
+///     Other(String),
+/// }
+///
+/// assert_eq!(Airport::Heathrow.rust_name(), "Heathrow");
+/// assert_eq!(Airport::Heathrow.scribe(), "LHR");
+/// assert_eq!(Airport::Other("STN".to_owned()).rust_name(), "Other");
+/// ```
+pub trait EnumRustName {
+    /// Returns the identifier of this variant as written in the enum's definition.
+    fn rust_name(&self) -> &'static str;
+}
+
+/// Trait for getting every string which converts to a particular variant of an enum.
+///
+/// Like all of the traits provided by enumscribe, this should not be implemented manually; use
+/// [`#[derive(EnumMatchStrings)]`](derive.EnumMatchStrings.html) provided by the
+/// [enumscribe_derive] crate instead.
+///
+/// This is useful for generating documentation for the strings which an API accepts.
+///
+/// ```
+/// use enumscribe::EnumMatchStrings;
+///
+/// #[derive(EnumMatchStrings)]
+/// enum Airport {
+///     #[enumscribe(str = "LHR", alias = "EGLL")]
+///     Heathrow,
+///     #[enumscribe(str = "LGW")]
+///     Gatwick,
+///     #[enumscribe(other)]
+///     Other(String),
+/// }
+///
+/// assert_eq!(Airport::Heathrow.match_strings(), ["LHR", "EGLL"]);
+/// assert_eq!(Airport::Gatwick.match_strings(), ["LGW"]);
+/// assert!(Airport::Other("STN".to_owned()).match_strings().is_empty());
+/// ```
+pub trait EnumMatchStrings {
+    /// Returns the strings which unscribe to this variant. The first is the string given by
+    /// `#[enumscribe(str = "...")]` (or the name of the variant if it is omitted), followed by
+    /// any aliases given by `#[enumscribe(alias = "...")]` or `accept_ident`.
+    ///
+    /// The strings are returned as written, even if the variant is matched case-insensitively.
+    /// Variants marked with `#[enumscribe(other)]`, `#[enumscribe(ignore)]` or
+    /// `#[enumscribe(fmt_fields)]` return an empty slice, as no fixed string unscribes to them.
+    fn match_strings(&self) -> &'static [&'static str];
+}
+
+/// Trait for getting every named variant of an enum along with the string it is scribed to.
+///
+/// This is useful for populating a list of options in a user interface. Variants marked with
+/// `#[enumscribe(ignore)]`, `#[enumscribe(other)]` or `#[enumscribe(fmt_fields)]` are not included,
+/// as they do not have a single fixed string.
+///
+/// Like all of the traits provided by enumscribe, this should not be implemented manually; use
+/// [`#[derive(EnumAllScribed)]`](derive.EnumAllScribed.html) provided by the [enumscribe_derive]
+/// crate instead.
+///
+/// ```
+/// use enumscribe::EnumAllScribed;
+///
+/// #[derive(EnumAllScribed, Clone, Copy, PartialEq, Eq, Debug)]
+/// enum Airport {
+///     #[enumscribe(str = "LHR")]
+///     Heathrow,
+///     #[enumscribe(str = "LGW")]
+///     Gatwick,
+///     #[enumscribe(ignore)]
+///     Secret,
+/// }
+///
+/// assert_eq!(Airport::all_scribed(), &[
+///     (Airport::Heathrow, "LHR"),
+///     (Airport::Gatwick, "LGW"),
+/// ]);
+/// ```
+pub trait EnumAllScribed: Copy + 'static {
+    /// Returns every named variant of the enum in declaration order, each paired with the string
+    /// given by `#[enumscribe(str = "...")]` (or the name of the variant if it has none).
+    fn all_scribed() -> &'static [(Self, &'static str)];
+}
+
+/// Trait for getting the name of every named variant of an enum as a fixed-size array.
+///
+/// Unlike [`EnumAllScribed::all_scribed`], the names are available as an associated constant
+/// whose length `N` is known at compile time, so they can be used in const contexts such as
+/// array lengths and const generic arguments. This makes them suitable for generating headers
+/// for use over FFI. `N` is the number of variants not marked with `#[enumscribe(ignore)]`,
+/// `#[enumscribe(other)]` or `#[enumscribe(fmt_fields)]`.
+///
+/// Like all of the traits provided by enumscribe, this should not be implemented manually; use
+/// [`#[derive(EnumVariantNames)]`](derive.EnumVariantNames.html) provided by the
+/// [enumscribe_derive] crate instead.
+///
+/// ```
+/// use enumscribe::EnumVariantNames;
+///
+/// #[derive(EnumVariantNames)]
+/// enum Airport {
+///     #[enumscribe(str = "LHR")]
+///     Heathrow,
+///     #[enumscribe(str = "LGW")]
+///     Gatwick,
+///     #[enumscribe(other)]
+///     Other(String),
+/// }
+///
+/// assert_eq!(Airport::VARIANT_NAMES, ["LHR", "LGW"]);
+///
+/// let visits = [0u32; Airport::VARIANT_NAMES.len()];
+/// ```
+pub trait EnumVariantNames<const N: usize> {
+    /// The string given by `#[enumscribe(str = "...")]` (or the name of the variant if it has
+    /// none) for every named variant of the enum, in declaration order.
+    const VARIANT_NAMES: [&'static str; N];
 }
+
+/// The error returned by a derived `FromStr` implementation when a string could not be converted
+/// to any variant of the enum.
+///
+/// ```
+/// use enumscribe::{FromStr, UnscribeError};
+///
+/// #[derive(FromStr, PartialEq, Eq, Debug)]
+/// #[enumscribe(case_insensitive)]
+/// enum Airport {
+///     #[enumscribe(str = "LHR")]
+///     Heathrow,
+///     #[enumscribe(str = "LGW")]
+///     Gatwick,
+/// }
+///
+/// assert_eq!("lhr".parse::<Airport>(), Ok(Airport::Heathrow));
+/// assert_eq!("STN".parse::<Airport>(), Err(UnscribeError));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct UnscribeError;
+
+impl fmt::Display for UnscribeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("string does not match any variant")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UnscribeError {}
+
+/// The error returned by [ScribeChecked::scribe_checked] when the string of an `other` variant
+/// would be unscribed to a named variant instead.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct OtherShadowsNamed;
+
+impl fmt::Display for OtherShadowsNamed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("string of other variant matches a named variant")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OtherShadowsNamed {}
+
+/// The error returned by [TryScribeCowStr::try_scribe_result] when a variant could not be
+/// converted to a string.
+///
+/// More reasons may be added in future, so this enum is marked `#[non_exhaustive]`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[non_exhaustive]
+pub enum ScribeError {
+    /// The variant is marked with `#[enumscribe(ignore)]`, so it has no string.
+    Ignored,
+}
+
+impl fmt::Display for ScribeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScribeError::Ignored => f.write_str("variant is ignored and has no string"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ScribeError {}
@@ -0,0 +1,26 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use enumscribe::ScribeCowStr;
+use enumscribe_benches::{Large, Medium, Small};
+
+/// Benchmarks scribing a named variant, which borrows a static string, and the `other` variant,
+/// which clones its field. Case-insensitivity has no effect on scribing, so only the
+/// case-sensitive enums are benchmarked.
+macro_rules! bench_enum {
+    ($c:expr, $group_name:expr, $named:expr, $other:path) => {{
+        let mut group = $c.benchmark_group($group_name);
+        let named = $named;
+        let other = $other("Inverness".to_owned());
+        group.bench_function("named", |b| b.iter(|| black_box(&named).scribe()));
+        group.bench_function("other", |b| b.iter(|| black_box(&other).scribe()));
+        group.finish();
+    }};
+}
+
+fn bench_scribe(c: &mut Criterion) {
+    bench_enum!(c, "scribe/small", Small::Luton, Small::Other);
+    bench_enum!(c, "scribe/medium", Medium::Belfast, Medium::Other);
+    bench_enum!(c, "scribe/large", Large::Variant199, Large::Other);
+}
+
+criterion_group!(benches, bench_scribe);
+criterion_main!(benches);
@@ -0,0 +1,38 @@
+use std::fmt::Debug;
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use enumscribe::Unscribe;
+use enumscribe_benches::{Large, LargeCi, Medium, MediumCi, Small, SmallCi};
+use enumscribe_benches::{LARGE_INPUTS, MEDIUM_INPUTS, SMALL_INPUTS};
+
+/// Benchmarks unscribing the first name, the last name and a string which falls back to the
+/// `other` variant. Case-insensitive enums are given the uppercase forms of the strings, so they
+/// take the same path as a mismatched case would.
+fn bench_enum<E, F>(c: &mut Criterion, group_name: &str, inputs: [&str; 3], to_input: F)
+where
+    E: Unscribe + Debug,
+    F: Fn(&str) -> String,
+{
+    let mut group = c.benchmark_group(group_name);
+
+    for (label, input) in ["first", "last", "other"].iter().zip(inputs.iter()) {
+        let input = to_input(input);
+        group.bench_with_input(BenchmarkId::from_parameter(label), &input, |b, input| {
+            b.iter(|| E::unscribe(black_box(input)))
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_unscribe(c: &mut Criterion) {
+    bench_enum::<Small, _>(c, "unscribe/small", SMALL_INPUTS, str::to_owned);
+    bench_enum::<SmallCi, _>(c, "unscribe/small_ci", SMALL_INPUTS, str::to_uppercase);
+    bench_enum::<Medium, _>(c, "unscribe/medium", MEDIUM_INPUTS, str::to_owned);
+    bench_enum::<MediumCi, _>(c, "unscribe/medium_ci", MEDIUM_INPUTS, str::to_uppercase);
+    bench_enum::<Large, _>(c, "unscribe/large", LARGE_INPUTS, str::to_owned);
+    bench_enum::<LargeCi, _>(c, "unscribe/large_ci", LARGE_INPUTS, str::to_uppercase);
+}
+
+criterion_group!(benches, bench_unscribe);
+criterion_main!(benches);
@@ -0,0 +1,45 @@
+//! The names of the same 300-variant enum as `size_derive`, stored by hand as slices of one
+//! concatenated string, which is what `#[derive(ScribeStaticStr)]` generates for a `Copy` enum of
+//! unit variants. Compare the sizes with `just size`.
+
+macro_rules! size_enum {
+    ($(($variant:ident, $name:literal),)*) => {
+        #[derive(Clone, Copy)]
+        enum Airport {
+            $($variant,)*
+        }
+
+        const ALL: &[Airport] = &[$(Airport::$variant),*];
+
+        const BLOB: &str = concat!($($name),*);
+
+        const LENS: &[usize] = &[$($name.len()),*];
+
+        /// The offset of each name in `BLOB`, followed by the length of `BLOB`.
+        const OFFSETS: [usize; LENS.len() + 1] = {
+            let mut offsets = [0; LENS.len() + 1];
+            let mut i = 0;
+            while i < LENS.len() {
+                offsets[i + 1] = offsets[i] + LENS[i];
+                i += 1;
+            }
+            offsets
+        };
+
+        impl Airport {
+            fn scribe(self) -> &'static str {
+                let i = self as usize;
+                &BLOB[OFFSETS[i]..OFFSETS[i + 1]]
+            }
+        }
+    };
+}
+
+enumscribe_benches::with_size_names!(size_enum);
+
+fn main() {
+    // Scribe every variant, so that none of the names can be optimised away
+    for airport in ALL {
+        println!("{}", airport.scribe());
+    }
+}
@@ -0,0 +1,24 @@
+//! The names of a 300-variant enum scribed with `#[derive(ScribeStaticStr)]`. Compare its size
+//! with `size_literals` and `size_blob` using `just size`.
+
+use enumscribe::ScribeStaticStr;
+
+macro_rules! size_enum {
+    ($(($variant:ident, $name:literal),)*) => {
+        #[derive(ScribeStaticStr, Clone, Copy)]
+        enum Airport {
+            $(#[enumscribe(str = $name)] $variant,)*
+        }
+
+        const ALL: &[Airport] = &[$(Airport::$variant),*];
+    };
+}
+
+enumscribe_benches::with_size_names!(size_enum);
+
+fn main() {
+    // Scribe every variant, so that none of the names can be optimised away
+    for airport in ALL {
+        println!("{}", airport.scribe());
+    }
+}
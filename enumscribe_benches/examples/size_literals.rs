@@ -0,0 +1,30 @@
+//! The names of the same 300-variant enum as `size_derive`, stored by hand as an array of separate
+//! string literals indexed by the variant. Compare the sizes with `just size`.
+
+macro_rules! size_enum {
+    ($(($variant:ident, $name:literal),)*) => {
+        #[derive(Clone, Copy)]
+        enum Airport {
+            $($variant,)*
+        }
+
+        const ALL: &[Airport] = &[$(Airport::$variant),*];
+
+        const NAMES: &[&str] = &[$($name),*];
+
+        impl Airport {
+            fn scribe(self) -> &'static str {
+                NAMES[self as usize]
+            }
+        }
+    };
+}
+
+enumscribe_benches::with_size_names!(size_enum);
+
+fn main() {
+    // Scribe every variant, so that none of the names can be optimised away
+    for airport in ALL {
+        println!("{}", airport.scribe());
+    }
+}
@@ -0,0 +1,246 @@
+//! Enums of different sizes for benchmarking the code generated by the enumscribe derives. Each
+//! enum comes in a case-sensitive and a case-insensitive version, both with an `other` variant so
+//! that strings which match no name can be benchmarked too.
+//!
+//! Run the benchmarks with `cargo bench -p enumscribe_benches`. The `size_*` examples compare the
+//! binary size of different ways of storing the names of a large enum, and are built and measured
+//! by `just size`.
+
+use enumscribe::{ScribeCowStr, TryUnscribe, Unscribe};
+
+/// Declares a case-sensitive and a case-insensitive enum with the given variants, followed by an
+/// `other` variant.
+macro_rules! bench_enums {
+    ($sensitive:ident, $insensitive:ident, [$($variant:ident),* $(,)?]) => {
+        #[derive(ScribeCowStr, Unscribe, TryUnscribe, Debug)]
+        pub enum $sensitive {
+            $($variant,)*
+            #[enumscribe(other)]
+            Other(String),
+        }
+
+        #[derive(ScribeCowStr, Unscribe, TryUnscribe, Debug)]
+        #[enumscribe(case_insensitive)]
+        pub enum $insensitive {
+            $($variant,)*
+            #[enumscribe(other)]
+            Other(String),
+        }
+    };
+}
+
+bench_enums!(
+    Small,
+    SmallCi,
+    [Heathrow, Gatwick, Stansted, Luton]
+);
+
+bench_enums!(
+    Medium,
+    MediumCi,
+    [
+        Heathrow, Gatwick, Stansted, Luton, LondonCity, Southend,
+        Birmingham, Manchester, Liverpool, Leeds, Newcastle, EastMidlands,
+        Bristol, Cardiff, Exeter, Southampton, Edinburgh, Glasgow,
+        Aberdeen, Belfast,
+    ]
+);
+
+bench_enums!(
+    Large,
+    LargeCi,
+    [
+        Variant000, Variant001, Variant002, Variant003, Variant004, Variant005, Variant006, Variant007,
+        Variant008, Variant009, Variant010, Variant011, Variant012, Variant013, Variant014, Variant015,
+        Variant016, Variant017, Variant018, Variant019, Variant020, Variant021, Variant022, Variant023,
+        Variant024, Variant025, Variant026, Variant027, Variant028, Variant029, Variant030, Variant031,
+        Variant032, Variant033, Variant034, Variant035, Variant036, Variant037, Variant038, Variant039,
+        Variant040, Variant041, Variant042, Variant043, Variant044, Variant045, Variant046, Variant047,
+        Variant048, Variant049, Variant050, Variant051, Variant052, Variant053, Variant054, Variant055,
+        Variant056, Variant057, Variant058, Variant059, Variant060, Variant061, Variant062, Variant063,
+        Variant064, Variant065, Variant066, Variant067, Variant068, Variant069, Variant070, Variant071,
+        Variant072, Variant073, Variant074, Variant075, Variant076, Variant077, Variant078, Variant079,
+        Variant080, Variant081, Variant082, Variant083, Variant084, Variant085, Variant086, Variant087,
+        Variant088, Variant089, Variant090, Variant091, Variant092, Variant093, Variant094, Variant095,
+        Variant096, Variant097, Variant098, Variant099, Variant100, Variant101, Variant102, Variant103,
+        Variant104, Variant105, Variant106, Variant107, Variant108, Variant109, Variant110, Variant111,
+        Variant112, Variant113, Variant114, Variant115, Variant116, Variant117, Variant118, Variant119,
+        Variant120, Variant121, Variant122, Variant123, Variant124, Variant125, Variant126, Variant127,
+        Variant128, Variant129, Variant130, Variant131, Variant132, Variant133, Variant134, Variant135,
+        Variant136, Variant137, Variant138, Variant139, Variant140, Variant141, Variant142, Variant143,
+        Variant144, Variant145, Variant146, Variant147, Variant148, Variant149, Variant150, Variant151,
+        Variant152, Variant153, Variant154, Variant155, Variant156, Variant157, Variant158, Variant159,
+        Variant160, Variant161, Variant162, Variant163, Variant164, Variant165, Variant166, Variant167,
+        Variant168, Variant169, Variant170, Variant171, Variant172, Variant173, Variant174, Variant175,
+        Variant176, Variant177, Variant178, Variant179, Variant180, Variant181, Variant182, Variant183,
+        Variant184, Variant185, Variant186, Variant187, Variant188, Variant189, Variant190, Variant191,
+        Variant192, Variant193, Variant194, Variant195, Variant196, Variant197, Variant198, Variant199,
+    ]
+);
+
+/// The names of the first and last variants of each enum, followed by a string which matches
+/// none of them, for benchmarking hits at both ends of the match and the `other` fallback.
+pub const SMALL_INPUTS: [&str; 3] = ["Heathrow", "Luton", "Birmingham"];
+pub const MEDIUM_INPUTS: [&str; 3] = ["Heathrow", "Belfast", "Inverness"];
+pub const LARGE_INPUTS: [&str; 3] = ["Variant000", "Variant199", "Variant200"];
+
+/// Calls the given macro with the variants and names of a 300-variant enum, used by the `size_*`
+/// examples to compare the binary size of different ways of storing the names. The names share
+/// long substrings, which is the case a single concatenated string could help with.
+#[macro_export]
+macro_rules! with_size_names {
+    ($callback:ident) => {
+        $callback! {
+            (V000, "north_international_airport_000"), (V001, "south_international_airport_001"),
+            (V002, "east_international_airport_002"), (V003, "west_international_airport_003"),
+            (V004, "central_international_airport_004"), (V005, "north_regional_airport_005"),
+            (V006, "south_regional_airport_006"), (V007, "east_regional_airport_007"),
+            (V008, "west_regional_airport_008"), (V009, "central_regional_airport_009"),
+            (V010, "north_city_airport_010"), (V011, "south_city_airport_011"),
+            (V012, "east_city_airport_012"), (V013, "west_city_airport_013"),
+            (V014, "central_city_airport_014"), (V015, "north_municipal_airport_015"),
+            (V016, "south_municipal_airport_016"), (V017, "east_municipal_airport_017"),
+            (V018, "west_municipal_airport_018"), (V019, "central_municipal_airport_019"),
+            (V020, "north_county_airport_020"), (V021, "south_county_airport_021"),
+            (V022, "east_county_airport_022"), (V023, "west_county_airport_023"),
+            (V024, "central_county_airport_024"), (V025, "north_field_airport_025"),
+            (V026, "south_field_airport_026"), (V027, "east_field_airport_027"),
+            (V028, "west_field_airport_028"), (V029, "central_field_airport_029"),
+            (V030, "north_international_airport_030"), (V031, "south_international_airport_031"),
+            (V032, "east_international_airport_032"), (V033, "west_international_airport_033"),
+            (V034, "central_international_airport_034"), (V035, "north_regional_airport_035"),
+            (V036, "south_regional_airport_036"), (V037, "east_regional_airport_037"),
+            (V038, "west_regional_airport_038"), (V039, "central_regional_airport_039"),
+            (V040, "north_city_airport_040"), (V041, "south_city_airport_041"),
+            (V042, "east_city_airport_042"), (V043, "west_city_airport_043"),
+            (V044, "central_city_airport_044"), (V045, "north_municipal_airport_045"),
+            (V046, "south_municipal_airport_046"), (V047, "east_municipal_airport_047"),
+            (V048, "west_municipal_airport_048"), (V049, "central_municipal_airport_049"),
+            (V050, "north_county_airport_050"), (V051, "south_county_airport_051"),
+            (V052, "east_county_airport_052"), (V053, "west_county_airport_053"),
+            (V054, "central_county_airport_054"), (V055, "north_field_airport_055"),
+            (V056, "south_field_airport_056"), (V057, "east_field_airport_057"),
+            (V058, "west_field_airport_058"), (V059, "central_field_airport_059"),
+            (V060, "north_international_airport_060"), (V061, "south_international_airport_061"),
+            (V062, "east_international_airport_062"), (V063, "west_international_airport_063"),
+            (V064, "central_international_airport_064"), (V065, "north_regional_airport_065"),
+            (V066, "south_regional_airport_066"), (V067, "east_regional_airport_067"),
+            (V068, "west_regional_airport_068"), (V069, "central_regional_airport_069"),
+            (V070, "north_city_airport_070"), (V071, "south_city_airport_071"),
+            (V072, "east_city_airport_072"), (V073, "west_city_airport_073"),
+            (V074, "central_city_airport_074"), (V075, "north_municipal_airport_075"),
+            (V076, "south_municipal_airport_076"), (V077, "east_municipal_airport_077"),
+            (V078, "west_municipal_airport_078"), (V079, "central_municipal_airport_079"),
+            (V080, "north_county_airport_080"), (V081, "south_county_airport_081"),
+            (V082, "east_county_airport_082"), (V083, "west_county_airport_083"),
+            (V084, "central_county_airport_084"), (V085, "north_field_airport_085"),
+            (V086, "south_field_airport_086"), (V087, "east_field_airport_087"),
+            (V088, "west_field_airport_088"), (V089, "central_field_airport_089"),
+            (V090, "north_international_airport_090"), (V091, "south_international_airport_091"),
+            (V092, "east_international_airport_092"), (V093, "west_international_airport_093"),
+            (V094, "central_international_airport_094"), (V095, "north_regional_airport_095"),
+            (V096, "south_regional_airport_096"), (V097, "east_regional_airport_097"),
+            (V098, "west_regional_airport_098"), (V099, "central_regional_airport_099"),
+            (V100, "north_city_airport_100"), (V101, "south_city_airport_101"),
+            (V102, "east_city_airport_102"), (V103, "west_city_airport_103"),
+            (V104, "central_city_airport_104"), (V105, "north_municipal_airport_105"),
+            (V106, "south_municipal_airport_106"), (V107, "east_municipal_airport_107"),
+            (V108, "west_municipal_airport_108"), (V109, "central_municipal_airport_109"),
+            (V110, "north_county_airport_110"), (V111, "south_county_airport_111"),
+            (V112, "east_county_airport_112"), (V113, "west_county_airport_113"),
+            (V114, "central_county_airport_114"), (V115, "north_field_airport_115"),
+            (V116, "south_field_airport_116"), (V117, "east_field_airport_117"),
+            (V118, "west_field_airport_118"), (V119, "central_field_airport_119"),
+            (V120, "north_international_airport_120"), (V121, "south_international_airport_121"),
+            (V122, "east_international_airport_122"), (V123, "west_international_airport_123"),
+            (V124, "central_international_airport_124"), (V125, "north_regional_airport_125"),
+            (V126, "south_regional_airport_126"), (V127, "east_regional_airport_127"),
+            (V128, "west_regional_airport_128"), (V129, "central_regional_airport_129"),
+            (V130, "north_city_airport_130"), (V131, "south_city_airport_131"),
+            (V132, "east_city_airport_132"), (V133, "west_city_airport_133"),
+            (V134, "central_city_airport_134"), (V135, "north_municipal_airport_135"),
+            (V136, "south_municipal_airport_136"), (V137, "east_municipal_airport_137"),
+            (V138, "west_municipal_airport_138"), (V139, "central_municipal_airport_139"),
+            (V140, "north_county_airport_140"), (V141, "south_county_airport_141"),
+            (V142, "east_county_airport_142"), (V143, "west_county_airport_143"),
+            (V144, "central_county_airport_144"), (V145, "north_field_airport_145"),
+            (V146, "south_field_airport_146"), (V147, "east_field_airport_147"),
+            (V148, "west_field_airport_148"), (V149, "central_field_airport_149"),
+            (V150, "north_international_airport_150"), (V151, "south_international_airport_151"),
+            (V152, "east_international_airport_152"), (V153, "west_international_airport_153"),
+            (V154, "central_international_airport_154"), (V155, "north_regional_airport_155"),
+            (V156, "south_regional_airport_156"), (V157, "east_regional_airport_157"),
+            (V158, "west_regional_airport_158"), (V159, "central_regional_airport_159"),
+            (V160, "north_city_airport_160"), (V161, "south_city_airport_161"),
+            (V162, "east_city_airport_162"), (V163, "west_city_airport_163"),
+            (V164, "central_city_airport_164"), (V165, "north_municipal_airport_165"),
+            (V166, "south_municipal_airport_166"), (V167, "east_municipal_airport_167"),
+            (V168, "west_municipal_airport_168"), (V169, "central_municipal_airport_169"),
+            (V170, "north_county_airport_170"), (V171, "south_county_airport_171"),
+            (V172, "east_county_airport_172"), (V173, "west_county_airport_173"),
+            (V174, "central_county_airport_174"), (V175, "north_field_airport_175"),
+            (V176, "south_field_airport_176"), (V177, "east_field_airport_177"),
+            (V178, "west_field_airport_178"), (V179, "central_field_airport_179"),
+            (V180, "north_international_airport_180"), (V181, "south_international_airport_181"),
+            (V182, "east_international_airport_182"), (V183, "west_international_airport_183"),
+            (V184, "central_international_airport_184"), (V185, "north_regional_airport_185"),
+            (V186, "south_regional_airport_186"), (V187, "east_regional_airport_187"),
+            (V188, "west_regional_airport_188"), (V189, "central_regional_airport_189"),
+            (V190, "north_city_airport_190"), (V191, "south_city_airport_191"),
+            (V192, "east_city_airport_192"), (V193, "west_city_airport_193"),
+            (V194, "central_city_airport_194"), (V195, "north_municipal_airport_195"),
+            (V196, "south_municipal_airport_196"), (V197, "east_municipal_airport_197"),
+            (V198, "west_municipal_airport_198"), (V199, "central_municipal_airport_199"),
+            (V200, "north_county_airport_200"), (V201, "south_county_airport_201"),
+            (V202, "east_county_airport_202"), (V203, "west_county_airport_203"),
+            (V204, "central_county_airport_204"), (V205, "north_field_airport_205"),
+            (V206, "south_field_airport_206"), (V207, "east_field_airport_207"),
+            (V208, "west_field_airport_208"), (V209, "central_field_airport_209"),
+            (V210, "north_international_airport_210"), (V211, "south_international_airport_211"),
+            (V212, "east_international_airport_212"), (V213, "west_international_airport_213"),
+            (V214, "central_international_airport_214"), (V215, "north_regional_airport_215"),
+            (V216, "south_regional_airport_216"), (V217, "east_regional_airport_217"),
+            (V218, "west_regional_airport_218"), (V219, "central_regional_airport_219"),
+            (V220, "north_city_airport_220"), (V221, "south_city_airport_221"),
+            (V222, "east_city_airport_222"), (V223, "west_city_airport_223"),
+            (V224, "central_city_airport_224"), (V225, "north_municipal_airport_225"),
+            (V226, "south_municipal_airport_226"), (V227, "east_municipal_airport_227"),
+            (V228, "west_municipal_airport_228"), (V229, "central_municipal_airport_229"),
+            (V230, "north_county_airport_230"), (V231, "south_county_airport_231"),
+            (V232, "east_county_airport_232"), (V233, "west_county_airport_233"),
+            (V234, "central_county_airport_234"), (V235, "north_field_airport_235"),
+            (V236, "south_field_airport_236"), (V237, "east_field_airport_237"),
+            (V238, "west_field_airport_238"), (V239, "central_field_airport_239"),
+            (V240, "north_international_airport_240"), (V241, "south_international_airport_241"),
+            (V242, "east_international_airport_242"), (V243, "west_international_airport_243"),
+            (V244, "central_international_airport_244"), (V245, "north_regional_airport_245"),
+            (V246, "south_regional_airport_246"), (V247, "east_regional_airport_247"),
+            (V248, "west_regional_airport_248"), (V249, "central_regional_airport_249"),
+            (V250, "north_city_airport_250"), (V251, "south_city_airport_251"),
+            (V252, "east_city_airport_252"), (V253, "west_city_airport_253"),
+            (V254, "central_city_airport_254"), (V255, "north_municipal_airport_255"),
+            (V256, "south_municipal_airport_256"), (V257, "east_municipal_airport_257"),
+            (V258, "west_municipal_airport_258"), (V259, "central_municipal_airport_259"),
+            (V260, "north_county_airport_260"), (V261, "south_county_airport_261"),
+            (V262, "east_county_airport_262"), (V263, "west_county_airport_263"),
+            (V264, "central_county_airport_264"), (V265, "north_field_airport_265"),
+            (V266, "south_field_airport_266"), (V267, "east_field_airport_267"),
+            (V268, "west_field_airport_268"), (V269, "central_field_airport_269"),
+            (V270, "north_international_airport_270"), (V271, "south_international_airport_271"),
+            (V272, "east_international_airport_272"), (V273, "west_international_airport_273"),
+            (V274, "central_international_airport_274"), (V275, "north_regional_airport_275"),
+            (V276, "south_regional_airport_276"), (V277, "east_regional_airport_277"),
+            (V278, "west_regional_airport_278"), (V279, "central_regional_airport_279"),
+            (V280, "north_city_airport_280"), (V281, "south_city_airport_281"),
+            (V282, "east_city_airport_282"), (V283, "west_city_airport_283"),
+            (V284, "central_city_airport_284"), (V285, "north_municipal_airport_285"),
+            (V286, "south_municipal_airport_286"), (V287, "east_municipal_airport_287"),
+            (V288, "west_municipal_airport_288"), (V289, "central_municipal_airport_289"),
+            (V290, "north_county_airport_290"), (V291, "south_county_airport_291"),
+            (V292, "east_county_airport_292"), (V293, "west_county_airport_293"),
+            (V294, "central_county_airport_294"), (V295, "north_field_airport_295"),
+            (V296, "south_field_airport_296"), (V297, "east_field_airport_297"),
+            (V298, "west_field_airport_298"), (V299, "central_field_airport_299"),
+        }
+    };
+}
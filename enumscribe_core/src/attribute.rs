@@ -0,0 +1,428 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use proc_macro2::Span;
+use syn::ext::IdentExt;
+use syn::parse::discouraged::Speculative;
+use syn::parse::{Parse, ParseBuffer, ParseStream};
+use syn::{Attribute, Ident, Lit, LitStr, Macro, Path, Token};
+
+use crate::error::{MacroError, MacroResult, ValueTypeError, ValueTypeResult};
+
+#[derive(Clone)]
+pub(crate) enum Value {
+    None,
+    Lit(Lit),
+    Ident(Ident),
+    List(Vec<Lit>),
+}
+
+impl Value {
+    pub(crate) fn type_name(&self) -> &'static str {
+        match self {
+            Value::None => "nothing",
+            Value::Lit(lit) => match lit {
+                Lit::Str(_) => "string",
+                Lit::ByteStr(_) => "byte string",
+                Lit::Byte(_) => "byte",
+                Lit::Char(_) => "character",
+                Lit::Int(_) => "integer",
+                Lit::Float(_) => "float",
+                Lit::Bool(_) => "boolean",
+                Lit::Verbatim(_) => "verbatim literal",
+            },
+            Value::Ident(_) => "identifier",
+            Value::List(_) => "list",
+        }
+    }
+
+    /// The span of the value itself, if it has one which is more precise than the span of its key.
+    pub(crate) fn span(&self) -> Option<Span> {
+        match self {
+            Value::Lit(lit) => Some(lit.span()),
+            Value::Ident(ident) => Some(ident.span()),
+            Value::None | Value::List(_) => None,
+        }
+    }
+
+    /// Gets the boolean value associated with this Value. `Value::None` value is considered to
+    /// be true. If this value cannot represent a boolean, a `ValueTypeError` will be returned.
+    pub(crate) fn value_bool(&self) -> ValueTypeResult<bool> {
+        match self {
+            Value::None => Ok(true),
+            Value::Lit(Lit::Bool(lit_bool)) => Ok(lit_bool.value),
+            val => Err(ValueTypeError {
+                message: format!("expected boolean but found {}", val.type_name()).into(),
+            }),
+        }
+    }
+
+    /// Gets the string value associated with this Value. If this value cannot represent a string,
+    /// a `ValueTypeError` will be returned.
+    pub(crate) fn value_string(&self) -> ValueTypeResult<String> {
+        match self {
+            Value::Lit(Lit::Str(lit_str)) => Ok(lit_str.value()),
+            val => Err(ValueTypeError {
+                message: format!("expected string but found {}", val.type_name()).into(),
+            }),
+        }
+    }
+
+    /// Gets the character associated with this Value. If this value cannot represent a character,
+    /// a `ValueTypeError` will be returned.
+    pub(crate) fn value_char(&self) -> ValueTypeResult<char> {
+        match self {
+            Value::Lit(Lit::Char(lit_char)) => Ok(lit_char.value()),
+            val => Err(ValueTypeError {
+                message: format!("expected character but found {}", val.type_name()).into(),
+            }),
+        }
+    }
+
+    /// Gets the strings in the list associated with this Value, e.g. `key("a", "b")`. If this value
+    /// is not a list of strings, a `ValueTypeError` will be returned.
+    pub(crate) fn value_string_list(&self) -> ValueTypeResult<Vec<String>> {
+        match self {
+            Value::List(lits) => lits
+                .iter()
+                .map(|lit| match lit {
+                    Lit::Str(lit_str) => Ok(lit_str.value()),
+                    lit => Err(ValueTypeError {
+                        message: format!(
+                            "expected list of strings but found {}",
+                            Value::Lit(lit.clone()).type_name()
+                        )
+                        .into(),
+                    }),
+                })
+                .collect(),
+            val => Err(ValueTypeError {
+                message: format!("expected list but found {}", val.type_name()).into(),
+            }),
+        }
+    }
+
+    /// Gets the path associated with this Value, which must be given as a string containing the
+    /// path. If this value cannot represent a path, a `ValueTypeError` will be returned.
+    pub(crate) fn value_path(&self) -> ValueTypeResult<Path> {
+        match self {
+            Value::Lit(Lit::Str(lit_str)) => lit_str.parse::<Path>().map_err(|_| ValueTypeError {
+                message: format!("expected path but found \"{}\"", lit_str.value()).into(),
+            }),
+            Value::Ident(ident) => Ok(Path::from(ident.clone())),
+            val => Err(ValueTypeError {
+                message: format!("expected string but found {}", val.type_name()).into(),
+            }),
+        }
+    }
+}
+
+impl fmt::Debug for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::None => write!(f, "ε"),
+            Value::Lit(lit) => match lit {
+                Lit::Str(lit_str) => lit_str.value().fmt(f),
+                Lit::ByteStr(lit_byte_str) => lit_byte_str.value().fmt(f),
+                Lit::Byte(lit_byte) => lit_byte.value().fmt(f),
+                Lit::Char(lit_char) => lit_char.value().fmt(f),
+                Lit::Int(lit_int) => write!(f, "{}", lit_int.base10_digits()),
+                Lit::Float(lit_float) => write!(f, "{}", lit_float.base10_digits()),
+                Lit::Bool(lit_bool) => lit_bool.value.fmt(f),
+                Lit::Verbatim(lit_verbatim) => lit_verbatim.fmt(f),
+            },
+            Value::Ident(ident) => ident.fmt(f),
+            Value::List(lits) => f
+                .debug_list()
+                .entries(lits.iter().cloned().map(Value::Lit))
+                .finish(),
+        }
+    }
+}
+
+/// The key-value pairs from every `#[tag(...)]` on an item. Most keys may appear at most once, but
+/// multi-valued keys may appear any number of times (even across several `#[tag(...)]`s, such as
+/// ones split up by `cfg_attr`), and keep every value they are given in order.
+#[derive(Clone, Debug)]
+pub(crate) struct Dict {
+    pub(crate) inner: HashMap<String, Vec<(Value, Span)>>,
+    /// Every key in the order it first appeared, so that errors can be reported in that order.
+    keys: Vec<String>,
+}
+
+/// Represents the contents of a single `#[tag(...)]`.
+/// The contents are parsed from `key = value` pairs or `key(value, ...)` lists, separated by commas.
+#[derive(Clone, Debug)]
+struct AttributeTag {
+    inner: Vec<(String, Value, Span)>,
+}
+
+#[derive(Clone, Debug)]
+struct KeyValPair {
+    key: String,
+    val: Value,
+    span: Span,
+}
+
+impl Dict {
+    pub(crate) fn new() -> Self {
+        Dict {
+            inner: HashMap::new(),
+            keys: Vec::new(),
+        }
+    }
+
+    pub(crate) fn from_attrs(
+        name: &str,
+        multi_valued_keys: &[&str],
+        attrs: &[Attribute],
+    ) -> MacroResult<Self> {
+        let mut dict = Dict::new();
+
+        // A bare `#[tag]` with no arguments is treated as an empty list of arguments, rather than
+        // being passed to `parse_args` (which would fail as there are no parentheses)
+        let attribute_tags = attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident(name))
+            .filter(|attr| !attr.tokens.is_empty())
+            .map(|attr| attr.parse_args::<AttributeTag>());
+
+        for tag in attribute_tags {
+            let tag = tag.map_err(MacroError::from)?;
+
+            for (key, val, span) in tag.inner {
+                if dict.inner.contains_key(&key) && !multi_valued_keys.contains(&key.as_str()) {
+                    return Err(MacroError::new(
+                        format!("key appears more than once: {}", key),
+                        span,
+                    ));
+                }
+
+                if !dict.inner.contains_key(&key) {
+                    dict.keys.push(key.clone());
+                }
+
+                dict.inner.entry(key).or_default().push((val, span));
+            }
+        }
+
+        Ok(dict)
+    }
+
+    pub(crate) fn remove_typed<T, F>(
+        &mut self,
+        key: &str,
+        converter: F,
+    ) -> MacroResult<Option<(T, Span)>>
+    where
+        F: Fn(&Value) -> ValueTypeResult<T>,
+    {
+        // Keys which are not multi-valued never have more than one value
+        match self.inner.remove(key).and_then(|mut vals| vals.pop()) {
+            None => Ok(None),
+            Some((val, span)) => convert_value(key, &val, span, &converter).map(Some),
+        }
+    }
+
+    /// Removes every value of a multi-valued key, in the order they were given.
+    pub(crate) fn remove_typed_all<T, F>(
+        &mut self,
+        key: &str,
+        converter: F,
+    ) -> MacroResult<Vec<(T, Span)>>
+    where
+        F: Fn(&Value) -> ValueTypeResult<T>,
+    {
+        self.inner
+            .remove(key)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(val, span)| convert_value(key, &val, span, &converter))
+            .collect()
+    }
+
+    pub(crate) fn remove_typed_or_default<T, F>(
+        &mut self,
+        key: &str,
+        default: (T, Span),
+        converter: F,
+    ) -> MacroResult<(T, Span)>
+    where
+        F: Fn(&Value) -> ValueTypeResult<T>,
+    {
+        match self.remove_typed(key, converter) {
+            Ok(Some(value)) => Ok(value),
+            Ok(None) => Ok(default),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Returns an error if any keys have not been removed, with one message for each of them so
+    /// that every unexpected key is reported at once.
+    pub(crate) fn assert_empty(&self) -> MacroResult<()> {
+        let mut unexpected = self
+            .keys
+            .iter()
+            .filter_map(|key| {
+                let (_, span) = self.inner.get(key)?.first()?;
+                Some(MacroError::new(format!("unexpected key: {}", key), *span))
+            });
+
+        match unexpected.next() {
+            Some(mut err) => {
+                for other_err in unexpected {
+                    err.combine(other_err);
+                }
+                Err(err)
+            },
+            None => Ok(()),
+        }
+    }
+}
+
+fn convert_value<T, F>(key: &str, val: &Value, span: Span, converter: &F) -> MacroResult<(T, Span)>
+where
+    F: Fn(&Value) -> ValueTypeResult<T>,
+{
+    match converter(val) {
+        Ok(converted) => Ok((converted, span)),
+        // Point at the value which has the wrong type, rather than its key
+        Err(ValueTypeError { message }) => Err(MacroError::new(
+            format!("{} for key: {}", message, key),
+            val.span().unwrap_or(span),
+        )),
+    }
+}
+
+impl Parse for AttributeTag {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(AttributeTag {
+            inner: input
+                .parse_terminated::<KeyValPair, Token![,]>(KeyValPair::parse)?
+                .into_iter()
+                .map(|pair| (pair.key, pair.val, pair.span))
+                .collect::<Vec<_>>(),
+        })
+    }
+}
+
+impl Parse for KeyValPair {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // Keys may be keywords, such as `crate`
+        let key = input.call(Ident::parse_any)?;
+
+        let val = if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+
+            if let Ok(lit) = speculative_parse::<Lit>(input) {
+                Value::Lit(lit)
+            } else if let Ok(mac) = speculative_parse::<Macro>(input) {
+                Value::Lit(eval_macro(&mac)?)
+            } else if let Ok(ident) = speculative_parse::<Ident>(input) {
+                Value::Ident(ident)
+            } else {
+                return Err(input.error(format!(
+                    "could not parse value corresponding to key: {}",
+                    key
+                )));
+            }
+        } else if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+            let lits = content.parse_terminated::<Lit, Token![,]>(parse_lit_or_macro)?;
+            Value::List(lits.into_iter().collect())
+        } else {
+            Value::None
+        };
+
+        Ok(KeyValPair {
+            key: key.to_string(),
+            val,
+            span: key.span(),
+        })
+    }
+}
+
+/// Parses a literal, or a macro call which can be evaluated to a string literal by [eval_macro].
+fn parse_lit_or_macro(input: ParseStream) -> syn::Result<Lit> {
+    if let Ok(lit) = speculative_parse::<Lit>(input) {
+        Ok(lit)
+    } else {
+        input.parse::<Macro>().and_then(|mac| eval_macro(&mac))
+    }
+}
+
+/// Evaluates a call to `concat!` or `stringify!` to the string literal it would expand to, so that
+/// names can be built by macros. The arguments of `concat!` may be literals or further calls to
+/// either macro. Any other macro cannot be evaluated when the derive macro is expanded, so it
+/// results in an error.
+fn eval_macro(mac: &Macro) -> syn::Result<Lit> {
+    // Paths such as `core::concat!` are accepted, so only the last segment is checked
+    let macro_ident = &mac
+        .path
+        .segments
+        .last()
+        .expect("macro path has no segments")
+        .ident;
+
+    let value = if macro_ident == "stringify" {
+        mac.tokens.to_string()
+    } else if macro_ident == "concat" {
+        let parts = mac.parse_body_with(|input: ParseStream| {
+            input.parse_terminated::<Lit, Token![,]>(parse_lit_or_macro)
+        })?;
+
+        let mut value = String::new();
+        for part in parts.iter() {
+            match part {
+                Lit::Str(lit_str) => value.push_str(&lit_str.value()),
+                Lit::Char(lit_char) => value.push(lit_char.value()),
+                Lit::Int(lit_int) => value.push_str(lit_int.base10_digits()),
+                Lit::Float(lit_float) => value.push_str(lit_float.base10_digits()),
+                Lit::Bool(lit_bool) => value.push_str(if lit_bool.value { "true" } else { "false" }),
+                lit => {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        format!(
+                            "cannot concatenate {}",
+                            Value::Lit(lit.clone()).type_name()
+                        ),
+                    ))
+                },
+            }
+        }
+        value
+    } else {
+        return Err(syn::Error::new(
+            macro_ident.span(),
+            format!(
+                "cannot evaluate macro: {}!\n\
+                 hint: only concat! and stringify! can be used in place of a literal",
+                macro_ident
+            ),
+        ));
+    };
+
+    Ok(Lit::Str(LitStr::new(&value, macro_ident.span())))
+}
+
+fn speculative_parse<T>(input: ParseStream) -> syn::Result<T>
+where
+    T: Parse,
+{
+    match fork_and_parse(input) {
+        Ok((fork, parsed)) => {
+            input.advance_to(&fork);
+            Ok(parsed)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+fn fork_and_parse<T>(input: ParseStream) -> syn::Result<(ParseBuffer, T)>
+where
+    T: Parse,
+{
+    let fork = input.fork();
+    T::parse(&fork).map(move |parsed| (fork, parsed))
+}
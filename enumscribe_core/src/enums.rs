@@ -0,0 +1,2003 @@
+use std::collections::{HashMap, HashSet};
+use std::iter;
+
+use proc_macro2::{Ident, Span, TokenTree};
+use quote::{quote, ToTokens};
+use syn::spanned::Spanned;
+use syn::{DataEnum, Fields, Attribute, Lit, LitStr, Path, Type};
+
+use crate::attribute::{Dict, Value};
+use crate::error::{MacroError, MacroResult, ValueTypeResult};
+use crate::rename::RenameVariant;
+use crate::TokenStream2;
+use crate::keys::{CASE_INSENSITIVE, CASE_SENSITIVE, CRATE_ATTR, IGNORE, NAME, OTHER, RENAME, RENAME_ALL};
+use crate::keys::{ACCEPT_IDENT, CI_OVERFLOW, DENY_PREFIX, MESSAGE, NORM_FIELD, RAW_FIELD, SERIALIZE_AS};
+use crate::keys::{DEBUG_AS_SCRIBE, DE_ERROR, EXPECT_NAMES, FMT_FIELDS, PHF, SCRIBE_MAP, SERIALIZE_WITH_IMPL};
+use crate::keys::{ALIAS, EMPTY_IS_OTHER, MULTI_VALUED_KEYS, NORMALIZE_NEWLINES, PLACEHOLDER, SCRIBE_AS};
+use crate::keys::{CRATE_PATH, OPTIONAL_FIELD, SCRIBE_CHECKED, SERDE_OPTIONAL, SERDE_WITH, SORT_EXPECTED};
+use crate::keys::{DEPRECATED, NFC, ON_DEPRECATED, PAD, REQUIRE_STR, SERDE_NATIVE};
+use crate::keys::{DOCUMENT_STR, EXTEND, LINT, SERIALIZE_TRANSFORM};
+
+#[derive(Clone)]
+pub struct Enum<'a> {
+    variants: Box<[Variant<'a>]>,
+    name_capacity: usize,
+    name_upper_capacity: usize,
+    serialize_as: SerializeAs,
+    serde_native: bool,
+    ci_overflow: CiOverflow,
+    de_error: Option<Box<str>>,
+    debug_as_scribe: bool,
+    scribe_checked: bool,
+    normalize_newlines: bool,
+    nfc: bool,
+    empty_is_other: bool,
+    serde_optional: Option<Box<str>>,
+    sort_expected: bool,
+    serde_with: Option<Ident>,
+    on_deprecated: Option<Path>,
+    pad: u8,
+    extend: Option<Path>,
+    crate_path: Path,
+    #[cfg(feature = "phf")]
+    phf: bool,
+}
+
+impl<'a> Enum<'a> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        variants: Box<[Variant<'a>]>,
+        serialize_as: SerializeAs,
+        serde_native: bool,
+        ci_overflow: CiOverflow,
+        de_error: Option<Box<str>>,
+        debug_as_scribe: bool,
+        scribe_checked: bool,
+        normalize_newlines: bool,
+        nfc: bool,
+        empty_is_other: bool,
+        serde_optional: Option<Box<str>>,
+        sort_expected: bool,
+        serde_with: Option<Ident>,
+        on_deprecated: Option<Path>,
+        pad: u8,
+        extend: Option<Path>,
+        crate_path: Path,
+        phf: bool,
+    ) -> Self {
+        // `phf` is always false without the phf feature, as `parse_enum` rejects it.
+        #[cfg(not(feature = "phf"))]
+        let _ = phf;
+
+        let name_capacity = variants
+            .iter()
+            .filter_map(|v| v.v_type.as_named())
+            .flat_map(|named| named.match_names())
+            .map(str::len)
+            .max()
+            .unwrap_or(0);
+
+        // Uppercase conversion may expand a string (e.g. "ß" becomes "SS"), so this is computed
+        // from the converted names rather than the original ones. An input whose uppercase
+        // conversion does not fit cannot be equal to any converted name, so it is safe to treat
+        // overflowing the buffer as a failed match. ASCII case-insensitive matching compares
+        // strings in place, so if every case-insensitive variant uses it, no buffer is needed.
+        let needs_uppercase = variants
+            .iter()
+            .filter_map(|v| v.v_type.as_named())
+            .any(NamedVariant::uppercase_case_insensitive);
+
+        let name_upper_capacity = if needs_uppercase {
+            variants
+                .iter()
+                .filter_map(|v| v.v_type.as_named())
+                .filter(|named| !named.ascii_case_insensitive())
+                .flat_map(|named| named.match_names_upper())
+                .map(str::len)
+                .max()
+                .unwrap_or(0)
+        } else {
+            0
+        };
+
+        Self {
+            variants,
+            name_capacity,
+            name_upper_capacity,
+            serialize_as,
+            serde_native,
+            ci_overflow,
+            de_error,
+            debug_as_scribe,
+            scribe_checked,
+            normalize_newlines,
+            nfc,
+            empty_is_other,
+            serde_optional,
+            sort_expected,
+            serde_with,
+            on_deprecated,
+            pad,
+            extend,
+            crate_path,
+            #[cfg(feature = "phf")]
+            phf,
+        }
+    }
+
+    pub fn variants(&self) -> &[Variant<'a>] {
+        &self.variants
+    }
+
+    pub fn has_other(&self) -> bool {
+        self.variants
+            .iter()
+            .any(|v| matches!(v.v_type, VariantType::Other(_)))
+    }
+
+    /// Whether any of the named variants are matched case-insensitively.
+    pub fn has_case_insensitive(&self) -> bool {
+        self.variants
+            .iter()
+            .filter_map(|v| v.v_type.as_named())
+            .any(NamedVariant::case_insensitive)
+    }
+
+    /// Whether any of the named variants are matched case-insensitively by converting strings to
+    /// uppercase, rather than by only ignoring the case of ASCII letters.
+    pub fn has_uppercase_case_insensitive(&self) -> bool {
+        self.variants
+            .iter()
+            .filter_map(|v| v.v_type.as_named())
+            .any(NamedVariant::uppercase_case_insensitive)
+    }
+
+    /// The prefix given by `#[enumscribe(other, deny_prefix = "...")]`, if any.
+    pub fn deny_prefix(&self) -> Option<&str> {
+        self.variants.iter().find_map(|v| match &v.v_type {
+            VariantType::Other(other) => other.deny_prefix(),
+            _ => None,
+        })
+    }
+
+    pub fn name_upper_capacity(&self) -> usize {
+        self.name_upper_capacity
+    }
+
+    /// The length of the longest string which could match a variant name.
+    pub fn match_capacity(&self) -> usize {
+        // A string which matches a case-insensitive name may be longer than the name itself,
+        // since some characters become shorter when converted to uppercase (e.g. "ſ" becomes
+        // "S"). Every character of such a string is at most 4 bytes and becomes at least one
+        // character of the uppercase name, so 4 times the length of the longest uppercase name
+        // is always enough. ASCII case-insensitive matching never changes the length of a string.
+        if self.has_uppercase_case_insensitive() {
+            self.name_capacity.max(self.name_upper_capacity * 4)
+        } else {
+            self.name_capacity
+        }
+    }
+
+    /// The length of the longest string which could match a variant name before it is normalized
+    /// by `normalize_newlines` or `nfc`.
+    pub fn input_capacity(&self) -> usize {
+        // Normalizing newlines replaces each "\r\n" with a single byte, so it may make a string up
+        // to half as long.
+        let newlines_factor = if self.normalize_newlines { 2 } else { 1 };
+
+        // A string has the same canonical decomposition as its NFC form, and each of its
+        // characters is at most 4 bytes and decomposes to at least one character. No character
+        // of n bytes decomposes to more than 1.5n characters, so the string is at most 6 times
+        // as long as its NFC form.
+        let nfc_factor = if self.nfc { 6 } else { 1 };
+
+        self.match_capacity() * newlines_factor * nfc_factor
+    }
+
+    pub fn serialize_as(&self) -> SerializeAs {
+        self.serialize_as
+    }
+
+    /// Whether `#[enumscribe(serde_native)]` was used, so `EnumDeserialize` should deserialize the
+    /// enum with `deserialize_enum`, accepting serde's own representation of unit variants.
+    pub fn serde_native(&self) -> bool {
+        self.serde_native
+    }
+
+    pub fn ci_overflow(&self) -> CiOverflow {
+        self.ci_overflow
+    }
+
+    /// The path to the enumscribe crate to use in generated code, which is `::enumscribe` unless
+    /// overridden by `#[enumscribe(crate = "...")]`.
+    pub fn crate_path(&self) -> &Path {
+        &self.crate_path
+    }
+
+    /// The message given by `#[enumscribe(de_error = "...")]` to use when deserializing a string
+    /// which does not match any variant, if any.
+    pub fn de_error(&self) -> Option<&str> {
+        self.de_error.as_deref()
+    }
+
+    /// Whether `#[enumscribe(debug_as_scribe)]` was used, so `ScribeCowStr` should also generate a
+    /// `Debug` implementation.
+    pub fn debug_as_scribe(&self) -> bool {
+        self.debug_as_scribe
+    }
+
+    /// Whether `#[enumscribe(scribe_checked)]` was used, so `ScribeCowStr` should also generate a
+    /// `ScribeChecked` implementation.
+    pub fn scribe_checked(&self) -> bool {
+        self.scribe_checked
+    }
+
+    /// Whether `#[enumscribe(normalize_newlines)]` was used, so `"\r\n"` should be replaced with
+    /// `"\n"` in strings before they are matched against the names of the variants.
+    pub fn normalize_newlines(&self) -> bool {
+        self.normalize_newlines
+    }
+
+    /// Whether `#[enumscribe(nfc)]` was used, so strings should be converted to Unicode
+    /// Normalization Form C before they are matched against the names of the variants.
+    pub fn nfc(&self) -> bool {
+        self.nfc
+    }
+
+    /// Whether `#[enumscribe(empty_is_other)]` was used, so `EnumDeserialize` should always store
+    /// an empty string in the `other` variant rather than matching it against the names.
+    pub fn empty_is_other(&self) -> bool {
+        self.empty_is_other
+    }
+
+    /// The name of the feature given by `#[enumscribe(serde_optional)]`, which the `Serialize` and
+    /// `Deserialize` implementations should only be compiled with, if any.
+    pub fn serde_optional(&self) -> Option<&str> {
+        self.serde_optional.as_deref()
+    }
+
+    /// Whether `#[enumscribe(sort_expected)]` was used, so the names listed in serde's "unknown
+    /// variant" error should be sorted alphabetically rather than given in declaration order.
+    pub fn sort_expected(&self) -> bool {
+        self.sort_expected
+    }
+
+    /// The name of the module generated by `EnumSerdeWith`, given by
+    /// `#[enumscribe(serde_with = "...")]`, if any.
+    pub fn serde_with(&self) -> Option<&Ident> {
+        self.serde_with.as_ref()
+    }
+
+    /// The function given by `#[enumscribe(on_deprecated = "...")]`, which is called with the
+    /// string being unscribed whenever it matches a variant marked as `deprecated`.
+    pub fn on_deprecated(&self) -> Option<&Path> {
+        self.on_deprecated.as_ref()
+    }
+
+    /// The enum given by `#[enumscribe(extend = ...)]`, whose variants `EnumExtend` converts to
+    /// the variants of this enum with the same names.
+    pub fn extend(&self) -> Option<&Path> {
+        self.extend.as_ref()
+    }
+
+    /// The byte given by `#[enumscribe(pad = '...')]`, which `ScribeFixed` uses to fill the rest of
+    /// the array after a name shorter than it. This is a space unless overridden.
+    pub fn pad(&self) -> u8 {
+        self.pad
+    }
+
+    /// Whether names should be looked up in a perfect hash map rather than matched with a `match`
+    /// when unscribing.
+    #[cfg(feature = "phf")]
+    pub fn phf(&self) -> bool {
+        self.phf
+    }
+}
+
+/// What the fallible unscribe traits should do when a string is too long to be converted to
+/// uppercase for case-insensitive matching.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CiOverflow {
+    /// Treat the string as not matching any case-insensitive variant, so it falls through to the
+    /// `other` variant if there is one.
+    Other,
+    /// Fail, even if there is an `other` variant.
+    Error,
+}
+
+impl CiOverflow {
+    pub fn from_str(s: &str, span: Span) -> MacroResult<Self> {
+        match s {
+            "other" => Ok(Self::Other),
+            "error" => Ok(Self::Error),
+            _ => Err(MacroError::new(
+                format!(
+                    "invalid {} value {:?} (allowed values are: other, error)",
+                    CI_OVERFLOW,
+                    s
+                ),
+                span
+            )),
+        }
+    }
+}
+
+/// The shape that `EnumSerialize` and `EnumDeserialize` use to represent the enum.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SerializeAs {
+    /// A bare string, e.g. `"LHR"`.
+    String,
+    /// A map with a single entry, whose key is the name of the enum and whose value is the
+    /// string, e.g. `{"Airport": "LHR"}`.
+    Tagged,
+}
+
+impl SerializeAs {
+    pub fn from_str(s: &str, span: Span) -> MacroResult<Self> {
+        match s {
+            "string" => Ok(Self::String),
+            "tagged" => Ok(Self::Tagged),
+            _ => Err(MacroError::new(
+                format!(
+                    "invalid {} value {:?} (allowed values are: string, tagged)",
+                    SERIALIZE_AS,
+                    s
+                ),
+                span
+            )),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Variant<'a> {
+    pub data: &'a syn::Variant,
+    pub v_type: VariantType<'a>,
+    pub message: Option<Box<str>>,
+    pub span: Span,
+}
+
+#[derive(Clone)]
+pub enum VariantType<'a> {
+    Ignore(IgnoredVariant),
+    Named(NamedVariant),
+    Other(OtherVariant<'a>),
+    Formatted(FormattedVariant),
+}
+
+impl<'a> VariantType<'a> {
+    pub fn as_named(&self) -> Option<&NamedVariant> {
+        match self {
+            Self::Named(named) => Some(named),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct NamedVariant {
+    name: Box<str>,
+    aliases: Box<[Box<str>]>,
+    match_names: Box<[Box<str>]>,
+    match_names_upper: Box<[Box<str>]>,
+    constructor: VariantConstructor,
+    case_insensitive: bool,
+    ascii_case_insensitive: bool,
+    scribe_map: Option<Path>,
+    deprecated: bool,
+}
+
+impl NamedVariant {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        name: Box<str>,
+        aliases: Vec<Box<str>>,
+        constructor: VariantConstructor,
+        case_insensitive: bool,
+        ascii_case_insensitive: bool,
+        scribe_map: Option<Path>,
+        deprecated: bool,
+        normalize_newlines: bool,
+        nfc: bool,
+    ) -> Self
+    {
+        let match_names = Some(&name)
+            .into_iter()
+            .chain(aliases.iter())
+            .map(|match_name| {
+                normalized_name(match_name, normalize_newlines, nfc).into_boxed_str()
+            })
+            .collect::<Box<[_]>>();
+        let match_names_upper = match_names
+            .iter()
+            .map(|match_name| char_wise_uppercase(match_name))
+            .collect();
+        Self {
+            name,
+            aliases: aliases.into_boxed_slice(),
+            match_names,
+            match_names_upper,
+            constructor,
+            case_insensitive,
+            ascii_case_insensitive,
+            scribe_map,
+            deprecated,
+        }
+    }
+    
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The name of the variant, followed by any other strings which unscribe to it, as they were
+    /// written in the enum's definition.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        Some(&*self.name)
+            .into_iter()
+            .chain(self.aliases.iter().map(|alias| &**alias))
+    }
+
+    /// The name of the variant, followed by any other strings which unscribe to it. If the enum
+    /// uses `#[enumscribe(normalize_newlines)]`, `"\r\n"` is replaced with `"\n"` in each of them,
+    /// and if it uses `#[enumscribe(nfc)]`, each of them is converted to NFC.
+    pub fn match_names(&self) -> impl Iterator<Item = &str> {
+        self.match_names.iter().map(|match_name| &**match_name)
+    }
+
+    /// Uppercase versions of the strings returned by `match_names`.
+    pub fn match_names_upper(&self) -> impl Iterator<Item = &str> {
+        self.match_names_upper.iter().map(|match_name| &**match_name)
+    }
+
+    pub fn constructor(&self) -> &VariantConstructor {
+        &self.constructor
+    }
+
+    pub fn case_insensitive(&self) -> bool {
+        self.case_insensitive
+    }
+
+    /// Whether the variant is case-insensitive, but only ignores the case of ASCII letters, as
+    /// set by `case_insensitive = "ascii"` on the variant or the enum.
+    pub fn ascii_case_insensitive(&self) -> bool {
+        self.case_insensitive && self.ascii_case_insensitive
+    }
+
+    /// Whether the variant is case-insensitive, and strings must be converted to uppercase to be
+    /// compared to its names.
+    pub fn uppercase_case_insensitive(&self) -> bool {
+        self.case_insensitive && !self.ascii_case_insensitive
+    }
+
+    /// The function given by `#[enumscribe(scribe_map = "...")]` which the name should be passed
+    /// through when scribing, if any.
+    pub fn scribe_map(&self) -> Option<&Path> {
+        self.scribe_map.as_ref()
+    }
+
+    /// Whether the variant is marked with `#[enumscribe(deprecated)]`, so unscribing it calls the
+    /// function given by `#[enumscribe(on_deprecated = "...")]`.
+    pub fn deprecated(&self) -> bool {
+        self.deprecated
+    }
+}
+
+/// A variant marked with `#[enumscribe(ignore)]`, which is never unscribed.
+#[derive(Clone)]
+pub struct IgnoredVariant {
+    placeholder: Option<Box<str>>,
+    document_str: Option<Box<str>>,
+}
+
+impl IgnoredVariant {
+    /// The string given by `#[enumscribe(ignore, placeholder = "...")]` which this variant is
+    /// scribed as, if any. Without one, this variant cannot be scribed.
+    pub fn placeholder(&self) -> Option<&str> {
+        self.placeholder.as_deref()
+    }
+
+    /// The string given by `#[enumscribe(ignore, document_str = "...")]`, which is listed among
+    /// the expected names in serde's "unknown variant" error even though it never matches.
+    pub fn document_str(&self) -> Option<&str> {
+        self.document_str.as_deref()
+    }
+}
+
+#[derive(Clone)]
+pub struct OtherVariant<'a> {
+    field_name: Option<&'a Ident>,
+    norm_field_name: Option<&'a Ident>,
+    serialize_with_impl: bool,
+    deny_prefix: Option<Box<str>>,
+    scribe_as: Option<Box<str>>,
+    serialize_transform: Option<RenameVariant>,
+    none_placeholder: Option<Box<str>>,
+    borrowed_field: Option<&'a Type>,
+    field_ty: &'a Type,
+}
+
+impl<'a> OtherVariant<'a> {
+    pub fn field_name(&self) -> Option<&'a Ident> {
+        self.field_name
+    }
+
+    pub fn norm_field_name(&self) -> Option<&'a Ident> {
+        self.norm_field_name
+    }
+
+    pub fn serialize_with_impl(&self) -> bool {
+        self.serialize_with_impl
+    }
+
+    pub fn deny_prefix(&self) -> Option<&str> {
+        self.deny_prefix.as_deref()
+    }
+
+    /// The fixed string given by `#[enumscribe(other, scribe_as = "...")]` which this variant is
+    /// scribed as instead of its field, if any.
+    pub fn scribe_as(&self) -> Option<&str> {
+        self.scribe_as.as_deref()
+    }
+
+    /// The case given by `rename_all` or `rename` which `EnumSerialize` should convert the field
+    /// of this variant to, if the variant is marked with `#[enumscribe(other, serialize_transform)]`.
+    pub fn serialize_transform(&self) -> Option<RenameVariant> {
+        self.serialize_transform
+    }
+
+    /// If the variant is marked with `#[enumscribe(other, optional_field)]`, so its field is an
+    /// `Option` which is `None` for empty strings, the string to scribe `None` as. This is given by
+    /// `placeholder`, or is empty by default.
+    pub fn none_placeholder(&self) -> Option<&str> {
+        self.none_placeholder.as_deref()
+    }
+
+    /// The type of the first field of this variant which borrows data, such as `&'a str`. The
+    /// string being unscribed cannot be stored in such a field, as it may not live long enough.
+    pub fn borrowed_field(&self) -> Option<&'a Type> {
+        self.borrowed_field
+    }
+
+    /// The type of the field containing the verbatim string.
+    pub fn field_ty(&self) -> &'a Type {
+        self.field_ty
+    }
+
+    /// An expression for the string stored in the field bound by
+    /// [`pattern`](OtherVariant::pattern). This is just the binding, unless the field is optional,
+    /// in which case it is a `&str` which is the placeholder if the field is `None`.
+    pub fn field_value(&self) -> TokenStream2 {
+        let binding = self.binding();
+        match self.none_placeholder() {
+            Some(none_placeholder) => quote! {
+                match #binding {
+                    ::core::option::Option::Some(__enumscribe_other_some) => {
+                        <_ as ::core::convert::AsRef<str>>::as_ref(__enumscribe_other_some)
+                    },
+                    ::core::option::Option::None => #none_placeholder,
+                }
+            },
+            None => binding,
+        }
+    }
+
+    /// The identifier which the field containing the verbatim string is bound to by
+    /// [`pattern`](OtherVariant::pattern).
+    pub fn binding(&self) -> TokenStream2 {
+        match self.field_name {
+            Some(field_name) => field_name.to_token_stream(),
+            None => quote! { __enumscribe_other_inner },
+        }
+    }
+
+    /// The pattern used to match this variant, binding the field containing the verbatim string to
+    /// [`binding`](OtherVariant::binding).
+    pub fn pattern(&self, enum_ident: &Ident, variant_ident: &Ident) -> TokenStream2 {
+        let binding = self.binding();
+        match (self.field_name, self.norm_field_name) {
+            (Some(_), Some(_)) => quote! { #enum_ident::#variant_ident { #binding, .. } },
+            (Some(_), None) => quote! { #enum_ident::#variant_ident { #binding } },
+            (None, _) => quote! { #enum_ident::#variant_ident(#binding) },
+        }
+    }
+}
+
+/// A variant marked with `#[enumscribe(fmt_fields)]`, which is scribed by formatting its fields
+/// into its name.
+#[derive(Clone)]
+pub struct FormattedVariant {
+    format: LitStr,
+    bindings: Box<[Ident]>,
+    named_fields: bool,
+}
+
+impl FormattedVariant {
+    /// The pattern used to match this variant, binding each of its fields in declaration order.
+    pub fn pattern(&self, enum_ident: &Ident, variant_ident: &Ident) -> TokenStream2 {
+        let bindings = &*self.bindings;
+        if self.named_fields {
+            quote! { #enum_ident::#variant_ident { #(#bindings),* } }
+        } else {
+            quote! { #enum_ident::#variant_ident(#(#bindings),*) }
+        }
+    }
+
+    /// An expression producing a `String` from the variant's name, with its `{}` placeholders
+    /// filled by the fields bound by [`pattern`](FormattedVariant::pattern).
+    pub fn format(&self, enumscribe: &Path) -> TokenStream2 {
+        let format = &self.format;
+        let bindings = &*self.bindings;
+        quote! { #enumscribe::internal::alloc::format!(#format, #(#bindings),*) }
+    }
+}
+
+/// The shape of a named variant. A named variant has no fields other than `PhantomData` markers,
+/// which hold no data and are filled in with `PhantomData` when the variant is constructed.
+#[derive(Clone, Debug)]
+pub enum VariantConstructor {
+    None,
+    /// Parentheses containing the given number of `PhantomData` fields.
+    Paren(usize),
+    /// Braces containing the `PhantomData` fields with the given names.
+    Brace(Vec<Ident>),
+}
+
+impl<'a> Variant<'a> {
+    /// The pattern used to match this variant without binding any of its fields.
+    pub fn pattern_ignoring_fields(&self, enum_ident: &Ident) -> TokenStream2 {
+        let variant_ident = &self.data.ident;
+        quote! { #enum_ident::#variant_ident { .. } }
+    }
+
+    /// The function given by `#[enumscribe(scribe_map = "...")]`, if this is a named variant and
+    /// the enum has one.
+    pub fn scribe_map(&self) -> Option<&Path> {
+        self.v_type.as_named().and_then(NamedVariant::scribe_map)
+    }
+
+    pub fn match_variant<F, G, H>(
+        &self,
+        enum_ident: &Ident,
+        enumscribe: &Path,
+        named_fn: &F,
+        other_fn: &G,
+        formatted_fn: &H,
+    ) -> MacroResult<Option<(TokenStream2, TokenStream2)>>
+    where
+        F: Fn(&Variant, &Ident, &str) -> MacroResult<TokenStream2>,
+        G: Fn(&Variant, &Ident, TokenStream2) -> MacroResult<TokenStream2>,
+        H: Fn(&Variant, &Ident, TokenStream2) -> MacroResult<TokenStream2>,
+    {
+        let variant_ident = &self.data.ident;
+
+        match &self.v_type {
+            // An ignored variant with a placeholder is scribed in the same way as a named variant.
+            VariantType::Ignore(ignored) => match ignored.placeholder() {
+                Some(placeholder) => {
+                    let pattern = self.pattern_ignoring_fields(enum_ident);
+                    Ok(Some((pattern, named_fn(self, enum_ident, placeholder)?)))
+                },
+                None => Ok(None),
+            },
+
+            VariantType::Named(named) => {
+                let constructor_tokens = named.constructor().empty_toks();
+                let pattern = quote! { #enum_ident::#variant_ident #constructor_tokens };
+                Ok(Some((pattern, named_fn(self, enum_ident, named.name())?)))
+            }
+
+            VariantType::Other(other) => {
+                // A variant with a fixed string to scribe as is scribed in the same way as a named
+                // variant, so the string it stores is never exposed.
+                if let Some(scribe_as) = other.scribe_as() {
+                    let pattern = self.pattern_ignoring_fields(enum_ident);
+                    return Ok(Some((pattern, named_fn(self, enum_ident, scribe_as)?)));
+                }
+
+                let field_name_tokens = other.field_value();
+                let pattern = other.pattern(enum_ident, variant_ident);
+                Ok(Some((
+                    pattern,
+                    other_fn(self, enum_ident, field_name_tokens)?,
+                )))
+            }
+
+            VariantType::Formatted(formatted) => {
+                let pattern = formatted.pattern(enum_ident, variant_ident);
+                Ok(Some((pattern, formatted_fn(self, enum_ident, formatted.format(enumscribe))?)))
+            }
+        }
+    }
+}
+
+impl VariantConstructor {
+    /// The tokens following the variant's identifier which construct it, filling any fields with
+    /// `PhantomData`. Since `PhantomData` is a unit struct, these tokens are also a pattern which
+    /// matches the variant.
+    pub fn empty_toks(&self) -> TokenStream2 {
+        match self {
+            VariantConstructor::None => quote! {},
+            VariantConstructor::Paren(fields) => {
+                let fields = (0..*fields).map(|_| quote! { ::core::marker::PhantomData });
+                quote! { (#(#fields),*) }
+            },
+            VariantConstructor::Brace(fields) => quote! {
+                { #(#fields: ::core::marker::PhantomData),* }
+            },
+        }
+    }
+}
+
+/// Parses the `#[enumscribe(...)]` attributes of an enum, given as `attrs`, and of each of its
+/// variants. Returns an error spanning the offending attribute if any of them are invalid, such as
+/// when two variants have the same name or an unknown key is used.
+pub fn parse_enum<'a>(data: &'a DataEnum, attrs: &'a [Attribute]) -> MacroResult<Enum<'a>> {
+    let mut variants = Vec::with_capacity(data.variants.len());
+    let mut other_variant = false;
+    let mut variant_names = Vec::new();
+
+    let mut global_dict = Dict::from_attrs(CRATE_ATTR, MULTI_VALUED_KEYS, attrs)?;
+    
+    let global_case_insensitive =
+        global_dict.remove_typed(CASE_INSENSITIVE, value_case_insensitive)?;
+
+    let (global_case_insensitive, global_ascii_case_insensitive) =
+        case_insensitive_mode(global_case_insensitive)?;
+
+    let mut taken_names = TakenNames::new();
+
+    let global_rename = global_dict.remove_typed(RENAME_ALL, Value::value_string)?
+        .map(|(global_rename, span)| RenameVariant::from_str(&global_rename, span))
+        .transpose()?;
+
+    let serialize_as = global_dict.remove_typed(SERIALIZE_AS, Value::value_string)?
+        .map(|(serialize_as, span)| {
+            SerializeAs::from_str(&serialize_as, span).map(|serialize_as| (serialize_as, span))
+        })
+        .transpose()?;
+
+    let (serde_native, serde_native_span) = global_dict.remove_typed_or_default(
+        SERDE_NATIVE,
+        (false, data.enum_token.span()),
+        Value::value_bool,
+    )?;
+
+    if serde_native && matches!(serialize_as, Some((SerializeAs::Tagged, _))) {
+        return Err(MacroError::new(
+            format!(
+                "cannot use {} together with {} = \"tagged\"\n\
+                 explanation: {} deserializes the enum in serde's own representation of unit \
+                 variants, so it cannot also be deserialized from a tagged map",
+                SERDE_NATIVE,
+                SERIALIZE_AS,
+                SERDE_NATIVE
+            ),
+            serde_native_span,
+        ));
+    }
+
+    let serialize_as = serialize_as
+        .map(|(serialize_as, _)| serialize_as)
+        .unwrap_or(SerializeAs::String);
+
+    let ci_overflow = global_dict.remove_typed(CI_OVERFLOW, Value::value_string)?
+        .map(|(ci_overflow, span)| CiOverflow::from_str(&ci_overflow, span))
+        .transpose()?
+        .unwrap_or(CiOverflow::Other);
+
+    let (require_str, _) = global_dict.remove_typed_or_default(
+        REQUIRE_STR,
+        (false, data.enum_token.span()),
+        Value::value_bool,
+    )?;
+
+    let (lint, _) = global_dict.remove_typed_or_default(
+        LINT,
+        (false, data.enum_token.span()),
+        Value::value_bool,
+    )?;
+
+    let (global_accept_ident, _) = global_dict.remove_typed_or_default(
+        ACCEPT_IDENT,
+        (false, data.enum_token.span()),
+        Value::value_bool,
+    )?;
+
+    let de_error = global_dict.remove_typed(DE_ERROR, Value::value_string)?
+        .map(|(de_error, _)| de_error.into_boxed_str());
+
+    let expect_names = global_dict.remove_typed(EXPECT_NAMES, Value::value_string_list)?;
+
+    let (debug_as_scribe, _) = global_dict.remove_typed_or_default(
+        DEBUG_AS_SCRIBE,
+        (false, data.enum_token.span()),
+        Value::value_bool,
+    )?;
+
+    let (scribe_checked, _) = global_dict.remove_typed_or_default(
+        SCRIBE_CHECKED,
+        (false, data.enum_token.span()),
+        Value::value_bool,
+    )?;
+
+    let (normalize_newlines, _) = global_dict.remove_typed_or_default(
+        NORMALIZE_NEWLINES,
+        (false, data.enum_token.span()),
+        Value::value_bool,
+    )?;
+
+    let (nfc, nfc_span) = global_dict.remove_typed_or_default(
+        NFC,
+        (false, data.enum_token.span()),
+        Value::value_bool,
+    )?;
+
+    if nfc && !cfg!(feature = "nfc") {
+        return Err(MacroError::new(
+            format!(
+                "cannot use {} because the nfc feature is not enabled\n\
+                 hint: enable the nfc feature of enumscribe",
+                NFC
+            ),
+            nfc_span,
+        ));
+    }
+
+    let (empty_is_other, _) = global_dict.remove_typed_or_default(
+        EMPTY_IS_OTHER,
+        (false, data.enum_token.span()),
+        Value::value_bool,
+    )?;
+
+    // `serde_optional` on its own uses a feature named "serde"
+    let serde_optional = global_dict
+        .remove_typed(SERDE_OPTIONAL, |value| match value {
+            Value::None => Ok(None),
+            value => value.value_string().map(Some),
+        })?
+        .map(|(feature, _)| feature.unwrap_or_else(|| "serde".to_owned()).into_boxed_str());
+
+    let (sort_expected, _) = global_dict.remove_typed_or_default(
+        SORT_EXPECTED,
+        (false, data.enum_token.span()),
+        Value::value_bool,
+    )?;
+
+    let serde_with = global_dict
+        .remove_typed(SERDE_WITH, Value::value_string)?
+        .map(|(module, span)| match syn::parse_str::<Ident>(&module) {
+            Ok(mut module_ident) => {
+                module_ident.set_span(span);
+                Ok(module_ident)
+            },
+            Err(_) => Err(MacroError::new(
+                format!("{:?} is not a valid module name for {}", module, SERDE_WITH),
+                span,
+            )),
+        })
+        .transpose()?;
+
+    let (phf, phf_span) = global_dict.remove_typed_or_default(
+        PHF,
+        (false, data.enum_token.span()),
+        Value::value_bool,
+    )?;
+
+    if phf && !cfg!(feature = "phf") {
+        return Err(MacroError::new(
+            format!(
+                "cannot use {} because the phf feature is not enabled\n\
+                 hint: enable the phf feature of enumscribe",
+                PHF
+            ),
+            phf_span,
+        ));
+    }
+
+    let scribe_map = global_dict.remove_typed(SCRIBE_MAP, Value::value_path)?
+        .map(|(scribe_map, _)| scribe_map);
+
+    let on_deprecated = global_dict.remove_typed(ON_DEPRECATED, Value::value_path)?
+        .map(|(on_deprecated, _)| on_deprecated);
+
+    let pad = match global_dict.remove_typed(PAD, Value::value_char)? {
+        None => b' ',
+        Some((pad, _)) if pad.is_ascii() => pad as u8,
+        Some((pad, pad_span)) => {
+            return Err(MacroError::new(
+                format!(
+                    "{:?} cannot be used as the {} character because it is not ASCII\n\
+                     explanation: ScribeFixed pads names byte by byte, so the {} character must \
+                     be a single byte",
+                    pad,
+                    PAD,
+                    PAD
+                ),
+                pad_span,
+            ));
+        }
+    };
+
+    let extend = global_dict.remove_typed(EXTEND, Value::value_path)?
+        .map(|(extend, _)| extend);
+
+    let crate_path = global_dict.remove_typed(CRATE_PATH, Value::value_path)?
+        .map(|(crate_path, _)| crate_path)
+        .unwrap_or_else(|| syn::parse_quote!(::enumscribe));
+
+    global_dict.assert_empty()?;
+    drop(global_dict);
+
+    for variant in data.variants.iter() {
+        let variant_span = variant.span();
+
+        // Parse the `#[enumscribe(...)]` attributes for this variant into a single Dict
+        let mut dict = Dict::from_attrs(CRATE_ATTR, MULTI_VALUED_KEYS, &variant.attrs)?;
+
+        // Convert the values in the Dict to the appropriate types
+        let name_opt = dict.remove_typed(NAME, Value::value_string)?;
+
+        let explicit_aliases = dict.remove_typed_all(ALIAS, Value::value_string)?;
+        
+        let (other, other_span) = dict.remove_typed_or_default(
+            OTHER,
+            (false, variant_span),
+            Value::value_bool
+        )?;
+        
+        let (ignore, _) = dict.remove_typed_or_default(
+            IGNORE,
+            (false, variant_span),
+            Value::value_bool
+        )?;
+        
+        let case_insensitive_opt = dict.remove_typed(CASE_INSENSITIVE, value_case_insensitive)?;
+        let case_insensitive_span = case_insensitive_opt.as_ref().map(|(_, span)| *span);
+        let (case_insensitive, ascii_case_insensitive) = case_insensitive_mode(
+            case_insensitive_opt
+        )?;
+
+        let (case_sensitive, case_sensitive_span) = dict.remove_typed_or_default(
+            CASE_SENSITIVE,
+            (false, variant_span),
+            Value::value_bool
+        )?;
+
+        // A variant which is marked as case-insensitive without giving "ascii" uses the same kind
+        // of case-insensitive matching as the enum
+        let ascii_case_insensitive = ascii_case_insensitive || global_ascii_case_insensitive;
+
+        // The span of the variant's own case_insensitive attribute, if it enables it, which lint
+        // checks is not pointless
+        let explicit_case_insensitive_span = case_insensitive_span.filter(|_| case_insensitive);
+
+        let case_insensitive = match (case_insensitive, case_sensitive) {
+            (false, false) => global_case_insensitive,
+            (false, true) => false,
+            (true, false) => true,
+            (true, true) => {
+                return Err(MacroError::new(
+                    format!(
+                        "variant {} cannot be both case_insensitive and case_sensitive",
+                        variant.ident,
+                    ),
+                    case_sensitive_span,
+                ))
+            }
+        };
+
+        let (accept_ident, accept_ident_span) = dict.remove_typed_or_default(
+            ACCEPT_IDENT,
+            (global_accept_ident, variant.ident.span()),
+            Value::value_bool,
+        )?;
+
+        let raw_field_opt = dict.remove_typed(RAW_FIELD, Value::value_string)?;
+        let norm_field_opt = dict.remove_typed(NORM_FIELD, Value::value_string)?;
+
+        let (serialize_with_impl, serialize_with_impl_span) = dict.remove_typed_or_default(
+            SERIALIZE_WITH_IMPL,
+            (false, variant_span),
+            Value::value_bool
+        )?;
+
+        let deny_prefix_opt = dict.remove_typed(DENY_PREFIX, Value::value_string)?;
+
+        let scribe_as_opt = dict.remove_typed(SCRIBE_AS, Value::value_string)?;
+
+        let (serialize_transform, serialize_transform_span) = dict.remove_typed_or_default(
+            SERIALIZE_TRANSFORM,
+            (false, variant_span),
+            Value::value_bool
+        )?;
+
+        let placeholder_opt = dict.remove_typed(PLACEHOLDER, Value::value_string)?;
+
+        let document_str_opt = dict.remove_typed(DOCUMENT_STR, Value::value_string)?;
+
+        let (optional_field, optional_field_span) = dict.remove_typed_or_default(
+            OPTIONAL_FIELD,
+            (false, variant_span),
+            Value::value_bool
+        )?;
+
+        let (fmt_fields, fmt_fields_span) = dict.remove_typed_or_default(
+            FMT_FIELDS,
+            (false, variant_span),
+            Value::value_bool
+        )?;
+
+        let (deprecated, deprecated_span) = dict.remove_typed_or_default(
+            DEPRECATED,
+            (false, variant_span),
+            Value::value_bool
+        )?;
+
+        let message = dict.remove_typed(MESSAGE, Value::value_string)?
+            .map(|(message, _)| message.into_boxed_str());
+
+        let rename = dict.remove_typed(RENAME, Value::value_string)?
+            .map(|(rename, span)| RenameVariant::from_str(&rename, span))
+            .transpose()?
+            .or(global_rename);
+
+        // Return an error if there are any unrecognised keys in the Dict
+        dict.assert_empty()?;
+
+        // Return an error if require_str is used and this variant would otherwise be named after
+        // its identifier
+        if require_str && !ignore && !other && name_opt.is_none() && rename.is_none() {
+            return Err(MacroError::new(
+                format!(
+                    "variant {} must be given a name with {} because the enum uses {}\n\
+                     explanation: {} forbids using the identifier of a variant as its name, so \
+                     that renaming the variant cannot change its string\n\
+                     hint: add #[enumscribe({} = \"...\")] to {}, or use {} on the enum",
+                    variant.ident,
+                    NAME,
+                    REQUIRE_STR,
+                    REQUIRE_STR,
+                    NAME,
+                    variant.ident,
+                    RENAME_ALL
+                ),
+                variant.ident.span(),
+            ));
+        }
+
+        // Return an error if optional_field is used without other
+        if optional_field && !other {
+            return Err(MacroError::new(
+                format!(
+                    "cannot use {} for variant {} because it is not marked as {}",
+                    OPTIONAL_FIELD,
+                    variant.ident,
+                    OTHER
+                ),
+                optional_field_span,
+            ));
+        }
+
+        // Return an error if deprecated is used for a variant which is never unscribed by name
+        if deprecated && (ignore || other || fmt_fields) {
+            let reason = if ignore {
+                format!("it is marked as {}", IGNORE)
+            } else if other {
+                format!("it is marked as {}", OTHER)
+            } else {
+                format!("it uses {}", FMT_FIELDS)
+            };
+            return Err(MacroError::new(
+                format!(
+                    "cannot use {} for variant {} because {}\n\
+                     explanation: only variants which are unscribed from their names can be \
+                     deprecated",
+                    DEPRECATED,
+                    variant.ident,
+                    reason
+                ),
+                deprecated_span,
+            ));
+        }
+
+        if let (false, Some((_, document_str_span))) = (ignore, &document_str_opt) {
+            return Err(MacroError::new(
+                format!(
+                    "cannot use {} for variant {} because it is not marked as {}\n\
+                     explanation: the names of variants which are not ignored are already listed \
+                     in deserialization errors",
+                    DOCUMENT_STR,
+                    variant.ident,
+                    IGNORE
+                ),
+                *document_str_span,
+            ));
+        }
+
+        // Return an error if placeholder is used without ignore, other than to give the string for
+        // an optional `other` field which is `None`
+        if let (false, false, Some((_, placeholder_span))) =
+            (ignore, optional_field, &placeholder_opt)
+        {
+            return Err(MacroError::new(
+                format!(
+                    "cannot use {} for variant {} because it is not marked as {}\n\
+                     hint: {} can also be used alongside {}, {}",
+                    PLACEHOLDER,
+                    variant.ident,
+                    IGNORE,
+                    PLACEHOLDER,
+                    OTHER,
+                    OPTIONAL_FIELD
+                ),
+                *placeholder_span,
+            ));
+        }
+
+        let scribe_variant = if ignore {
+            let placeholder = placeholder_opt.map(|(placeholder, _)| placeholder.into_boxed_str());
+            let document_str = document_str_opt
+                .map(|(document_str, _)| document_str.into_boxed_str());
+            Variant {
+                data: variant,
+                v_type: VariantType::Ignore(IgnoredVariant { placeholder, document_str }),
+                message,
+                span: variant_span,
+            }
+        } else if other {
+            // Return an error if there is already an "other" variant for this enum
+            if other_variant {
+                return Err(MacroError::new(
+                    format!("cannot have multiple variants marked as {}", OTHER),
+                    other_span,
+                ));
+            }
+
+            other_variant = true;
+
+            if fmt_fields {
+                return Err(MacroError::new(
+                    format!(
+                        "cannot use {} for variant {} because it is marked as {}",
+                        FMT_FIELDS,
+                        variant.ident,
+                        OTHER
+                    ),
+                    fmt_fields_span,
+                ));
+            }
+
+            // Return an error if a str name is provided for this variant
+            if let Some((_, name_span)) = name_opt {
+                return Err(MacroError::new(
+                    format!(
+                        "cannot use {} for variant {} because it is marked as {}",
+                        NAME,
+                        variant.ident,
+                        OTHER
+                    ),
+                    name_span,
+                ));
+            }
+
+            // Return an error if any aliases are provided for this variant
+            if let Some((_, alias_span)) = explicit_aliases.first() {
+                return Err(MacroError::new(
+                    format!(
+                        "cannot use {} for variant {} because it is marked as {}",
+                        ALIAS,
+                        variant.ident,
+                        OTHER
+                    ),
+                    *alias_span,
+                ));
+            }
+
+            let deny_prefix = deny_prefix_opt.map(|(deny_prefix, _)| deny_prefix.into_boxed_str());
+
+            if let Some((_, scribe_as_span)) = &scribe_as_opt {
+                if serialize_with_impl {
+                    return Err(MacroError::new(
+                        format!(
+                            "cannot use both {} and {} for variant {}",
+                            SCRIBE_AS,
+                            SERIALIZE_WITH_IMPL,
+                            variant.ident
+                        ),
+                        *scribe_as_span,
+                    ));
+                }
+            }
+
+            let scribe_as = scribe_as_opt.map(|(scribe_as, _)| scribe_as.into_boxed_str());
+
+            let serialize_transform = if serialize_transform {
+                if serialize_with_impl || scribe_as.is_some() {
+                    return Err(MacroError::new(
+                        format!(
+                            "cannot use {} for variant {} because it uses {}",
+                            SERIALIZE_TRANSFORM,
+                            variant.ident,
+                            if serialize_with_impl { SERIALIZE_WITH_IMPL } else { SCRIBE_AS }
+                        ),
+                        serialize_transform_span,
+                    ));
+                }
+
+                match rename {
+                    Some(rename) => Some(rename),
+                    None => return Err(MacroError::new(
+                        format!(
+                            "cannot use {} for variant {} because there is no case to convert to\n\
+                             hint: add {} to the enum",
+                            SERIALIZE_TRANSFORM,
+                            variant.ident,
+                            RENAME_ALL
+                        ),
+                        serialize_transform_span,
+                    )),
+                }
+            } else {
+                None
+            };
+
+            let none_placeholder = if optional_field {
+                let none_placeholder = placeholder_opt
+                    .map(|(placeholder, _)| placeholder)
+                    .unwrap_or_default();
+                Some(none_placeholder.into_boxed_str())
+            } else {
+                None
+            };
+
+            let borrowed_field = variant
+                .fields
+                .iter()
+                .map(|field| &field.ty)
+                .find(|ty| is_borrowed_type(ty));
+
+            let other_variant = match (raw_field_opt, norm_field_opt) {
+                (None, None) => {
+                    // Return an error if this variant doesn't have exactly one field
+                    if variant.fields.len() != 1 {
+                        return Err(MacroError::new(
+                            format!(
+                                "the variant {} must have exactly one field because it is marked \
+                                 as {}",
+                                variant.ident,
+                                OTHER
+                            ),
+                            variant_span,
+                        ));
+                    }
+
+                    // Get the name of the variant's field (or None if it is unnamed)
+                    let field = variant.fields.iter().next().unwrap();
+                    let field_name = field.ident.as_ref();
+
+                    OtherVariant {
+                        field_name,
+                        norm_field_name: None,
+                        serialize_with_impl,
+                        deny_prefix,
+                        scribe_as,
+                        serialize_transform,
+                        none_placeholder,
+                        borrowed_field,
+                        field_ty: &field.ty,
+                    }
+                },
+
+                (Some((raw_field, raw_field_span)), Some((norm_field, norm_field_span))) => {
+                    if optional_field {
+                        return Err(MacroError::new(
+                            format!(
+                                "cannot use {} for variant {} because it uses {} and {}",
+                                OPTIONAL_FIELD,
+                                variant.ident,
+                                RAW_FIELD,
+                                NORM_FIELD
+                            ),
+                            raw_field_span,
+                        ));
+                    }
+
+                    // Return an error if this variant doesn't have exactly two named fields
+                    if variant.fields.len() != 2 || !matches!(variant.fields, Fields::Named(_)) {
+                        return Err(MacroError::new(
+                            format!(
+                                "the variant {} must have exactly two named fields because it \
+                                 uses {} and {}",
+                                variant.ident,
+                                RAW_FIELD,
+                                NORM_FIELD
+                            ),
+                            variant_span,
+                        ));
+                    }
+
+                    if raw_field == norm_field {
+                        return Err(MacroError::new(
+                            format!("{} and {} must be different fields", RAW_FIELD, NORM_FIELD),
+                            norm_field_span,
+                        ));
+                    }
+
+                    let raw_field_name = find_named_field(variant, &raw_field, RAW_FIELD)?;
+                    let raw_field_ty = variant
+                        .fields
+                        .iter()
+                        .find(|field| field.ident.as_ref() == Some(raw_field_name))
+                        .map(|field| &field.ty)
+                        .unwrap();
+
+                    OtherVariant {
+                        field_name: Some(raw_field_name),
+                        norm_field_name: Some(find_named_field(variant, &norm_field, NORM_FIELD)?),
+                        serialize_with_impl,
+                        deny_prefix,
+                        scribe_as,
+                        serialize_transform,
+                        none_placeholder: None,
+                        borrowed_field,
+                        field_ty: raw_field_ty,
+                    }
+                },
+
+                (Some((_, span)), None) | (None, Some((_, span))) => {
+                    return Err(MacroError::new(
+                        format!(
+                            "{} and {} must be used together for variant {}",
+                            RAW_FIELD,
+                            NORM_FIELD,
+                            variant.ident
+                        ),
+                        span,
+                    ));
+                },
+            };
+
+            Variant {
+                data: variant,
+                v_type: VariantType::Other(other_variant),
+                message,
+                span: variant_span,
+            }
+        } else {
+            // Return an error if raw_field or norm_field is used without other
+            if let Some((_, span)) = raw_field_opt.as_ref().or(norm_field_opt.as_ref()) {
+                return Err(MacroError::new(
+                    format!(
+                        "cannot use {} or {} for variant {} because it is not marked as {}",
+                        RAW_FIELD,
+                        NORM_FIELD,
+                        variant.ident,
+                        OTHER
+                    ),
+                    *span,
+                ));
+            }
+
+            if serialize_with_impl {
+                return Err(MacroError::new(
+                    format!(
+                        "cannot use {} for variant {} because it is not marked as {}",
+                        SERIALIZE_WITH_IMPL,
+                        variant.ident,
+                        OTHER
+                    ),
+                    serialize_with_impl_span,
+                ));
+            }
+
+            if serialize_transform {
+                return Err(MacroError::new(
+                    format!(
+                        "cannot use {} for variant {} because it is not marked as {}",
+                        SERIALIZE_TRANSFORM,
+                        variant.ident,
+                        OTHER
+                    ),
+                    serialize_transform_span,
+                ));
+            }
+
+            if let Some((_, deny_prefix_span)) = deny_prefix_opt {
+                return Err(MacroError::new(
+                    format!(
+                        "cannot use {} for variant {} because it is not marked as {}",
+                        DENY_PREFIX,
+                        variant.ident,
+                        OTHER
+                    ),
+                    deny_prefix_span,
+                ));
+            }
+
+            if let Some((_, scribe_as_span)) = scribe_as_opt {
+                return Err(MacroError::new(
+                    format!(
+                        "cannot use {} for variant {} because it is not marked as {}\n\
+                         hint: use {} to set the string of a variant which is not marked as {}",
+                        SCRIBE_AS,
+                        variant.ident,
+                        OTHER,
+                        NAME,
+                        OTHER
+                    ),
+                    scribe_as_span,
+                ));
+            }
+
+            // Use the str name if one is provided, otherwise use the variant's name
+            let (name, name_span) = match name_opt {
+                Some((name, name_span)) => (name, name_span),
+                None => {
+                    let name_span = variant.ident.span();
+                    let mut name = variant.ident.to_string();
+                    if let Some(rename) = rename {
+                        name = rename.apply(&name);
+                    }
+                    (name, name_span)
+                },
+            };
+
+            // A variant using fmt_fields is never unscribed, so its name is a format string rather
+            // than a name which needs to be distinct from the others
+            if fmt_fields {
+                if let Some((_, alias_span)) = explicit_aliases.first() {
+                    return Err(MacroError::new(
+                        format!(
+                            "cannot use {} for variant {} because it uses {}",
+                            ALIAS,
+                            variant.ident,
+                            FMT_FIELDS
+                        ),
+                        *alias_span,
+                    ));
+                }
+
+                if variant.fields.is_empty() {
+                    return Err(MacroError::new(
+                        format!(
+                            "the variant {} must have at least one field because it uses {}",
+                            variant.ident,
+                            FMT_FIELDS
+                        ),
+                        fmt_fields_span,
+                    ));
+                }
+
+                let bindings = variant
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, field)| match &field.ident {
+                        Some(field_ident) => field_ident.clone(),
+                        None => Ident::new(&format!("__enumscribe_field_{}", i), Span::call_site()),
+                    })
+                    .collect();
+
+                variants.push(Variant {
+                    data: variant,
+                    v_type: VariantType::Formatted(FormattedVariant {
+                        format: LitStr::new(&name, name_span),
+                        bindings,
+                        named_fields: matches!(variant.fields, Fields::Named(_)),
+                    }),
+                    message,
+                    span: variant_span,
+                });
+
+                continue;
+            }
+
+            // Do not allow duplicate names, including names which only become the same once their
+            // newlines are normalized or they are converted to NFC
+            let match_name = |name: &str| normalized_name(name, normalize_newlines, nfc);
+
+            taken_names.insert(
+                &match_name(&name),
+                case_insensitive,
+                ascii_case_insensitive,
+                name_span,
+            )?;
+            variant_names.push((name.clone(), name_span, &variant.ident));
+
+            // Also accept any aliases given with `alias = "..."`
+            let mut aliases = Vec::new();
+            for (alias, alias_span) in explicit_aliases {
+                taken_names.insert(
+                    &match_name(&alias),
+                    case_insensitive,
+                    ascii_case_insensitive,
+                    alias_span,
+                )?;
+                aliases.push(alias.into_boxed_str());
+            }
+
+            // Also accept the variant's identifier if it differs from its name
+            if accept_ident {
+                let ident_name = variant.ident.to_string();
+
+                // If the name is case-insensitive and only differs from the identifier by case,
+                // then the identifier is already matched by the name.
+                let ident_covered = ident_name == name
+                    || (case_insensitive && ascii_case_insensitive
+                        && ident_name.eq_ignore_ascii_case(&name))
+                    || (case_insensitive && !ascii_case_insensitive
+                        && char_wise_uppercase(&ident_name) == char_wise_uppercase(&name));
+
+                if !ident_covered {
+                    taken_names.insert(
+                        &ident_name,
+                        case_insensitive,
+                        ascii_case_insensitive,
+                        accept_ident_span,
+                    )?;
+                    aliases.push(ident_name.into_boxed_str());
+                }
+            }
+
+            // With lint, return an error if the variant is marked as case_insensitive but none of
+            // its names has a different uppercase or lowercase form, as that is likely a mistake.
+            // In ascii mode, only the case of ASCII letters is ignored, so other cased characters
+            // do not count.
+            if let Some(case_insensitive_span) = explicit_case_insensitive_span.filter(|_| lint) {
+                let names = iter::once(name.as_str()).chain(aliases.iter().map(|alias| &**alias));
+                let (any_cased, cased_description, uncased_names) = if ascii_case_insensitive {
+                    (
+                        names.clone().any(has_ascii_letters),
+                        "ASCII letters",
+                        "names without ASCII letters",
+                    )
+                } else {
+                    (
+                        names.clone().any(has_cased_chars),
+                        "cased characters",
+                        "names which are the same in uppercase and lowercase",
+                    )
+                };
+
+                if !any_cased {
+                    return Err(MacroError::new(
+                        format!(
+                            "the variant {} is marked as {}, but none of its names ({}) have \
+                             {}\n\
+                             explanation: {} has no effect on {}\n\
+                             hint: remove {} from {}, or remove {} from the enum to allow this",
+                            variant.ident,
+                            CASE_INSENSITIVE,
+                            names.map(|name| format!("{:?}", name)).collect::<Vec<_>>().join(", "),
+                            cased_description,
+                            CASE_INSENSITIVE,
+                            uncased_names,
+                            CASE_INSENSITIVE,
+                            variant.ident,
+                            LINT
+                        ),
+                        case_insensitive_span,
+                    ));
+                }
+            }
+
+            // Return an error if the variant has any fields which hold data. PhantomData fields
+            // are allowed, since the variant can be constructed without any data for them.
+            if !variant.fields.iter().all(|field| is_phantom_data(&field.ty)) {
+                return Err(MacroError::new(
+                    format!(
+                        "the variant {} must not have any fields\n\
+                         hint: if you do not want to remove {}\'s fields, try using \
+                         #[enumscribe(ignore)] for {}",
+                        variant.ident, variant.ident, variant.ident
+                    ),
+                    variant_span,
+                ));
+            }
+
+            // The variant is allowed to have an empty constructor, so find out if it has one
+            // and, if so, what type of constructor (parentheses or braces)
+            let constructor = match &variant.fields {
+                Fields::Named(fields) => VariantConstructor::Brace(
+                    fields.named.iter().filter_map(|field| field.ident.clone()).collect()
+                ),
+                Fields::Unnamed(fields) => VariantConstructor::Paren(fields.unnamed.len()),
+                Fields::Unit => VariantConstructor::None,
+            };
+
+            let named = NamedVariant::new(
+                name.into_boxed_str(),
+                aliases,
+                constructor,
+                case_insensitive,
+                ascii_case_insensitive,
+                scribe_map.clone(),
+                deprecated,
+                normalize_newlines,
+                nfc,
+            );
+            let v_type = VariantType::Named(named);
+
+            Variant {
+                data: variant,
+                v_type,
+                message,
+                span: variant_span,
+            }
+        };
+
+        variants.push(scribe_variant);
+    }
+
+    if lint {
+        check_norm_field_case_sensitive(&variants)?;
+    }
+
+    if let Some((expect_names, expect_names_span)) = expect_names {
+        check_expected_names(&variant_names, &expect_names, expect_names_span)?;
+    }
+
+    Ok(Enum::new(
+        variants.into_boxed_slice(),
+        serialize_as,
+        serde_native,
+        ci_overflow,
+        de_error,
+        debug_as_scribe,
+        scribe_checked,
+        normalize_newlines,
+        nfc,
+        empty_is_other,
+        serde_optional,
+        sort_expected,
+        serde_with,
+        on_deprecated,
+        pad,
+        extend,
+        crate_path,
+        phf,
+    ))
+}
+
+/// Returns an error if the names of the named variants, given as `(name, name_span, ident)`, are
+/// not exactly the names listed by `#[enumscribe(expect_names(...))]`, ignoring order.
+fn check_expected_names(
+    variant_names: &[(String, Span, &Ident)],
+    expect_names: &[String],
+    expect_names_span: Span,
+) -> MacroResult<()> {
+    let expected = expect_names.iter().map(String::as_str).collect::<HashSet<_>>();
+
+    if let Some((name, name_span, ident)) = variant_names
+        .iter()
+        .find(|(name, _, _)| !expected.contains(name.as_str()))
+    {
+        return Err(MacroError::new(
+            format!(
+                "the variant {} has the name {:?}, which is not listed in {}",
+                ident, name, EXPECT_NAMES
+            ),
+            *name_span,
+        ));
+    }
+
+    let actual = variant_names
+        .iter()
+        .map(|(name, _, _)| name.as_str())
+        .collect::<HashSet<_>>();
+
+    let mut reported = HashSet::new();
+    let missing = expect_names
+        .iter()
+        .filter(|name| !actual.contains(name.as_str()) && reported.insert(name.as_str()))
+        .map(|name| format!("{:?}", name))
+        .collect::<Vec<_>>();
+
+    if !missing.is_empty() {
+        let message = if missing.len() == 1 {
+            format!("no variant has the name {}, which is listed in {}", missing[0], EXPECT_NAMES)
+        } else {
+            format!(
+                "no variants have the names {}, which are listed in {}",
+                missing.join(", "),
+                EXPECT_NAMES
+            )
+        };
+
+        return Err(MacroError::new(message, expect_names_span));
+    }
+
+    Ok(())
+}
+
+/// Returns an error if the `other` variant stores a lowercase copy of unmatched strings in its
+/// `norm_field`, and a case-sensitive variant has a name with cased characters. Named variants
+/// take priority, so nothing is unreachable, but strings which only differ from the name by case
+/// are stored in `other` with a normalized form equal to the lowercase name, which is likely to be
+/// confused with the variant.
+fn check_norm_field_case_sensitive(variants: &[Variant]) -> MacroResult<()> {
+    let other = variants.iter().find_map(|variant| match &variant.v_type {
+        VariantType::Other(other) => other.norm_field_name().map(|_| variant),
+        _ => None,
+    });
+
+    let other = match other {
+        Some(other) => other,
+        None => return Ok(()),
+    };
+
+    for variant in variants {
+        let named = match &variant.v_type {
+            VariantType::Named(named) if !named.case_insensitive() => named,
+            _ => continue,
+        };
+
+        if let Some(name) = named.names().find(|name| has_cased_chars(name)) {
+            let lowercase_name = name.to_lowercase();
+            let example = if lowercase_name != name {
+                lowercase_name
+            } else {
+                name.to_uppercase()
+            };
+
+            return Err(MacroError::new(
+                format!(
+                    "the variant {} has the case-sensitive name {:?}, but {} stores a lowercase \
+                     copy of unmatched strings in its {}\n\
+                     explanation: strings such as {:?} do not match {:?} and are stored in {}, \
+                     but they have the same lowercase form as {:?}\n\
+                     hint: mark {} as {}, or remove {} from the enum to allow this",
+                    variant.data.ident,
+                    name,
+                    other.data.ident,
+                    NORM_FIELD,
+                    example,
+                    name,
+                    other.data.ident,
+                    name,
+                    variant.data.ident,
+                    CASE_INSENSITIVE,
+                    LINT
+                ),
+                variant.span,
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads the value of a `case_insensitive` key, which may be a boolean or the string "ascii".
+fn value_case_insensitive(value: &Value) -> ValueTypeResult<Result<bool, String>> {
+    match value {
+        Value::Lit(Lit::Str(lit_str)) => Ok(Err(lit_str.value())),
+        value => value.value_bool().map(Ok),
+    }
+}
+
+/// Whether `s` contains any characters which are different in uppercase or lowercase, so that
+/// matching it case-insensitively is different from matching it exactly.
+fn has_cased_chars(s: &str) -> bool {
+    s.to_uppercase() != s || s.to_lowercase() != s
+}
+
+/// Whether `s` contains any ASCII letters, so that matching it with `case_insensitive = "ascii"`
+/// is different from matching it exactly.
+fn has_ascii_letters(s: &str) -> bool {
+    s.bytes().any(|b| b.is_ascii_alphabetic())
+}
+
+/// Returns whether a `case_insensitive` key makes names case-insensitive, and whether it only
+/// ignores the case of ASCII letters.
+fn case_insensitive_mode(
+    value: Option<(Result<bool, String>, Span)>,
+) -> MacroResult<(bool, bool)> {
+    match value {
+        None => Ok((false, false)),
+        Some((Ok(case_insensitive), _)) => Ok((case_insensitive, false)),
+        Some((Err(mode), _)) if mode == "ascii" => Ok((true, true)),
+        Some((Err(mode), span)) => Err(MacroError::new(
+            format!(
+                "invalid {} value {:?} (allowed values are: true, false, ascii)",
+                CASE_INSENSITIVE,
+                mode
+            ),
+            span,
+        )),
+    }
+}
+
+/// The set of names used by the variants of an enum, used to detect duplicate names.
+struct TakenNames {
+    names: HashSet<String>,
+    /// Maps the uppercase form of each case-insensitive name to the original name, for names which
+    /// are matched by converting strings to uppercase.
+    insensitive_names: HashMap<String, String>,
+    /// Maps the ASCII uppercase form of each name which only ignores the case of ASCII letters to
+    /// the original name.
+    ascii_insensitive_names: HashMap<String, String>,
+    /// Maps the uppercase form of each name which only ignores the case of ASCII letters to the
+    /// original name.
+    ascii_insensitive_names_upper: HashMap<String, String>,
+}
+
+impl TakenNames {
+    fn new() -> Self {
+        Self {
+            names: HashSet::new(),
+            insensitive_names: HashMap::new(),
+            ascii_insensitive_names: HashMap::new(),
+            ascii_insensitive_names_upper: HashMap::new(),
+        }
+    }
+
+    /// Adds a name to the set, returning an error if it conflicts with a name already in the set.
+    /// `ascii` is whether a case-insensitive name only ignores the case of ASCII letters.
+    fn insert(
+        &mut self,
+        name: &str,
+        case_insensitive: bool,
+        ascii: bool,
+        span: Span,
+    ) -> MacroResult<()> {
+        if self.names.contains(name) {
+            return Err(MacroError::new(format!("duplicate name {:?}", name), span));
+        }
+
+        self.names.insert(name.to_owned());
+
+        // Extra duplicate checking for case-insensitive names. If two case-insensitive names are
+        // equal when case is ignored, then some strings would match both of them, so whichever is
+        // checked second could never be reached for those strings. A case-sensitive name may
+        // overlap with a case-insensitive one, since case-sensitive names are always checked
+        // first, so both can be reached. Case-insensitive matching compares uppercase strings, so
+        // the names are compared in the same way here (e.g. "groß" and "GROSS" are considered
+        // equal). Only two names which both ignore just the case of ASCII letters are compared
+        // with their ASCII uppercase forms instead.
+        if !case_insensitive {
+            return Ok(());
+        }
+
+        let uppercase_name = char_wise_uppercase(name).into_string();
+
+        let taken = if ascii {
+            let ascii_uppercase_name = name.to_ascii_uppercase();
+            let taken = self.ascii_insensitive_names.get(&ascii_uppercase_name)
+                .or_else(|| self.insensitive_names.get(&uppercase_name))
+                .cloned();
+            self.ascii_insensitive_names.insert(ascii_uppercase_name, name.to_owned());
+            self.ascii_insensitive_names_upper.insert(uppercase_name, name.to_owned());
+            taken
+        } else {
+            let taken = self.insensitive_names.get(&uppercase_name)
+                .or_else(|| self.ascii_insensitive_names_upper.get(&uppercase_name))
+                .cloned();
+            self.insensitive_names.insert(uppercase_name, name.to_owned());
+            taken
+        };
+
+        if let Some(taken) = taken {
+            return Err(MacroError::new(
+                format!(
+                    "duplicate name {:?}\n\
+                     explanation: {:?} and {:?} are equal when case is ignored, and both are \
+                     case-insensitive, so a string could match both of them\n\
+                     hint: use a different name, or use #[enumscribe(case_sensitive)] for one of \
+                     the variants",
+                    name, name, taken
+                ),
+                span,
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+fn find_named_field<'a>(
+    variant: &'a syn::Variant,
+    field_name: &str,
+    key: &str,
+) -> MacroResult<&'a Ident>
+{
+    variant
+        .fields
+        .iter()
+        .filter_map(|field| field.ident.as_ref())
+        .find(|ident| *ident == field_name)
+        .ok_or_else(|| MacroError::new(
+            format!(
+                "the variant {} has no field named {} (specified by {})",
+                variant.ident,
+                field_name,
+                key
+            ),
+            variant.span(),
+        ))
+}
+
+/// Returns whether the given type borrows data for some lifetime, i.e. whether it contains a
+/// reference or a lifetime other than `'static`.
+/// Returns true if `ty` is `PhantomData`, which is recognised by the last segment of its path
+/// (so `PhantomData<T>`, `core::marker::PhantomData<T>` and `std::marker::PhantomData<T>` are all
+/// accepted).
+fn is_phantom_data(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) => {
+            type_path.qself.is_none()
+                && matches!(
+                    type_path.path.segments.last(),
+                    Some(segment) if segment.ident == "PhantomData"
+                )
+        },
+        Type::Group(group) => is_phantom_data(&group.elem),
+        Type::Paren(paren) => is_phantom_data(&paren.elem),
+        _ => false,
+    }
+}
+
+fn is_borrowed_type(ty: &Type) -> bool {
+    fn is_borrowed_tokens(tokens: TokenStream2) -> bool {
+        let mut tokens = tokens.into_iter().peekable();
+        while let Some(token) = tokens.next() {
+            let borrowed = match token {
+                TokenTree::Punct(punct) => match punct.as_char() {
+                    '&' => true,
+                    '\'' => !matches!(tokens.peek(), Some(TokenTree::Ident(ident)) if ident == "static"),
+                    _ => false,
+                },
+                TokenTree::Group(group) => is_borrowed_tokens(group.stream()),
+                _ => false,
+            };
+
+            if borrowed {
+                return true;
+            }
+        }
+        false
+    }
+
+    is_borrowed_tokens(ty.to_token_stream())
+}
+
+/// Applies the normalizations given by `normalize_newlines` and `nfc` to the given name, in the
+/// same order as the code generated by `gen_unscribe_match`.
+fn normalized_name(name: &str, normalize_newlines: bool, nfc: bool) -> String {
+    let name = if nfc {
+        nfc_normalized(name)
+    } else {
+        name.to_owned()
+    };
+
+    if normalize_newlines {
+        newline_normalized(&name)
+    } else {
+        name
+    }
+}
+
+/// Replaces `"\r\n"` with `"\n"`, in the same way as
+/// `enumscribe::internal::capped_string::CowCappedString::normalize_newlines`.
+fn newline_normalized(s: &str) -> String {
+    s.replace("\r\n", "\n")
+}
+
+/// Converts the string to Unicode Normalization Form C, in the same way as
+/// `enumscribe::internal::capped_string::CowCappedString::nfc`.
+#[cfg(feature = "nfc")]
+fn nfc_normalized(s: &str) -> String {
+    unicode_normalization::UnicodeNormalization::nfc(s).collect()
+}
+
+#[cfg(not(feature = "nfc"))]
+fn nfc_normalized(_s: &str) -> String {
+    unreachable!("nfc is rejected by parse_enum without the nfc feature")
+}
+
+fn char_wise_uppercase(s: &str) -> Box<str> {
+    // Use the same uppercase algorithm as `enumscribe::internal::capped_string`.
+    s.chars()
+        .flat_map(char::to_uppercase)
+        .collect::<String>()
+        .into_boxed_str()
+}
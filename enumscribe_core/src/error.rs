@@ -0,0 +1,116 @@
+use proc_macro::TokenStream;
+use std::borrow::Cow;
+use std::error;
+use std::fmt;
+use std::result;
+
+use proc_macro2::Span;
+use quote::quote_spanned;
+use syn::Error;
+
+use crate::TokenStream2;
+
+#[derive(Clone, Debug)]
+pub struct MacroError {
+    pub message: Cow<'static, str>,
+    pub span: Span,
+    /// Further errors to report along with this one.
+    others: Vec<MacroError>,
+}
+
+pub type MacroResult<T> = result::Result<T, MacroError>;
+
+impl MacroError {
+    pub fn new<T>(message: T, span: Span) -> Self
+    where
+        T: Into<Cow<'static, str>>,
+    {
+        MacroError {
+            message: message.into(),
+            span,
+            others: Vec::new(),
+        }
+    }
+
+    /// Adds another error to be reported along with this one, so that several problems can be
+    /// reported from a single macro invocation.
+    pub fn combine(&mut self, mut other: MacroError) {
+        let other_others = std::mem::take(&mut other.others);
+        self.others.push(other);
+        self.others.extend(other_others);
+    }
+
+    /// This error followed by every error combined with it.
+    pub fn iter(&self) -> impl Iterator<Item = &MacroError> {
+        Some(self).into_iter().chain(self.others.iter())
+    }
+
+    pub fn to_token_stream(&self) -> TokenStream {
+        self.to_token_stream2().into()
+    }
+
+    pub fn to_token_stream2(&self) -> TokenStream2 {
+        self.iter()
+            .map(|err| {
+                let message = &err.message;
+                quote_spanned! {
+                    err.span => ::core::compile_error!(#message);
+                }
+            })
+            .collect()
+    }
+}
+
+impl From<syn::Error> for MacroError {
+    fn from(err: Error) -> Self {
+        let mut errs = err
+            .into_iter()
+            .map(|err| MacroError::new(err.to_string(), err.span()));
+
+        // A syn::Error always contains at least one message
+        let mut macro_err = errs.next().expect("empty syn::Error");
+        for other_err in errs {
+            macro_err.combine(other_err);
+        }
+        macro_err
+    }
+}
+
+impl From<MacroError> for TokenStream {
+    fn from(err: MacroError) -> Self {
+        err.to_token_stream()
+    }
+}
+
+impl From<MacroError> for TokenStream2 {
+    fn from(err: MacroError) -> Self {
+        err.to_token_stream2()
+    }
+}
+
+impl fmt::Display for MacroError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        for other in &self.others {
+            write!(f, "\n{}", other.message)?;
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for MacroError {}
+
+#[derive(Clone, Debug)]
+pub struct ValueTypeError {
+    pub message: Cow<'static, str>,
+}
+
+pub type ValueTypeResult<T> = result::Result<T, ValueTypeError>;
+
+impl fmt::Display for ValueTypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl error::Error for ValueTypeError {}
@@ -0,0 +1,49 @@
+//! The keys which may be given in `#[enumscribe(...)]` attributes.
+
+pub const CRATE_ATTR: &str = "enumscribe";
+
+pub const NAME: &str = "str";
+pub const OTHER: &str = "other";
+pub const IGNORE: &str = "ignore";
+pub const CASE_INSENSITIVE: &str = "case_insensitive";
+pub const CASE_SENSITIVE: &str = "case_sensitive";
+pub const RENAME: &str = "rename";
+pub const RENAME_ALL: &str = "rename_all";
+pub const RAW_FIELD: &str = "raw_field";
+pub const NORM_FIELD: &str = "norm_field";
+pub const SERIALIZE_AS: &str = "serialize_as";
+pub const SERIALIZE_WITH_IMPL: &str = "serialize_with_impl";
+pub const CI_OVERFLOW: &str = "ci_overflow";
+pub const ACCEPT_IDENT: &str = "accept_ident";
+pub const MESSAGE: &str = "message";
+pub const DENY_PREFIX: &str = "deny_prefix";
+pub const SCRIBE_MAP: &str = "scribe_map";
+pub const PHF: &str = "phf";
+pub const DE_ERROR: &str = "de_error";
+pub const FMT_FIELDS: &str = "fmt_fields";
+pub const DEBUG_AS_SCRIBE: &str = "debug_as_scribe";
+pub const EXPECT_NAMES: &str = "expect_names";
+pub const NORMALIZE_NEWLINES: &str = "normalize_newlines";
+pub const NFC: &str = "nfc";
+pub const ALIAS: &str = "alias";
+pub const SCRIBE_AS: &str = "scribe_as";
+pub const EMPTY_IS_OTHER: &str = "empty_is_other";
+pub const PLACEHOLDER: &str = "placeholder";
+pub const SERDE_OPTIONAL: &str = "serde_optional";
+pub const SCRIBE_CHECKED: &str = "scribe_checked";
+pub const CRATE_PATH: &str = "crate";
+pub const SORT_EXPECTED: &str = "sort_expected";
+pub const OPTIONAL_FIELD: &str = "optional_field";
+pub const SERDE_WITH: &str = "serde_with";
+pub const DEPRECATED: &str = "deprecated";
+pub const ON_DEPRECATED: &str = "on_deprecated";
+pub const REQUIRE_STR: &str = "require_str";
+pub const PAD: &str = "pad";
+pub const SERDE_NATIVE: &str = "serde_native";
+pub const SERIALIZE_TRANSFORM: &str = "serialize_transform";
+pub const DOCUMENT_STR: &str = "document_str";
+pub const EXTEND: &str = "extend";
+pub const LINT: &str = "lint";
+
+/// Keys which may be given more than once, accumulating every value rather than causing an error.
+pub const MULTI_VALUED_KEYS: &[&str] = &[ALIAS];
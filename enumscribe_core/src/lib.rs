@@ -0,0 +1,56 @@
+//! The attribute parsing used by [`enumscribe_derive`](https://docs.rs/enumscribe_derive), for
+//! use by other procedural macros which want to accept the same `#[enumscribe(...)]` attributes.
+//!
+//! [parse_enum] reads the attributes of an enum and its variants, checks them in the same way as
+//! the enumscribe derive macros do, and returns an [Enum] describing the name of each variant and
+//! how strings should be matched against it.
+//!
+//! ```
+//! use enumscribe_core::{parse_enum, VariantType};
+//! use syn::{Data, DeriveInput};
+//!
+//! let input: DeriveInput = syn::parse_quote! {
+//!     #[enumscribe(case_insensitive)]
+//!     enum Airport {
+//!         #[enumscribe(str = "LHR")]
+//!         Heathrow,
+//!         #[enumscribe(other)]
+//!         Other(String),
+//!     }
+//! };
+//!
+//! let data = match &input.data {
+//!     Data::Enum(data) => data,
+//!     _ => unreachable!(),
+//! };
+//!
+//! let parsed_enum = parse_enum(data, &input.attrs).unwrap();
+//! let heathrow = parsed_enum.variants()[0].v_type.as_named().unwrap();
+//! assert_eq!(heathrow.name(), "LHR");
+//! assert!(heathrow.case_insensitive());
+//! assert!(matches!(parsed_enum.variants()[1].v_type, VariantType::Other(_)));
+//! ```
+//!
+//! # Stability
+//!
+//! This crate exposes the internals of `enumscribe_derive`, and its API changes whenever a new
+//! attribute is added or the generated code needs different information. Unlike `enumscribe`
+//! itself, breaking changes may be made in any release, so depend on an exact version
+//! (`enumscribe_core = "=0.4.0"`) and use the same version as the `enumscribe_derive` your users
+//! will have, so that both accept the same attributes.
+
+extern crate proc_macro;
+
+mod attribute;
+mod enums;
+mod error;
+pub mod keys;
+mod rename;
+
+pub use enums::{parse_enum, CiOverflow, Enum, SerializeAs};
+pub use enums::{FormattedVariant, IgnoredVariant, NamedVariant, OtherVariant};
+pub use enums::{Variant, VariantConstructor, VariantType};
+pub use error::{MacroError, MacroResult};
+pub use rename::RenameVariant;
+
+type TokenStream2 = proc_macro2::TokenStream;
@@ -3,7 +3,7 @@ use proc_macro2::Span;
 use crate::error::{MacroResult, MacroError};
 
 #[derive(Clone, Copy, Debug)]
-pub(crate) enum RenameVariant {
+pub enum RenameVariant {
     Lower,
     Upper,
     Pascal,
@@ -15,7 +15,7 @@ pub(crate) enum RenameVariant {
 }
 
 impl RenameVariant {
-    pub(crate) fn from_str(s: &str, span: Span) -> MacroResult<Self> {
+    pub fn from_str(s: &str, span: Span) -> MacroResult<Self> {
         // Shame we can't use enumscribe for this...
         match s {
             "lowercase" => Ok(Self::Lower),
@@ -44,7 +44,7 @@ impl RenameVariant {
         }
     }
     
-    pub(crate) fn apply(self, s: &str) -> String {
+    pub fn apply(self, s: &str) -> String {
         match self {
             RenameVariant::Lower => s.to_lowercase(),
             RenameVariant::Upper => s.to_uppercase(),
@@ -6,30 +6,28 @@
 
 #![deny(missing_docs)]
 
+use std::collections::HashMap;
+#[cfg(feature = "phf")]
+use std::collections::HashSet;
+
 use proc_macro::TokenStream;
 
-use proc_macro2::Ident;
+use proc_macro2::{Ident, Literal};
 use quote::quote;
+use syn::ext::IdentExt;
+use syn::spanned::Spanned;
 use syn::{Attribute, Data, DataEnum, DeriveInput};
 
-use error::{MacroError, MacroResult};
-
-use crate::enums::{Enum, Variant, VariantType};
-
-mod attribute;
-mod enums;
-mod error;
-mod rename;
-
-const CRATE_ATTR: &'static str = "enumscribe";
-
-const NAME: &'static str = "str";
-const OTHER: &'static str = "other";
-const IGNORE: &'static str = "ignore";
-const CASE_INSENSITIVE: &'static str = "case_insensitive";
-const CASE_SENSITIVE: &'static str = "case_sensitive";
-const RENAME: &'static str = "rename";
-const RENAME_ALL: &'static str = "rename_all";
+use enumscribe_core::{parse_enum, CiOverflow, Enum, MacroError, MacroResult, Variant, VariantType};
+use enumscribe_core::RenameVariant;
+#[cfg(feature = "serde")]
+use enumscribe_core::SerializeAs;
+use enumscribe_core::keys::{DENY_PREFIX, FMT_FIELDS, IGNORE, NFC, NORMALIZE_NEWLINES, OTHER};
+use enumscribe_core::keys::{EXTEND, NORM_FIELD, OPTIONAL_FIELD, PLACEHOLDER};
+#[cfg(feature = "alloc")]
+use enumscribe_core::keys::SCRIBE_CHECKED;
+#[cfg(feature = "serde")]
+use enumscribe_core::keys::SERIALIZE_TRANSFORM;
 
 type TokenStream2 = proc_macro2::TokenStream;
 
@@ -42,73 +40,232 @@ macro_rules! proc_try {
     };
 }
 
-fn gen_scribe_impl<F, G, E>(
-    input: TokenStream,
+/// The error for a derive which does not support variants marked with `#[enumscribe(fmt_fields)]`.
+fn fmt_fields_error(derive_name: &str, enum_ident: &Ident, variant: &Variant) -> MacroError {
+    MacroError::new(
+        format!(
+            "cannot derive {} for {} because the variant {} uses {}\n\
+             explanation: {} is converted to a string by formatting its fields, which is only \
+             supported by ScribeString, TryScribeString, ScribeCowStr and TryScribeCowStr",
+            derive_name, enum_ident, variant.data.ident, FMT_FIELDS, variant.data.ident
+        ),
+        variant.span,
+    )
+}
+
+/// The error for converting a string to an enum with a variant marked with
+/// `#[enumscribe(fmt_fields)]`, which cannot be done because there is no parser for its fields.
+fn fmt_fields_unscribe_error(enum_ident: &Ident, variant: &Variant) -> MacroError {
+    MacroError::new(
+        format!(
+            "cannot convert strings to {} because the variant {} uses {}\n\
+             explanation: the fields of {} cannot be recovered from a string\n\
+             hint: {} can only be used when deriving ScribeString, TryScribeString, ScribeCowStr \
+             and TryScribeCowStr",
+            enum_ident, variant.data.ident, FMT_FIELDS, variant.data.ident, FMT_FIELDS
+        ),
+        variant.span,
+    )
+}
+
+/// Returns the type parameter of the enum which is the type of its `other` variant's field, if
+/// any. Only a reference to the field is available when scribing, and an arbitrary type cannot be
+/// converted to a `String` through a reference, so such a field is cloned first. The scribe impls
+/// therefore require the parameter to implement `Clone`.
+fn generic_other_param<'a>(input: &'a DeriveInput, parsed_enum: &Enum) -> Option<&'a Ident> {
+    let field_ty = parsed_enum.variants().iter().find_map(|variant| match &variant.v_type {
+        VariantType::Other(other) if other.scribe_as().is_none() && other.none_placeholder().is_none() => {
+            Some(other.field_ty())
+        },
+        _ => None,
+    })?;
+
+    let field_ty_ident = match field_ty {
+        syn::Type::Path(type_path) if type_path.qself.is_none() => type_path.path.get_ident()?,
+        _ => return None,
+    };
+
+    input
+        .generics
+        .type_params()
+        .map(|type_param| &type_param.ident)
+        .find(|type_param_ident| *type_param_ident == field_ty_ident)
+}
+
+/// Returns the generics of the enum for a scribe impl, adding a `Clone` bound to the type of the
+/// `other` variant's field if it is a type parameter (see [`generic_other_param`]).
+fn scribe_impl_generics(input: &DeriveInput, parsed_enum: &Enum) -> syn::Generics {
+    let mut generics = input.generics.clone();
+    if let Some(other_param) = generic_other_param(input, parsed_enum) {
+        generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote! { #other_param: ::core::clone::Clone });
+    }
+    generics
+}
+
+/// Generates an expression which converts `field`, a reference to the field of the `other`
+/// variant, to a `String`. `other_param` is the result of [`generic_other_param`].
+#[cfg(feature = "alloc")]
+fn gen_other_into_string(
+    enumscribe: &syn::Path,
+    other_param: Option<&Ident>,
+    field: TokenStream2,
+) -> TokenStream2 {
+    match other_param {
+        Some(other_param) => quote! {
+            <#other_param as ::core::convert::Into<#enumscribe::internal::alloc::String>>::into(
+                <#other_param as ::core::clone::Clone>::clone(#field)
+            )
+        },
+        None => quote! {
+            <_ as ::core::convert::Into<#enumscribe::internal::alloc::String>>::into(#field)
+        },
+    }
+}
+
+/// Whether every variant of the enum is a unit variant without an explicit discriminant, so that
+/// casting a variant to `usize` gives its index in declaration order.
+fn is_castable_to_index(parsed_enum: &Enum) -> bool {
+    !parsed_enum.variants().is_empty()
+        && parsed_enum.variants().iter().all(|variant| {
+            matches!(variant.data.fields, syn::Fields::Unit) && variant.data.discriminant.is_none()
+        })
+}
+
+/// Generates an implementation of a scribe trait which matches on `self`. If `string_table` is
+/// true, every result must be a string literal, and enums which can be cast to an index slice the
+/// result out of one concatenated string instead.
+#[allow(clippy::too_many_arguments)]
+fn gen_scribe_impl<F, G, E, H, X>(
+    input: &DeriveInput,
+    parsed_enum: &Enum,
     trait_ident: TokenStream2,
+    trait_fn_name: TokenStream2,
     trait_return_type: TokenStream2,
     named_fn: F,
     other_fn: G,
     ignore_err_fn: E,
+    formatted_fn: H,
+    extra_items_fn: X,
+    string_table: bool,
 ) -> TokenStream
 where
     F: Fn(&Variant, &Ident, &str) -> MacroResult<TokenStream2>,
     G: Fn(&Variant, &Ident, TokenStream2) -> MacroResult<TokenStream2>,
     E: Fn(&Variant, &Ident) -> MacroError,
+    H: Fn(&Variant, &Ident, TokenStream2) -> MacroResult<TokenStream2>,
+    X: Fn(&Enum, &Ident) -> TokenStream2,
 {
-    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
-
-    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
-    let parsed_enum = proc_try!(enums::parse_enum(enum_data, enum_attrs));
-
     let enum_ident = &input.ident;
+    let enumscribe = parsed_enum.crate_path();
 
     let mut match_arms = Vec::with_capacity(parsed_enum.variants().len());
+    let mut results = Vec::with_capacity(parsed_enum.variants().len());
 
     for variant in parsed_enum.variants().iter() {
-        match variant.match_variant(enum_ident, &named_fn, &other_fn) {
-            Ok(Some((pattern, result))) => match_arms.push(quote! { #pattern => #result }),
+        match variant.match_variant(enum_ident, enumscribe, &named_fn, &other_fn, &formatted_fn) {
+            Ok(Some((pattern, result))) => {
+                match_arms.push(quote! { #pattern => #result });
+                results.push(result);
+            }
             Ok(None) => return ignore_err_fn(variant, enum_ident).into(),
             Err(err) => return err.into(),
         }
     }
 
+    let body = if string_table && is_castable_to_index(parsed_enum) {
+        // Casting a unit-only enum without explicit discriminants gives the index of the variant,
+        // so the result can be looked up instead of matching on `self`. The cast needs the enum by
+        // value, so this is only possible if it is `Copy`, which a derive macro cannot check;
+        // autoref specialization picks the lookup if it is, and falls back to the match.
+        //
+        // The results are string literals, which are concatenated into one string and sliced
+        // using a table of offsets. An array of `&'static str` would need a pointer for every
+        // entry, each with its own relocation in position-independent binaries; `just size`
+        // compares the two using the `size_*` examples in enumscribe_benches.
+        let num_results = results.len();
+        quote! {
+            #[allow(unused_imports)]
+            use #enumscribe::internal::index::{ViaCopy as _, ViaMatch as _};
+
+            const __ENUMSCRIBE_NAMES: &str = ::core::concat!(#(#results),*);
+
+            const __ENUMSCRIBE_LENS: [usize; #num_results] = [#(<str>::len(#results)),*];
+
+            // The offset of each name in `__ENUMSCRIBE_NAMES`, followed by its total length
+            const __ENUMSCRIBE_OFFSETS: [usize; #num_results + 1] = {
+                let mut offsets = [0; #num_results + 1];
+                let mut i = 0;
+                while i < #num_results {
+                    offsets[i + 1] = offsets[i] + __ENUMSCRIBE_LENS[i];
+                    i += 1;
+                }
+                offsets
+            };
+
+            match (&#enumscribe::internal::index::Wrap(self))
+                .__enumscribe_index(|variant: Self| variant as usize)
+            {
+                ::core::option::Option::Some(index) => {
+                    &__ENUMSCRIBE_NAMES[__ENUMSCRIBE_OFFSETS[index]..__ENUMSCRIBE_OFFSETS[index + 1]]
+                },
+                ::core::option::Option::None => match self {
+                    #(#match_arms,)*
+                },
+            }
+        }
+    } else {
+        quote! {
+            match self {
+                #(#match_arms,)*
+            }
+        }
+    };
+
+    let extra_items = extra_items_fn(parsed_enum, enum_ident);
+
+    let generics = scribe_impl_generics(input, parsed_enum);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
     (quote! {
         #[automatically_derived]
-        impl #trait_ident for #enum_ident {
-            fn scribe(&self) -> #trait_return_type {
-                match self {
-                    #(#match_arms,)*
-                }
+        impl #impl_generics #trait_ident for #enum_ident #ty_generics #where_clause {
+            fn #trait_fn_name(&self) -> #trait_return_type {
+                #body
             }
+
+            #extra_items
         }
     })
     .into()
 }
 
-fn gen_try_scribe_impl<F, G>(
-    input: TokenStream,
+#[allow(clippy::too_many_arguments)]
+fn gen_try_scribe_impl<F, G, H>(
+    input: &DeriveInput,
+    parsed_enum: &Enum,
     trait_ident: TokenStream2,
     trait_return_type: TokenStream2,
     named_fn: F,
     other_fn: G,
     ignore_result: TokenStream2,
+    formatted_fn: H,
 ) -> TokenStream
 where
     F: Fn(&Variant, &Ident, &str) -> MacroResult<TokenStream2>,
     G: Fn(&Variant, &Ident, TokenStream2) -> MacroResult<TokenStream2>,
+    H: Fn(&Variant, &Ident, TokenStream2) -> MacroResult<TokenStream2>,
 {
-    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
-
-    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
-    let parsed_enum = proc_try!(enums::parse_enum(enum_data, enum_attrs));
-
     let enum_ident = &input.ident;
+    let enumscribe = parsed_enum.crate_path();
 
     let mut ignore_variant = false;
     let mut match_arms = Vec::with_capacity(parsed_enum.variants().len());
 
     for variant in parsed_enum.variants().iter() {
-        match variant.match_variant(enum_ident, &named_fn, &other_fn) {
+        match variant.match_variant(enum_ident, enumscribe, &named_fn, &other_fn, &formatted_fn) {
             Ok(Some((pattern, result))) => match_arms.push(quote! { #pattern => #result }),
             Ok(None) => ignore_variant = true,
             Err(err) => return err.into(),
@@ -121,9 +278,12 @@ where
         quote! {}
     };
 
+    let generics = scribe_impl_generics(input, parsed_enum);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
     (quote! {
         #[automatically_derived]
-        impl #trait_ident for #enum_ident {
+        impl #impl_generics #trait_ident for #enum_ident #ty_generics #where_clause {
             fn try_scribe(&self) -> #trait_return_type {
                 match self {
                     #(#match_arms,)*
@@ -135,49 +295,187 @@ where
     .into()
 }
 
-fn gen_unscribe_impl<F, G, E>(
-    input: TokenStream,
+#[allow(clippy::too_many_arguments)]
+fn gen_unscribe_impl<F, G, E, X>(
+    input: &DeriveInput,
+    parsed_enum: &Enum,
     trait_ident: TokenStream2,
     trait_fn_name: TokenStream2,
     trait_return_type: TokenStream2,
     named_fn: F,
     other_fn: G,
     other_missing_fn: E,
+    ci_overflow_result: Option<TokenStream2>,
+    denied_result: Option<TokenStream2>,
+    extra_items_fn: X,
 ) -> TokenStream
 where
     F: Fn(TokenStream2) -> TokenStream2,
     G: Fn(TokenStream2) -> TokenStream2,
     E: Fn(&Ident) -> MacroResult<TokenStream2>,
+    X: Fn(&Enum) -> TokenStream2,
 {
-    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
-
-    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
-    let parsed_enum = proc_try!(enums::parse_enum(enum_data, enum_attrs));
-
     let enum_ident = &input.ident;
 
     let to_unscribe_ident = quote! { __enumscribe_to_unscribe };
 
     let main_match = proc_try!(gen_unscribe_match(
         enum_ident,
-        &parsed_enum,
+        parsed_enum,
         &to_unscribe_ident,
         named_fn,
         other_fn,
-        other_missing_fn
+        other_missing_fn,
+        ci_overflow_result,
+        denied_result,
+        None,
+        false,
+        true
     ));
 
+    let extra_items = extra_items_fn(parsed_enum);
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
     (quote! {
         #[automatically_derived]
-        impl #trait_ident for #enum_ident {
+        impl #impl_generics #trait_ident for #enum_ident #ty_generics #where_clause {
             fn #trait_fn_name(#to_unscribe_ident: &str) -> #trait_return_type {
                 #main_match
             }
+
+            #extra_items
         }
     })
     .into()
 }
 
+/// Returns an error if the `other` variant of the enum has a borrowed field, such as `&'a str`.
+/// The string being unscribed only lives as long as the call to `trait_name`'s function, so it
+/// cannot be stored in a borrowed field.
+fn check_other_owned(enum_ident: &Ident, parsed_enum: &Enum, trait_name: &str) -> MacroResult<()> {
+    for variant in parsed_enum.variants().iter() {
+        if let VariantType::Other(other) = &variant.v_type {
+            if let Some(borrowed_field) = other.borrowed_field() {
+                return Err(MacroError::new(
+                    format!(
+                        "cannot derive {} for {} because the {} variant {} has a borrowed field\n\
+                         explanation: {} converts strings which only live as long as the \
+                         conversion, so they cannot be stored in a borrowed field\n\
+                         hint: use an owned field type such as String or Box<str>",
+                        trait_name, enum_ident, OTHER, variant.data.ident, trait_name
+                    ),
+                    borrowed_field.span(),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns an error if the enum's `other` variant uses `optional_field`, which the byte traits do not
+/// support, since an empty byte string is stored in the `other` variant like any other.
+fn check_other_not_optional(
+    enum_ident: &Ident,
+    parsed_enum: &Enum,
+    trait_name: &str,
+) -> MacroResult<()> {
+    for variant in parsed_enum.variants().iter() {
+        if let VariantType::Other(other) = &variant.v_type {
+            if other.none_placeholder().is_some() {
+                return Err(MacroError::new(
+                    format!(
+                        "cannot derive {} for {} because the {} variant {} uses {}\n\
+                         explanation: {} stores bytes in the {} variant's field as they are, so \
+                         the field cannot be an Option",
+                        trait_name,
+                        enum_ident,
+                        OTHER,
+                        variant.data.ident,
+                        OPTIONAL_FIELD,
+                        trait_name,
+                        OTHER
+                    ),
+                    variant.span,
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Generates the arms of a `match` on a byte slice for the names of the case-sensitive variants,
+/// which can be compared against the bytes without checking that they are valid UTF-8.
+/// `result_fn` is given the constructed variant, and returns the result of its arm.
+fn gen_case_sensitive_byte_arms<R>(parsed_enum: &Enum, result_fn: R) -> Vec<TokenStream2>
+where
+    R: Fn(TokenStream2) -> TokenStream2,
+{
+    parsed_enum
+        .variants()
+        .iter()
+        .filter_map(|variant| {
+            let named = variant.v_type.as_named()?;
+            if named.case_insensitive() {
+                return None;
+            }
+            let variant_ident = &variant.data.ident;
+            let constructor_tokens = named.constructor().empty_toks();
+            let constructed_variant = result_fn(quote! { Self::#variant_ident #constructor_tokens });
+            match parsed_enum.on_deprecated() {
+                // The hook needs the matched string, which is the name the bytes are equal to, so
+                // each name gets its own arm
+                Some(on_deprecated) if named.deprecated() => {
+                    let arms = named.match_names().map(|name| {
+                        let name_bytes = Literal::byte_string(name.as_bytes());
+                        quote! {
+                            #name_bytes => {
+                                #on_deprecated(#name);
+                                #constructed_variant
+                            }
+                        }
+                    });
+                    Some(quote! { #(#arms),* })
+                },
+                _ => {
+                    let name_bytes = named
+                        .match_names()
+                        .map(|name| Literal::byte_string(name.as_bytes()));
+                    Some(quote! { #(#name_bytes)|* => #constructed_variant })
+                },
+            }
+        })
+        .collect()
+}
+
+/// Generates a `match` expression which unscribes `match_against`.
+///
+/// `ci_overflow_result` is the result to use if `match_against` is too long to be converted to
+/// uppercase for case-insensitive matching, when the enum is annotated with
+/// `#[enumscribe(ci_overflow = "error")]`. If it is `None`, `ci_overflow` is ignored and overflowing
+/// strings fall through to the `other` arm.
+///
+/// `denied_result` is the result to use if `match_against` would be stored in the `other` variant
+/// but starts with the prefix given by `#[enumscribe(other, deny_prefix = "...")]`. If it is
+/// `None`, `deny_prefix` is ignored.
+///
+/// If `uppercase_buf` is given, it is used as the buffer for case-insensitive matching rather than
+/// a new `CappedString`.
+///
+/// If the enum is annotated with `#[enumscribe(normalize_newlines)]`, `"\r\n"` is replaced with
+/// `"\n"` in `match_against` before it is compared to the names, but the `other` variant still
+/// stores `match_against` unchanged. Likewise, if it is annotated with `#[enumscribe(nfc)]`,
+/// `match_against` is converted to NFC first.
+///
+/// If `empty_is_other` is true, an empty `match_against` is never compared to the names, and
+/// always results in the `other` variant (or `other_missing_fn`'s arm if there is no `other`
+/// variant).
+///
+/// If `report_deprecated` is true, `match_against` is passed to the function given by
+/// `#[enumscribe(on_deprecated = "...")]` when it matches a variant marked as `deprecated`.
+#[allow(clippy::too_many_arguments)]
 fn gen_unscribe_match<F, G, E>(
     enum_ident: &Ident,
     parsed_enum: &Enum,
@@ -185,29 +483,48 @@ fn gen_unscribe_match<F, G, E>(
     named_fn: F,
     other_fn: G,
     other_missing_fn: E,
+    ci_overflow_result: Option<TokenStream2>,
+    denied_result: Option<TokenStream2>,
+    uppercase_buf: Option<&TokenStream2>,
+    empty_is_other: bool,
+    report_deprecated: bool,
 ) -> MacroResult<TokenStream2>
 where
     F: Fn(TokenStream2) -> TokenStream2,
     G: Fn(TokenStream2) -> TokenStream2,
     E: Fn(&Ident) -> MacroResult<TokenStream2>,
 {
+    let enumscribe = parsed_enum.crate_path();
+
+    let normalized_ident = quote! { __enumscribe_unscribe_normalized };
+    let nfc_ident = quote! { __enumscribe_unscribe_nfc };
+    let nfc_key = if parsed_enum.nfc() {
+        quote! { &*#nfc_ident }
+    } else {
+        match_against.clone()
+    };
+    let match_key = if parsed_enum.normalize_newlines() {
+        quote! { &*#normalized_ident }
+    } else {
+        nfc_key.clone()
+    };
+
     let mut other_arm = None;
     let mut case_sensitive_arms = Vec::new();
+    let mut ascii_case_insensitive_arms = Vec::new();
     let mut case_insensitive_arms = Vec::new();
 
     for variant in parsed_enum.variants().iter() {
         let variant_ident = &variant.data.ident;
 
         match &variant.v_type {
-            VariantType::Ignore => (),
+            VariantType::Ignore(_) => (),
 
             VariantType::Named(named) => {
-                let match_pattern = if named.case_insensitive() {
-                    let uppercase_name = named.name_upper();
-                    quote! { #uppercase_name }
+                let match_names = if named.uppercase_case_insensitive() {
+                    named.match_names_upper().collect::<Vec<_>>()
                 } else {
-                    let name = named.name();
-                    quote! { #name }
+                    named.match_names().collect::<Vec<_>>()
                 };
 
                 let constructor_tokens = named.constructor().empty_toks();
@@ -216,31 +533,77 @@ where
                 };
                 let match_result = named_fn(constructed_variant);
 
-                if named.case_insensitive() {
+                let match_result = match parsed_enum.on_deprecated() {
+                    Some(on_deprecated) if report_deprecated && named.deprecated() => quote! {
+                        {
+                            #on_deprecated(#match_against);
+                            #match_result
+                        }
+                    },
+                    _ => match_result,
+                };
+
+                if named.ascii_case_insensitive() {
+                    &mut ascii_case_insensitive_arms
+                } else if named.case_insensitive() {
                     &mut case_insensitive_arms
                 } else {
                     &mut case_sensitive_arms
                 }
-                .push(quote! { #match_pattern => #match_result });
+                .push((match_names, match_result));
             }
 
             VariantType::Other(other) => {
                 let unscribe_value =
-                    quote! { <_ as ::std::convert::Into<_>>::into(#match_against) };
+                    quote! { <_ as ::core::convert::Into<_>>::into(#match_against) };
 
-                let constructed_variant = match other.field_name() {
-                    None => quote! {
+                // An optional field is `None` rather than storing an empty string
+                let unscribe_value = if other.none_placeholder().is_some() {
+                    quote! {
+                        if <str>::is_empty(#match_against) {
+                            ::core::option::Option::None
+                        } else {
+                            ::core::option::Option::Some(#unscribe_value)
+                        }
+                    }
+                } else {
+                    unscribe_value
+                };
+
+                let constructed_variant = match (other.field_name(), other.norm_field_name()) {
+                    (None, _) => quote! {
                         #enum_ident::#variant_ident(#unscribe_value)
                     },
-                    Some(field_name) => quote! {
+                    (Some(field_name), None) => quote! {
                         #enum_ident::#variant_ident { #field_name: #unscribe_value }
                     },
+                    (Some(field_name), Some(norm_field_name)) => {
+                        let norm_value = quote! {
+                            <_ as ::core::convert::Into<_>>::into(
+                                <str>::to_lowercase(#match_against)
+                            )
+                        };
+                        quote! {
+                            #enum_ident::#variant_ident {
+                                #field_name: #unscribe_value,
+                                #norm_field_name: #norm_value,
+                            }
+                        }
+                    },
                 };
 
                 let match_result = other_fn(constructed_variant);
 
-                other_arm = Some(quote! { _ => #match_result })
+                other_arm = Some(match (other.deny_prefix(), &denied_result) {
+                    (Some(deny_prefix), Some(denied_result)) => quote! {
+                        _ if <str>::starts_with(#match_against, #deny_prefix) => #denied_result,
+                        _ => #match_result
+                    },
+                    _ => quote! { _ => #match_result },
+                });
             }
+
+            VariantType::Formatted(_) => return Err(fmt_fields_unscribe_error(enum_ident, variant)),
         }
     }
 
@@ -249,56 +612,241 @@ where
         None => other_missing_fn(enum_ident)?,
     };
 
+    let overflow_arm = match (parsed_enum.ci_overflow(), ci_overflow_result) {
+        (CiOverflow::Error, Some(ci_overflow_result)) if !case_insensitive_arms.is_empty() => {
+            quote! { _ => #ci_overflow_result }
+        },
+        _ => other_arm.clone(),
+    };
+
     let case_insensitive_match = if case_insensitive_arms.is_empty() {
         None
     } else {
         let match_against_upper_ident = quote! { __enumscribe_unscribe_uppercase };
         let name_upper_cap = parsed_enum.name_upper_capacity();
 
+        let match_against_upper = match uppercase_buf {
+            Some(uppercase_buf) => quote! {
+                #enumscribe
+                    ::internal
+                    ::capped_string
+                    ::CappedString
+                    ::assign_uppercase(#uppercase_buf, #match_key)
+            },
+            None => quote! {
+                #enumscribe
+                    ::internal
+                    ::capped_string
+                    ::CappedString
+                    ::<#name_upper_cap>
+                    ::uppercase_from_str(#match_key)
+            },
+        };
+
+        let upper_match = gen_names_match(
+            parsed_enum,
+            &quote! { &*#match_against_upper_ident },
+            &case_insensitive_arms,
+            &other_arm,
+        );
+
+        Some(quote! {
+            match #match_against_upper {
+                ::core::option::Option::Some(#match_against_upper_ident) => {
+                    #upper_match
+                },
+                #overflow_arm,
+            }
+        })
+    };
+
+    // Names which only ignore the case of ASCII letters are compared to the string directly rather
+    // than to an uppercase copy of it, so they are tried before converting it to uppercase.
+    let case_insensitive_match = if ascii_case_insensitive_arms.is_empty() {
+        case_insensitive_match
+    } else {
+        let guarded_arms = ascii_case_insensitive_arms.iter().map(|(names, result)| quote! {
+            _ if #(#enumscribe::internal::ascii::ascii_ci_eq(#match_key, #names))||* => #result
+        });
+
+        let fallback_arm = match case_insensitive_match {
+            Some(case_insensitive_match) => quote! { _ => { #case_insensitive_match } },
+            None => other_arm.clone(),
+        };
+
         Some(quote! {
-            match ::enumscribe
+            match #match_key {
+                #(#guarded_arms,)*
+                #fallback_arm,
+            }
+        })
+    };
+
+    let main_match = match (case_sensitive_arms.is_empty(), case_insensitive_match) {
+        (_, None) => gen_names_match(
+            parsed_enum,
+            &match_key,
+            &case_sensitive_arms,
+            &other_arm,
+        ),
+
+        (true, Some(case_insensitive_match)) => case_insensitive_match,
+
+        (false, Some(case_insensitive_match)) => gen_names_match(
+            parsed_enum,
+            &match_key,
+            &case_sensitive_arms,
+            &quote! { _ => { #case_insensitive_match } },
+        ),
+    };
+
+    let main_match = if parsed_enum.normalize_newlines() {
+        // A string whose normalized form does not fit in the buffer is too long to match any name.
+        let match_cap = parsed_enum.match_capacity();
+
+        quote! {
+            match #enumscribe
                 ::internal
                 ::capped_string
-                ::CappedString
-                ::<#name_upper_cap>
-                ::uppercase_from_str(#match_against)
+                ::CowCappedString
+                ::<#match_cap>
+                ::normalize_newlines(#nfc_key)
             {
-                Some(#match_against_upper_ident) => {
-                    match &*#match_against_upper_ident {
-                        #(#case_insensitive_arms,)*
-                        #other_arm,
-                    }
+                ::core::option::Option::Some(#normalized_ident) => {
+                    #main_match
                 },
-                #other_arm,
+                #overflow_arm,
             }
-        })
+        }
+    } else {
+        main_match
     };
 
-    let main_match = match (case_sensitive_arms.is_empty(), case_insensitive_match) {
-        (true, None) => quote! {
-            match #match_against {
-                #other_arm,
+    // Converting to NFC does not change newlines, so it is done first in order for the buffer to
+    // be borrowed by the newline normalization.
+    let main_match = if parsed_enum.nfc() {
+        let nfc_cap = if parsed_enum.normalize_newlines() {
+            parsed_enum.match_capacity() * 2
+        } else {
+            parsed_enum.match_capacity()
+        };
+
+        quote! {
+            match #enumscribe
+                ::internal
+                ::capped_string
+                ::CowCappedString
+                ::<#nfc_cap>
+                ::nfc(#match_against)
+            {
+                ::core::option::Option::Some(#nfc_ident) => {
+                    #main_match
+                },
+                #overflow_arm,
             }
-        },
+        }
+    } else {
+        main_match
+    };
+
+    if !empty_is_other {
+        return Ok(main_match);
+    }
 
-        (false, None) => quote! {
+    Ok(quote! {
+        if <str>::is_empty(#match_against) {
             match #match_against {
-                #(#case_sensitive_arms,)*
                 #other_arm,
             }
-        },
+        } else {
+            #main_match
+        }
+    })
+}
 
-        (true, Some(case_insensitive_match)) => case_insensitive_match,
+/// Generates an expression which evaluates to the result associated with the name equal to
+/// `match_against`, or to `fallback_arm` (which may consist of several match arms) if there is no
+/// such name. A `match` is used, unless the enum is annotated with `#[enumscribe(phf)]`, in which
+/// case the names are looked up in a perfect hash map generated at compile time.
+fn gen_names_match(
+    parsed_enum: &Enum,
+    match_against: &TokenStream2,
+    arms: &[(Vec<&str>, TokenStream2)],
+    fallback_arm: &TokenStream2,
+) -> TokenStream2 {
+    #[cfg(feature = "phf")]
+    {
+        if parsed_enum.phf() && !arms.is_empty() {
+            return gen_names_phf_match(
+                parsed_enum.crate_path(),
+                match_against,
+                arms,
+                fallback_arm,
+            );
+        }
+    }
 
-        (false, Some(case_insensitive_match)) => quote! {
-            match #match_against {
-                #(#case_sensitive_arms,)*
-                _ => { #case_insensitive_match },
-            }
-        },
-    };
+    #[cfg(not(feature = "phf"))]
+    let _ = parsed_enum;
+
+    let match_arms = arms.iter().map(|(names, result)| quote! { #(#names)|* => #result });
+
+    quote! {
+        match #match_against {
+            #(#match_arms,)*
+            #fallback_arm,
+        }
+    }
+}
+
+#[cfg(feature = "phf")]
+fn gen_names_phf_match(
+    enumscribe: &syn::Path,
+    match_against: &TokenStream2,
+    arms: &[(Vec<&str>, TokenStream2)],
+    fallback_arm: &TokenStream2,
+) -> TokenStream2 {
+    // Map each name to the index of the arm it belongs to. Like a `match`, the first arm wins if
+    // a name appears more than once; the keys of a perfect hash map must be unique.
+    let mut seen_names = HashSet::new();
+    let entries = arms
+        .iter()
+        .enumerate()
+        .flat_map(|(i, (names, _))| names.iter().map(move |name| (*name, i)))
+        .filter(|(name, _)| seen_names.insert(*name))
+        .collect::<Vec<_>>();
+
+    let keys = entries.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+    let hash_state = phf_generator::generate_hash(&keys);
+
+    let hash_key = hash_state.key;
+    let disps = hash_state.disps.iter().map(|(d1, d2)| quote! { (#d1, #d2) });
+    let map_entries = hash_state.map.iter().map(|&i| {
+        let (name, arm_index) = entries[i];
+        quote! { (#name, #arm_index) }
+    });
 
-    Ok(main_match)
+    let match_arms = arms.iter().enumerate().map(|(i, (_, result))| quote! {
+        ::core::option::Option::Some(&#i) => #result
+    });
+
+    // The fields of `phf::Map` are hidden and may change in any release of phf, which is why
+    // phf and phf_generator are pinned to the same exact version.
+    quote! {
+        {
+            static __ENUMSCRIBE_NAMES: #enumscribe::internal::phf::Map<&'static str, usize> =
+                #enumscribe::internal::phf::Map {
+                    key: #hash_key,
+                    disps: &[#(#disps),*],
+                    entries: &[#(#map_entries),*],
+                };
+
+            match #enumscribe::internal::phf::Map::get(&__ENUMSCRIBE_NAMES, #match_against) {
+                #(#match_arms,)*
+                #fallback_arm,
+            }
+        }
+    }
 }
 
 /// Derives [`enumscribe::ScribeStaticStr`](https://docs.rs/enumscribe/latest/enumscribe/trait.ScribeStaticStr.html) for an enum. This allows the enum to be converted to
@@ -318,18 +866,41 @@ where
 /// a meaningful `&'static str`.
 ///
 /// If you want to use `#[enumscribe(other)]`, try deriving
-/// [`ScribeCowStr`](derive.ScribeCowStr.html) instead.
+/// [`ScribeCowStr`](derive.ScribeCowStr.html) instead. The exception is an `other` variant
+/// annotated with `#[enumscribe(other, scribe_as = "...")]`, which is always converted to the
+/// given string, so it is allowed.
 ///
 /// If you want to use `#[enumscribe(ignore)]`, try deriving
-/// [`TryScribeStaticStr`](derive.TryScribeStaticStr.html) instead.
+/// [`TryScribeStaticStr`](derive.TryScribeStaticStr.html) instead. Alternatively, an ignored
+/// variant may be given a fixed string to be converted to with
+/// `#[enumscribe(ignore, placeholder = "N/A")]`, which is allowed. This only affects converting
+/// the enum to a string (including with [`EnumSerialize`](derive.EnumSerialize.html)); no string
+/// is ever converted back to an ignored variant, even if it is equal to the placeholder.
 ///
 /// If you want to use both, try deriving
 /// [`TryScribeCowStr`](derive.TryScribeCowStr.html) instead.
+///
+/// This derive also implements `scribe_with_ci()`, which returns each variant's string along with
+/// whether it is matched case-insensitively, as set by `#[enumscribe(case_insensitive)]`.
+///
+/// If the enum implements `Copy` and every variant is a unit variant without an explicit
+/// discriminant, the strings are stored in a single concatenated string, and the string for a
+/// variant is sliced from it by casting the variant to its index, rather than by matching on the
+/// variant. Any other enum is converted with a `match`.
 #[proc_macro_derive(ScribeStaticStr, attributes(enumscribe))]
 pub fn derive_scribe_static_str(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enumscribe = parsed_enum.crate_path();
+
     gen_scribe_impl(
-        input,
-        quote! { ::enumscribe::ScribeStaticStr },
+        &input,
+        &parsed_enum,
+        quote! { #enumscribe::ScribeStaticStr },
+        quote! { scribe },
         quote! { &'static str },
         |_, _, name| Ok(quote! { #name }),
         |variant, enum_ident, _| {
@@ -349,55 +920,202 @@ pub fn derive_scribe_static_str(input: TokenStream) -> TokenStream {
                     "cannot derive ScribeStaticStr for {} because the variant {} is marked as {}\n\
                      explanation: since {} is ignored, it cannot be guaranteed that the enum can \
                      always be successfully converted to a String\n\
-                     hint: try deriving TryScribeStaticStr instead",
-                    enum_ident, variant.data.ident, IGNORE, variant.data.ident
+                     hint: try deriving TryScribeStaticStr instead, or give {} a string to be \
+                     converted to with #[enumscribe({}, {} = \"...\")]",
+                    enum_ident,
+                    variant.data.ident,
+                    IGNORE,
+                    variant.data.ident,
+                    variant.data.ident,
+                    IGNORE,
+                    PLACEHOLDER
                 ),
                 variant.span,
             )
         },
+        |variant, enum_ident, _| Err(fmt_fields_error("ScribeStaticStr", enum_ident, variant)),
+        |parsed_enum, enum_ident| {
+            let case_insensitive_patterns = parsed_enum
+                .variants()
+                .iter()
+                .filter_map(|variant| {
+                    let named = variant.v_type.as_named()?;
+                    if !named.case_insensitive() {
+                        return None;
+                    }
+                    let variant_ident = &variant.data.ident;
+                    let constructor_tokens = named.constructor().empty_toks();
+                    Some(quote! { #enum_ident::#variant_ident #constructor_tokens })
+                })
+                .collect::<Vec<_>>();
+
+            let case_insensitive = if case_insensitive_patterns.is_empty() {
+                quote! { false }
+            } else {
+                quote! { ::core::matches!(self, #(#case_insensitive_patterns)|*) }
+            };
+
+            quote! {
+                fn scribe_with_ci(&self) -> (&'static str, bool) {
+                    (<Self as #enumscribe::ScribeStaticStr>::scribe(self), #case_insensitive)
+                }
+            }
+        },
+        true,
     )
 }
 
-/// Derives [`enumscribe::TryScribeStaticStr`](https://docs.rs/enumscribe/latest/enumscribe/trait.TryScribeStaticStr.html) for an enum. This allows the enum to be converted to
-/// a `Option<&'static str>` using the `try_scribe()` method.
-///
-/// You may annotate variants with `#[enumscribe(str = "foo")]` to specify what string the variant
-/// should be converted to (replacing `"foo"` with a string of your choice). If this is omitted,
-/// the name of the variant will be used instead. Using the same string for two variants of the
-/// same enum will cause a compile-time error.
-///
-/// This is a version of [`ScribeStaticStr`](derive.ScribeStaticStr.html) intended to be used if
-/// you have one or more variants annotated with `#[enumscribe(ignore)]`. Calling `try_scribe()`
-/// on an ignored variant will always return `None`.
+/// Derives [`enumscribe::ScribeStaticBytes`](https://docs.rs/enumscribe/latest/enumscribe/trait.ScribeStaticBytes.html) for an enum. This allows the enum to be converted to
+/// a `&'static [u8]` using the `scribe_bytes()` method.
 ///
-/// Like [`ScribeStaticStr`](derive.ScribeStaticStr.html), you may not use `#[enumscribe(other)]`
-/// when deriving this trait. If you want to use `other`, try deriving
-/// [`TryScribeCowStr`](derive.TryScribeCowStr.html) instead.
-#[proc_macro_derive(TryScribeStaticStr, attributes(enumscribe))]
-pub fn derive_try_scribe_static_str(input: TokenStream) -> TokenStream {
-    gen_try_scribe_impl(
-        input,
-        quote! { ::enumscribe::TryScribeStaticStr },
-        quote! { ::core::option::Option<&'static str> },
+/// The bytes returned for a variant are the UTF-8 encoding of the string that
+/// [`ScribeStaticStr`](derive.ScribeStaticStr.html) would return for it, and the same
+/// restrictions apply: using `#[enumscribe(other)]` or `#[enumscribe(ignore)]` will cause a
+/// compile-time error.
+#[proc_macro_derive(ScribeStaticBytes, attributes(enumscribe))]
+pub fn derive_scribe_static_bytes(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enumscribe = parsed_enum.crate_path();
+
+    gen_scribe_impl(
+        &input,
+        &parsed_enum,
+        quote! { #enumscribe::ScribeStaticBytes },
+        quote! { scribe_bytes },
+        quote! { &'static [u8] },
         |_, _, name| {
-            Ok(quote! {
-                ::core::option::Option::Some(#name)
-            })
+            let name_bytes = Literal::byte_string(name.as_bytes());
+            Ok(quote! { #name_bytes })
         },
         |variant, enum_ident, _| {
             Err(MacroError::new(
                 format!(
-                    "cannot derive TryScribeStaticStr for {} because the variant {} is marked as {}, so \
-                     there is no &'static str associated with it\n\
-                     hint: try deriving TryScribeCowStr instead",
+                    "cannot derive ScribeStaticBytes for {} because the variant {} is marked as {}, \
+                     so there is no &'static [u8] associated with it",
                     enum_ident, variant.data.ident, OTHER
                 ),
                 variant.span,
             ))
         },
-        quote! { ::core::option::Option::None },
-    )
-}
+        |variant, enum_ident| {
+            MacroError::new(
+                format!(
+                    "cannot derive ScribeStaticBytes for {} because the variant {} is marked as {}\n\
+                     explanation: since {} is ignored, it cannot be guaranteed that the enum can \
+                     always be successfully converted to a &'static [u8]",
+                    enum_ident, variant.data.ident, IGNORE, variant.data.ident
+                ),
+                variant.span,
+            )
+        },
+        |variant, enum_ident, _| Err(fmt_fields_error("ScribeStaticBytes", enum_ident, variant)),
+        |_, _| quote! {},
+        false,
+    )
+}
+
+/// Derives [`enumscribe::ScribeBytes`](https://docs.rs/enumscribe/latest/enumscribe/trait.ScribeBytes.html) for an enum. This allows the enum to be converted to a
+/// `&[u8]` using the `scribe_bytes()` method.
+///
+/// Named variants are converted to the UTF-8 encoding of their strings, in the same way as
+/// [`ScribeStaticBytes`](derive.ScribeStaticBytes.html). Unlike `ScribeStaticBytes`, the enum may
+/// have a variant annotated with `#[enumscribe(other)]`, whose field is returned as it is, so its
+/// type must implement `AsRef<[u8]>`. Its field may therefore be a `Vec<u8>` holding bytes read by
+/// [`UnscribeBytes`](derive.UnscribeBytes.html), which need not be valid UTF-8. The `other` variant
+/// may not use `optional_field`. Using `#[enumscribe(ignore)]` will cause a compile-time error.
+#[proc_macro_derive(ScribeBytes, attributes(enumscribe))]
+pub fn derive_scribe_bytes(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enumscribe = parsed_enum.crate_path();
+
+    proc_try!(check_other_not_optional(&input.ident, &parsed_enum, "ScribeBytes"));
+
+    gen_scribe_impl(
+        &input,
+        &parsed_enum,
+        quote! { #enumscribe::ScribeBytes },
+        quote! { scribe_bytes },
+        quote! { &[u8] },
+        |_, _, name| {
+            let name_bytes = Literal::byte_string(name.as_bytes());
+            Ok(quote! { #name_bytes })
+        },
+        |_, _, field| Ok(quote! { <_ as ::core::convert::AsRef<[u8]>>::as_ref(#field) }),
+        |variant, enum_ident| {
+            MacroError::new(
+                format!(
+                    "cannot derive ScribeBytes for {} because the variant {} is marked as {}\n\
+                     explanation: since {} is ignored, it cannot be guaranteed that the enum can \
+                     always be successfully converted to a &[u8]",
+                    enum_ident, variant.data.ident, IGNORE, variant.data.ident
+                ),
+                variant.span,
+            )
+        },
+        |variant, enum_ident, _| Err(fmt_fields_error("ScribeBytes", enum_ident, variant)),
+        |_, _| quote! {},
+        false,
+    )
+}
+
+/// Derives [`enumscribe::TryScribeStaticStr`](https://docs.rs/enumscribe/latest/enumscribe/trait.TryScribeStaticStr.html) for an enum. This allows the enum to be converted to
+/// a `Option<&'static str>` using the `try_scribe()` method.
+///
+/// You may annotate variants with `#[enumscribe(str = "foo")]` to specify what string the variant
+/// should be converted to (replacing `"foo"` with a string of your choice). If this is omitted,
+/// the name of the variant will be used instead. Using the same string for two variants of the
+/// same enum will cause a compile-time error.
+///
+/// This is a version of [`ScribeStaticStr`](derive.ScribeStaticStr.html) intended to be used if
+/// you have one or more variants annotated with `#[enumscribe(ignore)]`. Calling `try_scribe()`
+/// on an ignored variant will always return `None`, unless the variant is given a placeholder with
+/// `#[enumscribe(ignore, placeholder = "...")]`, in which case the placeholder is returned.
+///
+/// Like [`ScribeStaticStr`](derive.ScribeStaticStr.html), you may not use `#[enumscribe(other)]`
+/// when deriving this trait. If you want to use `other`, try deriving
+/// [`TryScribeCowStr`](derive.TryScribeCowStr.html) instead.
+#[proc_macro_derive(TryScribeStaticStr, attributes(enumscribe))]
+pub fn derive_try_scribe_static_str(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enumscribe = parsed_enum.crate_path();
+
+    gen_try_scribe_impl(
+        &input,
+        &parsed_enum,
+        quote! { #enumscribe::TryScribeStaticStr },
+        quote! { ::core::option::Option<&'static str> },
+        |_, _, name| {
+            Ok(quote! {
+                ::core::option::Option::Some(#name)
+            })
+        },
+        |variant, enum_ident, _| {
+            Err(MacroError::new(
+                format!(
+                    "cannot derive TryScribeStaticStr for {} because the variant {} is marked as {}, so \
+                     there is no &'static str associated with it\n\
+                     hint: try deriving TryScribeCowStr instead",
+                    enum_ident, variant.data.ident, OTHER
+                ),
+                variant.span,
+            ))
+        },
+        quote! { ::core::option::Option::None },
+        |variant, enum_ident, _| Err(fmt_fields_error("TryScribeStaticStr", enum_ident, variant)),
+    )
+}
 
 /// Derives [`enumscribe::ScribeString`](https://docs.rs/enumscribe/latest/enumscribe/trait.ScribeString.html) for an enum. This allows the enum to be converted to
 /// a `String` using the `scribe()` method.
@@ -408,23 +1126,34 @@ pub fn derive_try_scribe_static_str(input: TokenStream) -> TokenStream {
 /// Since a `String` is returned, an allocation must always be performed, which is wasteful.
 /// [`ScribeCowStr`](derive.ScribeCowStr.html) should be preferred because it avoids unnecessary
 /// allocations.
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 #[proc_macro_derive(ScribeString, attributes(enumscribe))]
 pub fn derive_scribe_string(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enumscribe = parsed_enum.crate_path();
+    let other_param = generic_other_param(&input, &parsed_enum);
+
     gen_scribe_impl(
-        input,
-        quote! { ::enumscribe::ScribeString },
-        quote! { ::std::string::String },
-        |_, _, name| {
-            Ok(quote! {
-                <_ as ::std::borrow::ToOwned>::to_owned(#name)
-            })
-        },
-        |_, _, field| {
-            Ok(quote! {
-                <_ as ::std::convert::Into<::std::string::String>>::into(#field)
+        &input,
+        &parsed_enum,
+        quote! { #enumscribe::ScribeString },
+        quote! { scribe },
+        quote! { #enumscribe::internal::alloc::String },
+        |variant, _, name| {
+            Ok(match variant.scribe_map() {
+                Some(scribe_map) => quote! {
+                    #enumscribe::internal::alloc::Cow::<'static, str>::into_owned(#scribe_map(#name))
+                },
+                None => quote! {
+                    <_ as #enumscribe::internal::alloc::ToOwned>::to_owned(#name)
+                },
             })
         },
+        |_, _, field| Ok(gen_other_into_string(enumscribe, other_param, field)),
         |variant, enum_ident| {
             MacroError::new(
                 format!(
@@ -437,6 +1166,9 @@ pub fn derive_scribe_string(input: TokenStream) -> TokenStream {
                 variant.span,
             )
         },
+        |_, _, formatted| Ok(formatted),
+        |_, _| quote! {},
+        false,
     )
 }
 
@@ -449,31 +1181,153 @@ pub fn derive_scribe_string(input: TokenStream) -> TokenStream {
 /// Since a `String` is returned, an allocation must always be performed, which is wasteful.
 /// [`TryScribeCowStr`](derive.TryScribeCowStr.html) should be preferred because it avoids
 /// unnecessary allocations.
-#[cfg(feature = "std")]
+#[cfg(feature = "alloc")]
 #[proc_macro_derive(TryScribeString, attributes(enumscribe))]
 pub fn derive_try_scribe_string(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enumscribe = parsed_enum.crate_path();
+    let other_param = generic_other_param(&input, &parsed_enum);
+
     gen_try_scribe_impl(
-        input,
-        quote! { ::enumscribe::TryScribeString },
-        quote! { ::std::option::Option<::std::string::String> },
-        |_, _, name| {
-            Ok(quote! {
-                ::std::option::Option::Some(
-                    <_ as ::std::borrow::ToOwned>::to_owned(#name)
-                )
+        &input,
+        &parsed_enum,
+        quote! { #enumscribe::TryScribeString },
+        quote! { ::core::option::Option<#enumscribe::internal::alloc::String> },
+        |variant, _, name| {
+            Ok(match variant.scribe_map() {
+                Some(scribe_map) => quote! {
+                    ::core::option::Option::Some(
+                        #enumscribe::internal::alloc::Cow::<'static, str>::into_owned(#scribe_map(#name))
+                    )
+                },
+                None => quote! {
+                    ::core::option::Option::Some(
+                        <_ as #enumscribe::internal::alloc::ToOwned>::to_owned(#name)
+                    )
+                },
             })
         },
         |_, _, field| {
+            let field_string = gen_other_into_string(enumscribe, other_param, field);
+            Ok(quote! { ::core::option::Option::Some(#field_string) })
+        },
+        quote! { ::core::option::Option::None },
+        |_, _, formatted| Ok(quote! { ::core::option::Option::Some(#formatted) }),
+    )
+}
+
+/// Derives [`enumscribe::ScribeBoxStr`](https://docs.rs/enumscribe/latest/enumscribe/trait.ScribeBoxStr.html) for an enum. This allows the enum to be converted to
+/// a `Box<str>` using the `scribe()` method.
+///
+/// This behaves almost identically to [`ScribeString`](derive.ScribeString.html), except the
+/// return type is `Box<str>` instead of `String`. The field of an `other` variant is converted to
+/// a `String` using its `Into<String>` implementation, and then boxed.
+#[cfg(feature = "alloc")]
+#[proc_macro_derive(ScribeBoxStr, attributes(enumscribe))]
+pub fn derive_scribe_box_str(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enumscribe = parsed_enum.crate_path();
+    let other_param = generic_other_param(&input, &parsed_enum);
+
+    gen_scribe_impl(
+        &input,
+        &parsed_enum,
+        quote! { #enumscribe::ScribeBoxStr },
+        quote! { scribe },
+        quote! { #enumscribe::internal::alloc::Box<str> },
+        |variant, _, name| Ok(gen_name_box_str(enumscribe, variant, name)),
+        |_, _, field| {
+            let field_string = gen_other_into_string(enumscribe, other_param, field);
+            Ok(quote! { #enumscribe::internal::alloc::String::into_boxed_str(#field_string) })
+        },
+        |variant, enum_ident| {
+            MacroError::new(
+                format!(
+                    "cannot derive ScribeBoxStr for {} because the variant {} is marked as {}\n\
+                     explanation: since {} is ignored, it cannot be guaranteed that the enum can \
+                     always be successfully converted to a Box<str>\n\
+                     hint: try deriving TryScribeBoxStr instead",
+                    enum_ident, variant.data.ident, IGNORE, variant.data.ident
+                ),
+                variant.span,
+            )
+        },
+        |_, _, formatted| {
+            Ok(quote! { #enumscribe::internal::alloc::String::into_boxed_str(#formatted) })
+        },
+        |_, _| quote! {},
+        false,
+    )
+}
+
+/// Derives [`enumscribe::TryScribeBoxStr`](https://docs.rs/enumscribe/latest/enumscribe/trait.TryScribeBoxStr.html) for an enum. This allows the enum to be converted to
+/// a `Option<Box<str>>` using the `try_scribe()` method.
+///
+/// This behaves almost identically to [`TryScribeString`](derive.TryScribeString.html), except
+/// the return type is `Option<Box<str>>` instead of `Option<String>`.
+#[cfg(feature = "alloc")]
+#[proc_macro_derive(TryScribeBoxStr, attributes(enumscribe))]
+pub fn derive_try_scribe_box_str(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enumscribe = parsed_enum.crate_path();
+    let other_param = generic_other_param(&input, &parsed_enum);
+
+    gen_try_scribe_impl(
+        &input,
+        &parsed_enum,
+        quote! { #enumscribe::TryScribeBoxStr },
+        quote! { ::core::option::Option<#enumscribe::internal::alloc::Box<str>> },
+        |variant, _, name| {
+            let name_box_str = gen_name_box_str(enumscribe, variant, name);
+            Ok(quote! { ::core::option::Option::Some(#name_box_str) })
+        },
+        |_, _, field| {
+            let field_string = gen_other_into_string(enumscribe, other_param, field);
             Ok(quote! {
-                ::std::option::Option::Some(
-                    <_ as ::std::convert::Into<::std::string::String>>::into(#field)
+                ::core::option::Option::Some(
+                    #enumscribe::internal::alloc::String::into_boxed_str(#field_string)
                 )
             })
         },
-        quote! { ::std::option::Option::None },
+        quote! { ::core::option::Option::None },
+        |_, _, formatted| Ok(quote! {
+            ::core::option::Option::Some(
+                #enumscribe::internal::alloc::String::into_boxed_str(#formatted)
+            )
+        }),
     )
 }
 
+/// Generates an expression which converts `name`, the name of a named variant, to a `Box<str>`,
+/// passing it through the enum's `scribe_map` function first if it has one.
+#[cfg(feature = "alloc")]
+fn gen_name_box_str(enumscribe: &syn::Path, variant: &Variant, name: &str) -> TokenStream2 {
+    match variant.scribe_map() {
+        Some(scribe_map) => quote! {
+            <#enumscribe::internal::alloc::Box<str> as ::core::convert::From<
+                #enumscribe::internal::alloc::Cow<'static, str>
+            >>::from(#scribe_map(#name))
+        },
+        None => quote! {
+            <#enumscribe::internal::alloc::Box<str> as ::core::convert::From<&'static str>>::from(
+                #name
+            )
+        },
+    }
+}
+
 /// Derives [`enumscribe::ScribeCowStr`](https://docs.rs/enumscribe/latest/enumscribe/trait.ScribeCowStr.html) for an enum. This allows the enum to be converted to
 /// a `Cow<'static, str>` using the `scribe()` method.
 ///
@@ -493,24 +1347,81 @@ pub fn derive_try_scribe_string(input: TokenStream) -> TokenStream {
 ///
 /// This derive does not support ignoring variants with `#[enumscribe(ignore)]`. If you want to
 /// ignore variants, try deriving [`TryScribeCowStr`](derive.TryScribeCowStr.html) instead.
-#[cfg(feature = "std")]
+///
+/// Annotating the enum with `#[enumscribe(scribe_map = "path::to::function")]` causes the string
+/// of every named variant to be passed through the given function, which must have the signature
+/// `fn(&'static str) -> Cow<'static, str>`, and its result to be returned instead. This can be
+/// used for localization, for example. The field of an `other` variant is returned as-is. This
+/// only affects scribing, so `Unscribe` and `TryUnscribe` still match against the original
+/// strings. It is also supported by [`TryScribeCowStr`](derive.TryScribeCowStr.html),
+/// [`ScribeString`](derive.ScribeString.html), [`TryScribeString`](derive.TryScribeString.html),
+/// [`ScribeBoxStr`](derive.ScribeBoxStr.html) and [`TryScribeBoxStr`](derive.TryScribeBoxStr.html),
+/// but has no effect on any other derive.
+///
+/// A variant with fields can be annotated with `#[enumscribe(str = "port:{}", fmt_fields)]` to
+/// scribe it by formatting its fields into its string, so `Port(8080)` becomes `"port:8080"`.
+/// Each `{}` placeholder is filled with the next field in declaration order using its `Display`
+/// implementation, and the result is always a `Cow::Owned`. The result is not passed through
+/// `scribe_map`. This is supported by the same derives as `scribe_map`; since there is no way to
+/// parse the fields back out of a string, any other derive (including
+/// [`Unscribe`](derive.Unscribe.html) and [`TryUnscribe`](derive.TryUnscribe.html)) will cause a
+/// compile-time error.
+///
+/// Annotating the `other` variant with `#[enumscribe(other, scribe_as = "<redacted>")]` causes it
+/// to be scribed as the given fixed string rather than its field, which is useful if the strings
+/// it stores may be sensitive. Unscribing still stores the original string in the field. This is
+/// respected by every derive which converts the enum to a string, including
+/// [`EnumSerialize`](derive.EnumSerialize.html).
+///
+/// Annotating the enum with `#[enumscribe(scribe_checked)]` causes this derive to also implement
+/// `enumscribe::ScribeChecked`, whose `scribe_checked()` method returns an error rather than the
+/// string of the `other` variant if the string would be unscribed to a different variant.
+///
+/// Annotating the enum with `#[enumscribe(debug_as_scribe)]` causes this derive to also implement
+/// `core::fmt::Debug` for the enum, writing the same string as `scribe()` (so an `other` variant is
+/// shown as its stored string). This is useful for logging where the external name is what
+/// matters. The enum must not also derive `Debug`.
+///
+/// The enum may be generic. If the field of the `other` variant is one of the enum's type
+/// parameters, as in `enum Code<T: Into<String>> { ..., Other(T) }`, the field is cloned and
+/// converted using its `Into<String>` implementation, so the derived implementation also requires
+/// the parameter to implement `Clone`. This also applies to
+/// [`TryScribeCowStr`](derive.TryScribeCowStr.html), [`ScribeString`](derive.ScribeString.html),
+/// [`TryScribeString`](derive.TryScribeString.html), [`ScribeBoxStr`](derive.ScribeBoxStr.html)
+/// and [`TryScribeBoxStr`](derive.TryScribeBoxStr.html).
+#[cfg(feature = "alloc")]
 #[proc_macro_derive(ScribeCowStr, attributes(enumscribe))]
 pub fn derive_scribe_cow_str(input: TokenStream) -> TokenStream {
-    gen_scribe_impl(
-        input,
-        quote! { ::enumscribe::ScribeCowStr },
-        quote! { ::std::borrow::Cow<'static, str> },
-        |_, _, name| {
-            Ok(quote! {
-                ::std::borrow::Cow::Borrowed(#name)
+    let debug_impl = gen_debug_as_scribe_impl(input.clone());
+    let checked_impl = gen_scribe_checked_impl(input.clone());
+
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enumscribe = parsed_enum.crate_path();
+    let other_param = generic_other_param(&input, &parsed_enum);
+
+    let scribe_impl = gen_scribe_impl(
+        &input,
+        &parsed_enum,
+        quote! { #enumscribe::ScribeCowStr },
+        quote! { scribe },
+        quote! { #enumscribe::internal::alloc::Cow<'static, str> },
+        |variant, _, name| {
+            Ok(match variant.scribe_map() {
+                Some(scribe_map) => quote! {
+                    #scribe_map(#name)
+                },
+                None => quote! {
+                    #enumscribe::internal::alloc::Cow::Borrowed(#name)
+                },
             })
         },
         |_, _, field| {
-            Ok(quote! {
-                ::std::borrow::Cow::Owned(
-                    <_ as ::std::convert::Into<::std::string::String>>::into(#field)
-                )
-            })
+            let field_string = gen_other_into_string(enumscribe, other_param, field);
+            Ok(quote! { #enumscribe::internal::alloc::Cow::Owned(#field_string) })
         },
         |variant, enum_ident| {
             MacroError::new(
@@ -524,99 +1435,581 @@ pub fn derive_scribe_cow_str(input: TokenStream) -> TokenStream {
                 variant.span,
             )
         },
-    )
+        |_, _, formatted| Ok(quote! { #enumscribe::internal::alloc::Cow::Owned(#formatted) }),
+        |_, _| quote! {},
+        false,
+    );
+
+    scribe_impl.into_iter().chain(debug_impl).chain(checked_impl).collect()
 }
 
-/// Derives [`enumscribe::TryScribeCowStr`](https://docs.rs/enumscribe/latest/enumscribe/trait.TryScribeCowStr.html) for an enum. This allows the enum to be converted to
-/// a `Option<Cow<'static, str>>` using the `try_scribe()` method.
-///
-/// You may annotate variants with `#[enumscribe(str = "foo")]` to specify what string the variant
-/// should be converted to (replacing `"foo"` with a string of your choice). If this is omitted,
-/// the name of the variant will be used instead. Using the same string for two variants of the
-/// same enum will cause a compile-time error.
+/// Derives `core::convert::From<&Enum>` for `Cow<'static, str>`, where `Enum` is the enum being
+/// derived for. This allows a reference to the enum to be passed to functions accepting
+/// `impl Into<Cow<'static, str>>`.
 ///
-/// This is a version of [`ScribeCowStr`](derive.ScribeCowStr.html) intended to be used if
-/// you have one or more variants annotated with `#[enumscribe(ignore)]`. Calling `try_scribe()`
-/// on an ignored variant will always return `None`.
+/// The conversion uses the enum's `ScribeCowStr` implementation, so the enum must also derive
+/// [`ScribeCowStr`](derive.ScribeCowStr.html), and all of its attributes apply. In particular,
+/// variants cannot be ignored with `#[enumscribe(ignore)]` unless they are given a placeholder.
+#[cfg(feature = "alloc")]
+#[proc_macro_derive(IntoCowStr, attributes(enumscribe))]
+pub fn derive_into_cow_str(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enum_ident = &input.ident;
+    let enumscribe = parsed_enum.crate_path();
+
+    let generics = scribe_impl_generics(&input, &parsed_enum);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    (quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::convert::From<&#enum_ident #ty_generics>
+            for #enumscribe::internal::alloc::Cow<'static, str>
+            #where_clause
+        {
+            fn from(__enumscribe_value: &#enum_ident #ty_generics) -> Self {
+                <#enum_ident #ty_generics as #enumscribe::ScribeCowStr>::scribe(__enumscribe_value)
+            }
+        }
+    })
+    .into()
+}
+
+/// Derives [`enumscribe::ScribeInterned`](https://docs.rs/enumscribe/latest/enumscribe/trait.ScribeInterned.html) for an enum. This allows the enum to be converted
+/// to a `&'static str` using the `scribe_interned()` method, even if it has a variant annotated
+/// with `#[enumscribe(other)]`.
 ///
-/// This derive also supports annotating a variant with `#[enumscibe(other)]`, which is useful
-/// because it is required to derive [`Unscribe`](derive.Unscribe.html). This allows you to
-/// specify that a variant should be used to store strings that could not be matched to any other
-/// variant when unscribing. The variant should have a single field, which should have type
-/// `String`.
+/// The field of an `other` variant must implement `AsRef<str>`. Its string is copied into a
+/// process-global interner the first time it is scribed, and that copy is never freed, so every
+/// distinct string scribed this way is leaked. Variants annotated with
+/// `#[enumscribe(str = "...", fmt_fields)]` are formatted and then interned in the same way.
 ///
-/// If you do not want to use `#[enumscribe(other)]`, you should derive
-/// [`TryScribeStaticStr`](derive.TryScribeStaticStr.html) instead.
+/// This derive does not support ignoring variants with `#[enumscribe(ignore)]`, and
+/// `scribe_map` has no effect on it. It requires the `std` feature of enumscribe.
 #[cfg(feature = "std")]
-#[proc_macro_derive(TryScribeCowStr, attributes(enumscribe))]
-pub fn derive_try_scribe_cow_str(input: TokenStream) -> TokenStream {
-    gen_try_scribe_impl(
-        input,
-        quote! { ::enumscribe::TryScribeCowStr },
-        quote! { ::std::option::Option<::std::borrow::Cow<'static, str>> },
-        |_, _, name| {
-            Ok(quote! {
-                ::std::option::Option::Some(
-                    ::std::borrow::Cow::Borrowed(#name)
-                )
-            })
-        },
+#[proc_macro_derive(ScribeInterned, attributes(enumscribe))]
+pub fn derive_scribe_interned(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enumscribe = parsed_enum.crate_path();
+
+    gen_scribe_impl(
+        &input,
+        &parsed_enum,
+        quote! { #enumscribe::ScribeInterned },
+        quote! { scribe_interned },
+        quote! { &'static str },
+        |_, _, name| Ok(quote! { #name }),
         |_, _, field| {
             Ok(quote! {
-                ::std::option::Option::Some(
-                    ::std::borrow::Cow::Owned(
-                        <_ as ::std::convert::Into<::std::string::String>>::into(#field)
-                    )
+                #enumscribe::internal::intern::intern(
+                    <_ as ::core::convert::AsRef<str>>::as_ref(#field)
                 )
             })
         },
-        quote! { ::std::option::Option::None },
+        |variant, enum_ident| {
+            MacroError::new(
+                format!(
+                    "cannot derive ScribeInterned for {} because the variant {} is marked as {}\n\
+                     explanation: since {} is ignored, it cannot be guaranteed that the enum can \
+                     always be successfully converted to a &'static str\n\
+                     hint: try deriving TryScribeCowStr instead",
+                    enum_ident, variant.data.ident, IGNORE, variant.data.ident
+                ),
+                variant.span,
+            )
+        },
+        |_, _, formatted| Ok(quote! { #enumscribe::internal::intern::intern(&#formatted) }),
+        |_, _| quote! {},
+        false,
     )
 }
 
-/// Derives [`enumscribe::Unscribe`](https://docs.rs/enumscribe/latest/enumscribe/trait.Unscribe.html) for an enum. This allows a `&str` to be converted to the
-/// enum using the `unscribe()` associated function.
-///
-/// You may annotate variants with `#[enumscribe(str = "foo")]` to specify what string should
-/// convert to the variant (replacing `"foo"` with a string of your choice). If this is omitted,
-/// the name of the variant will be used instead. Using the same string for two variants of the
-/// same enum will cause a compile-time error.
-///
-/// You may annotate a variant with `#[enumscribe(case_insensitive)]` to use case-insensitive
-/// matching for that variant. For example, if a variant is annotated with
-/// `#[enumscribe(str = "baa", case_insensitive)]`, then strings like `"baa"`, `"BAA"`, `"bAa"`
-/// etc. will all be matched to that variant.
-///
-/// In order to derive this trait, you must have exactly one variant annotated with
-/// `#[enumscribe(other)]`. This variant will be used to store any string that could not be matched
-/// to any of the other variants. The variant must have exactly one field, which should have type
-/// `String`. Both named (`Variant { name: String }`) and unnamed (`Variant(String)`) fields are
-/// allowed.
-///
+/// Generates a `Debug` implementation which writes the result of `ScribeCowStr::scribe`, if the
+/// enum is annotated with `#[enumscribe(debug_as_scribe)]`. Any error in the enum's attributes is
+/// left to be reported by the `ScribeCowStr` implementation.
+#[cfg(feature = "alloc")]
+fn gen_debug_as_scribe_impl(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let parsed_enum = match get_enum_data(&input)
+        .and_then(|(enum_data, enum_attrs)| parse_enum(enum_data, enum_attrs))
+    {
+        Ok(parsed_enum) => parsed_enum,
+        Err(_) => return TokenStream::new(),
+    };
+
+    if !parsed_enum.debug_as_scribe() {
+        return TokenStream::new();
+    }
+
+    let enum_ident = &input.ident;
+    let enumscribe = parsed_enum.crate_path();
+
+    let generics = scribe_impl_generics(&input, &parsed_enum);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    (quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::fmt::Debug for #enum_ident #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                ::core::fmt::Formatter::write_str(
+                    f,
+                    &<Self as #enumscribe::ScribeCowStr>::scribe(self)
+                )
+            }
+        }
+    })
+    .into()
+}
+
+/// Generates a `ScribeChecked` implementation, if the enum is annotated with
+/// `#[enumscribe(scribe_checked)]`. Any error in the enum's attributes is left to be reported by
+/// the `ScribeCowStr` implementation.
+#[cfg(feature = "alloc")]
+fn gen_scribe_checked_impl(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let parsed_enum = match get_enum_data(&input)
+        .and_then(|(enum_data, enum_attrs)| parse_enum(enum_data, enum_attrs))
+    {
+        Ok(parsed_enum) => parsed_enum,
+        Err(_) => return TokenStream::new(),
+    };
+
+    if !parsed_enum.scribe_checked() {
+        return TokenStream::new();
+    }
+
+    let enum_ident = &input.ident;
+    let enumscribe = parsed_enum.crate_path();
+
+    for variant in parsed_enum.variants().iter() {
+        if let VariantType::Formatted(_) = variant.v_type {
+            return MacroError::new(
+                format!(
+                    "cannot use {} for {} because the variant {} uses {}\n\
+                     explanation: {} checks strings by converting them back to {}, but the fields \
+                     of {} cannot be recovered from a string",
+                    SCRIBE_CHECKED,
+                    enum_ident,
+                    variant.data.ident,
+                    FMT_FIELDS,
+                    SCRIBE_CHECKED,
+                    enum_ident,
+                    variant.data.ident
+                ),
+                variant.span,
+            )
+            .into();
+        }
+    }
+
+    let scribed_ident = quote! { __enumscribe_scribed };
+    let scribed_str_ident = quote! { __enumscribe_scribed_str };
+
+    // Only the string of an `other` variant can be equal to the name of a variant, so the names
+    // are only checked for that variant. The check is done by unscribing the string and seeing if
+    // the result is a named variant, so aliases and case-insensitivity are taken into account.
+    let other_pattern = parsed_enum.variants().iter().find_map(|variant| match variant.v_type {
+        VariantType::Other(_) => Some(variant.pattern_ignoring_fields(enum_ident)),
+        _ => None,
+    });
+
+    let check = match other_pattern {
+        Some(other_pattern) => {
+            let shadows_named = proc_try!(gen_unscribe_match(
+                enum_ident,
+                &parsed_enum,
+                &scribed_str_ident,
+                |_| quote! { true },
+                |_| quote! { false },
+                |_| Ok(quote! { _ => false }),
+                None,
+                None,
+                None,
+                false,
+                false
+            ));
+
+            quote! {
+                if let #other_pattern = self {
+                    let #scribed_str_ident: &str = &#scribed_ident;
+                    if #shadows_named {
+                        return ::core::result::Result::Err(#enumscribe::OtherShadowsNamed);
+                    }
+                }
+            }
+        },
+        None => quote! {},
+    };
+
+    let generics = scribe_impl_generics(&input, &parsed_enum);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    (quote! {
+        #[automatically_derived]
+        impl #impl_generics #enumscribe::ScribeChecked for #enum_ident #ty_generics #where_clause {
+            fn scribe_checked(&self) -> ::core::result::Result<
+                #enumscribe::internal::alloc::Cow<'static, str>,
+                #enumscribe::OtherShadowsNamed
+            > {
+                let #scribed_ident = <Self as #enumscribe::ScribeCowStr>::scribe(self);
+                #check
+                ::core::result::Result::Ok(#scribed_ident)
+            }
+        }
+    })
+    .into()
+}
+
+#[cfg(feature = "alloc")]
+fn gen_cased_match_arms(
+    parsed_enum: &Enum,
+    enum_ident: &Ident,
+    other_param: Option<&Ident>,
+    convert: fn(&str) -> String,
+    convert_fn: TokenStream2,
+) -> MacroResult<Vec<TokenStream2>> {
+    let enumscribe = parsed_enum.crate_path();
+
+    let mut match_arms = Vec::with_capacity(parsed_enum.variants().len());
+
+    for variant in parsed_enum.variants().iter() {
+        let arm = variant.match_variant(
+            enum_ident,
+            enumscribe,
+            &|_, _, name| {
+                let converted = convert(name);
+                Ok(quote! {
+                    #enumscribe::internal::alloc::Cow::Borrowed(#converted)
+                })
+            },
+            &|_, _, field| {
+                // A generic field is only known to be convertible to a `String`, so it is
+                // converted before changing its case.
+                let field_str = match other_param {
+                    Some(_) => {
+                        let field_string = gen_other_into_string(enumscribe, other_param, field);
+                        quote! { &#field_string }
+                    },
+                    None => quote! { <_ as ::core::convert::AsRef<str>>::as_ref(#field) },
+                };
+                Ok(quote! {
+                    #enumscribe::internal::alloc::Cow::Owned(<str>::#convert_fn(#field_str))
+                })
+            },
+            &|variant, enum_ident, _| Err(fmt_fields_error("ScribeCasedCowStr", enum_ident, variant)),
+        )?;
+
+        match arm {
+            Some((pattern, result)) => match_arms.push(quote! { #pattern => #result }),
+            None => return Err(MacroError::new(
+                format!(
+                    "cannot derive ScribeCasedCowStr for {} because the variant {} is marked as {}\n\
+                     explanation: since {} is ignored, it cannot be guaranteed that the enum can \
+                     always be successfully converted to a String",
+                    enum_ident, variant.data.ident, IGNORE, variant.data.ident
+                ),
+                variant.span,
+            )),
+        }
+    }
+
+    Ok(match_arms)
+}
+
+/// Derives [`enumscribe::ScribeCasedCowStr`](https://docs.rs/enumscribe/latest/enumscribe/trait.ScribeCasedCowStr.html) for an enum. This allows the enum to be converted to
+/// a lowercase or uppercase `Cow<'static, str>` using the `scribe_lower()` and `scribe_upper()`
+/// methods.
+///
+/// The strings for named variants are converted when the derive macro is expanded, so no
+/// allocation is needed for them. The field of a variant annotated with `#[enumscribe(other)]` is
+/// converted when the method is called.
+///
+/// Like [`ScribeCowStr`](derive.ScribeCowStr.html), this derive does not support ignoring variants
+/// with `#[enumscribe(ignore)]`.
+#[cfg(feature = "alloc")]
+#[proc_macro_derive(ScribeCasedCowStr, attributes(enumscribe))]
+pub fn derive_scribe_cased_cow_str(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enum_ident = &input.ident;
+    let enumscribe = parsed_enum.crate_path();
+    let other_param = generic_other_param(&input, &parsed_enum);
+
+    let lower_match_arms = proc_try!(gen_cased_match_arms(
+        &parsed_enum,
+        enum_ident,
+        other_param,
+        str::to_lowercase,
+        quote! { to_lowercase }
+    ));
+
+    let upper_match_arms = proc_try!(gen_cased_match_arms(
+        &parsed_enum,
+        enum_ident,
+        other_param,
+        str::to_uppercase,
+        quote! { to_uppercase }
+    ));
+
+    let generics = scribe_impl_generics(&input, &parsed_enum);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    (quote! {
+        #[automatically_derived]
+        impl #impl_generics #enumscribe::ScribeCasedCowStr for #enum_ident #ty_generics #where_clause {
+            fn scribe_lower(&self) -> #enumscribe::internal::alloc::Cow<'static, str> {
+                match self {
+                    #(#lower_match_arms,)*
+                }
+            }
+
+            fn scribe_upper(&self) -> #enumscribe::internal::alloc::Cow<'static, str> {
+                match self {
+                    #(#upper_match_arms,)*
+                }
+            }
+        }
+    })
+    .into()
+}
+
+/// Derives [`enumscribe::TryScribeCowStr`](https://docs.rs/enumscribe/latest/enumscribe/trait.TryScribeCowStr.html) for an enum. This allows the enum to be converted to
+/// a `Option<Cow<'static, str>>` using the `try_scribe()` method.
+///
+/// You may annotate variants with `#[enumscribe(str = "foo")]` to specify what string the variant
+/// should be converted to (replacing `"foo"` with a string of your choice). If this is omitted,
+/// the name of the variant will be used instead. Using the same string for two variants of the
+/// same enum will cause a compile-time error.
+///
+/// This is a version of [`ScribeCowStr`](derive.ScribeCowStr.html) intended to be used if
+/// you have one or more variants annotated with `#[enumscribe(ignore)]`. Calling `try_scribe()`
+/// on an ignored variant will always return `None`, unless the variant is given a placeholder with
+/// `#[enumscribe(ignore, placeholder = "...")]`, in which case the placeholder is returned.
+///
+/// This derive also supports annotating a variant with `#[enumscibe(other)]`, which is useful
+/// because it is required to derive [`Unscribe`](derive.Unscribe.html). This allows you to
+/// specify that a variant should be used to store strings that could not be matched to any other
+/// variant when unscribing. The variant should have a single field, which should have type
+/// `String`.
+///
+/// If you do not want to use `#[enumscribe(other)]`, you should derive
+/// [`TryScribeStaticStr`](derive.TryScribeStaticStr.html) instead.
+#[cfg(feature = "alloc")]
+#[proc_macro_derive(TryScribeCowStr, attributes(enumscribe))]
+pub fn derive_try_scribe_cow_str(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enumscribe = parsed_enum.crate_path();
+    let other_param = generic_other_param(&input, &parsed_enum);
+
+    gen_try_scribe_impl(
+        &input,
+        &parsed_enum,
+        quote! { #enumscribe::TryScribeCowStr },
+        quote! { ::core::option::Option<#enumscribe::internal::alloc::Cow<'static, str>> },
+        |variant, _, name| {
+            Ok(match variant.scribe_map() {
+                Some(scribe_map) => quote! {
+                    ::core::option::Option::Some(#scribe_map(#name))
+                },
+                None => quote! {
+                    ::core::option::Option::Some(
+                        #enumscribe::internal::alloc::Cow::Borrowed(#name)
+                    )
+                },
+            })
+        },
+        |_, _, field| {
+            let field_string = gen_other_into_string(enumscribe, other_param, field);
+            Ok(quote! {
+                ::core::option::Option::Some(#enumscribe::internal::alloc::Cow::Owned(#field_string))
+            })
+        },
+        quote! { ::core::option::Option::None },
+        |_, _, formatted| Ok(quote! {
+            ::core::option::Option::Some(#enumscribe::internal::alloc::Cow::Owned(#formatted))
+        }),
+    )
+}
+
+/// Derives [`enumscribe::Unscribe`](https://docs.rs/enumscribe/latest/enumscribe/trait.Unscribe.html) for an enum. This allows a `&str` to be converted to the
+/// enum using the `unscribe()` associated function.
+///
+/// You may annotate variants with `#[enumscribe(str = "foo")]` to specify what string should
+/// convert to the variant (replacing `"foo"` with a string of your choice). If this is omitted,
+/// the name of the variant will be used instead. Using the same string for two variants of the
+/// same enum will cause a compile-time error.
+///
+/// You may annotate a variant with `#[enumscribe(case_insensitive)]` to use case-insensitive
+/// matching for that variant. For example, if a variant is annotated with
+/// `#[enumscribe(str = "baa", case_insensitive)]`, then strings like `"baa"`, `"BAA"`, `"bAa"`
+/// etc. will all be matched to that variant.
+///
+/// In order to derive this trait, you must have exactly one variant annotated with
+/// `#[enumscribe(other)]`. This variant will be used to store any string that could not be matched
+/// to any of the other variants. The variant must have exactly one field, which should have type
+/// `String`. Both named (`Variant { name: String }`) and unnamed (`Variant(String)`) fields are
+/// allowed.
+///
+/// Annotating the `other` variant with `#[enumscribe(other, optional_field)]` allows its field to
+/// be an `Option<String>` instead, which is `None` if the string is empty and `Some` otherwise.
+/// When the enum is converted back to a string, `None` becomes an empty string, or the string
+/// given by `#[enumscribe(other, optional_field, placeholder = "...")]`. This is supported by
+/// every derive which accepts an `other` variant.
+///
+/// A variant may be annotated with `#[enumscribe(deprecated)]` to record that its names are only
+/// accepted for backwards compatibility. If the enum is annotated with
+/// `#[enumscribe(on_deprecated = "path::to::function")]`, the given function is called with the
+/// string being converted whenever it matches a deprecated variant, which is useful for logging
+/// usage of legacy names. The function must accept a `&str`. This is also supported by
+/// [`TryUnscribe`](derive.TryUnscribe.html), [`FromStr`](derive.FromStr.html) and
+/// [`EnumDeserialize`](derive.EnumDeserialize.html).
+///
+/// The derived implementation also provides `unscribe_tagged()`, which additionally reports
+/// whether the string fell through to the `other` variant, and `unscribe_with_buf()`, which
+/// performs case-insensitive matching using a caller-provided buffer.
+///
 /// If you do not want to use `#[enumscribe(other)]`, try deriving
 /// [`TryUnscribe`](derive.TryUnscribe.html) instead.
 #[proc_macro_derive(Unscribe, attributes(enumscribe))]
 pub fn derive_unscribe(input: TokenStream) -> TokenStream {
-    gen_unscribe_impl(
-        input,
-        quote! { ::enumscribe::Unscribe },
-        quote! { unscribe },
-        quote! { Self },
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enum_ident = &input.ident;
+    let enumscribe = parsed_enum.crate_path();
+
+    proc_try!(check_other_owned(enum_ident, &parsed_enum, "Unscribe"));
+
+    let to_unscribe_ident = quote! { __enumscribe_to_unscribe };
+
+    let other_missing_fn = |enum_ident: &Ident| {
+        Err(MacroError::new(
+            format!(
+                "cannot derive Unscribe for {} because no variant is marked as {}\n\
+                 explanation: since there is no {} variant, it cannot be guaranteed that every string \
+                 can be successfully converted to a variant of {}\n\
+                 hint: either introduce an {} variant, or try deriving TryUnscribe instead",
+                enum_ident, OTHER, OTHER, enum_ident, OTHER
+            ),
+            enum_ident.span(),
+        ))
+    };
+
+    if let Some(deny_prefix) = parsed_enum.deny_prefix() {
+        return MacroError::new(
+            format!(
+                "cannot derive Unscribe for {} because its {} variant uses {}\n\
+                 explanation: strings starting with {:?} cannot be converted to any variant of {}\n\
+                 hint: try deriving TryUnscribe instead",
+                enum_ident, OTHER, DENY_PREFIX, deny_prefix, enum_ident
+            ),
+            enum_ident.span(),
+        )
+        .into();
+    }
+
+    let main_match = proc_try!(gen_unscribe_match(
+        enum_ident,
+        &parsed_enum,
+        &to_unscribe_ident,
         |constructed_named_variant| constructed_named_variant,
         |constructed_other_variant| constructed_other_variant,
-        |enum_ident| {
-            Err(MacroError::new(
-                format!(
-                    "cannot derive Unscribe for {} because no variant is marked as {}\n\
-                     explanation: since there is no {} variant, it cannot be guaranteed that every string \
-                     can be successfully converted to a variant of {}\n\
-                     hint: either introduce an {} variant, or try deriving TryUnscribe instead",
-                    enum_ident, OTHER, OTHER, enum_ident, OTHER
-                ),
-                enum_ident.span(),
-            ))
-        },
-    )
+        other_missing_fn,
+        None,
+        None,
+        None,
+        false,
+        true
+    ));
+
+    let tagged_match = proc_try!(gen_unscribe_match(
+        enum_ident,
+        &parsed_enum,
+        &to_unscribe_ident,
+        |constructed_named_variant| quote! { (#constructed_named_variant, false) },
+        |constructed_other_variant| quote! { (#constructed_other_variant, true) },
+        other_missing_fn,
+        None,
+        None,
+        None,
+        false,
+        true
+    ));
+
+    // The default implementation of `unscribe_with_buf` just calls `unscribe`, which is fine if
+    // there is no case-insensitive matching to be done.
+    let with_buf_fn = if parsed_enum.has_uppercase_case_insensitive() {
+        let uppercase_buf_ident = quote! { __enumscribe_uppercase_buf };
+        let name_upper_cap = parsed_enum.name_upper_capacity();
+
+        let with_buf_match = proc_try!(gen_unscribe_match(
+            enum_ident,
+            &parsed_enum,
+            &to_unscribe_ident,
+            |constructed_named_variant| constructed_named_variant,
+            |constructed_other_variant| constructed_other_variant,
+            other_missing_fn,
+            None,
+            None,
+            Some(&uppercase_buf_ident),
+            false,
+            true
+        ));
+
+        Some(quote! {
+            fn unscribe_with_buf<const N: usize>(
+                #to_unscribe_ident: &str,
+                #uppercase_buf_ident: &mut #enumscribe::internal::capped_string::CappedString<N>,
+            ) -> Self
+            {
+                // A buffer which is too small could cause strings which match a case-insensitive
+                // variant to overflow it, so fall back to using our own buffer.
+                if N < #name_upper_cap {
+                    return <Self as #enumscribe::Unscribe>::unscribe(#to_unscribe_ident);
+                }
+
+                #with_buf_match
+            }
+        })
+    } else {
+        None
+    };
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    (quote! {
+        #[automatically_derived]
+        impl #impl_generics #enumscribe::Unscribe for #enum_ident #ty_generics #where_clause {
+            fn unscribe(#to_unscribe_ident: &str) -> Self {
+                #main_match
+            }
+
+            fn unscribe_tagged(#to_unscribe_ident: &str) -> (Self, bool) {
+                #tagged_match
+            }
+
+            #with_buf_fn
+        }
+    })
+    .into()
 }
 
 /// Derives [`enumscribe::TryUnscribe`](https://docs.rs/enumscribe/latest/enumscribe/trait.TryUnscribe.html) for an enum. This allows a `&str` to be converted to an
@@ -632,43 +2025,1476 @@ pub fn derive_unscribe(input: TokenStream) -> TokenStream {
 /// `#[enumscribe(str = "baa", case_insensitive)]`, then strings like `"baa"`, `"BAA"`, `"bAa"`
 /// etc. will all be matched to that variant.
 ///
+/// A case-sensitive name may be equal to a case-insensitive name when case is ignored, such as
+/// `"OK"` and `#[enumscribe(str = "ok", case_insensitive)]`. Case-sensitive names always take
+/// precedence, so `"OK"` is matched to the first variant, and any other casing such as `"ok"` or
+/// `"Ok"` is matched to the second. Two case-insensitive names which are equal when case is ignored
+/// will cause a compile-time error.
+///
+/// Case-insensitive matching normally converts the string to uppercase, which handles any
+/// Unicode letter. For names which are known to be ASCII, a variant may be annotated with
+/// `#[enumscribe(case_insensitive = "ascii")]` to only ignore the case of ASCII letters instead:
+/// the string is compared to each of its names with
+/// [`ascii_ci_eq`](https://docs.rs/enumscribe/latest/enumscribe/internal/ascii/fn.ascii_ci_eq.html),
+/// so no uppercase buffer is needed. Annotating the enum with it makes every variant
+/// case-insensitive in this way, unless a variant opts out with `#[enumscribe(case_sensitive)]`.
+/// If no variant needs the uppercase buffer, `ci_overflow` has no effect. This is supported by
+/// every derive which matches strings to variants.
+///
 /// Unlike [`Unscribe`](derive.Unscribe.html), there is no requirement to have a variant annotated
 /// with `#[enumscribe(other)]`, although you may use it if you want. If there is an `other`
 /// variant, then the `other` variant will be returned when a string could not be matched to any
 /// other variant. If there is no `other` variant, `None` will be returned when a string could not
 /// be matched to any other variant.
+///
+/// Annotating a variant with `#[enumscribe(accept_ident)]` causes the variant's identifier to be
+/// accepted in addition to its `str` name, so a variant `Heathrow` annotated with
+/// `#[enumscribe(str = "LHR", accept_ident)]` will be matched by both `"LHR"` and `"Heathrow"`.
+/// The identifier uses the same case sensitivity as the variant. Annotating the enum with
+/// `#[enumscribe(accept_ident)]` does this for every variant; individual variants may opt out
+/// with `#[enumscribe(accept_ident = false)]`. This is also supported by
+/// [`Unscribe`](derive.Unscribe.html) and [`EnumDeserialize`](derive.EnumDeserialize.html).
+///
+/// Other strings may be accepted for a variant with `#[enumscribe(alias = "...")]`, which uses the
+/// same case sensitivity as the variant and is only used for matching, never for scribing. Unlike
+/// other keys, `alias` may be given any number of times, including across several
+/// `#[enumscribe(...)]` attributes on the same variant (such as ones added by `cfg_attr`), and
+/// every alias is accepted. This is also supported by [`Unscribe`](derive.Unscribe.html),
+/// [`FromStr`](derive.FromStr.html), [`UnscribePrefix`](derive.UnscribePrefix.html) and
+/// [`EnumDeserialize`](derive.EnumDeserialize.html).
+///
+/// Case-insensitive matching converts the string to uppercase in a fixed-size buffer, large enough
+/// to hold the longest uppercase variant name. By default, a string which does not fit is treated
+/// like any other string that does not match, so the `other` variant is returned if there is one.
+/// Annotating the enum with `#[enumscribe(ci_overflow = "error")]` causes `None` to be returned for
+/// such strings instead, even if there is an `other` variant. This has no effect on
+/// [`Unscribe`](derive.Unscribe.html), which cannot fail.
+///
+/// Annotating the enum with `#[enumscribe(normalize_newlines)]` replaces every `"\r\n"` with
+/// `"\n"` in both the names of the variants and the string being matched, so Windows-style line
+/// endings match names written with Unix-style ones and vice versa. This is done before any
+/// case-insensitive conversion, and the `other` variant still stores the string unchanged. It is
+/// also supported by [`Unscribe`](derive.Unscribe.html), [`FromStr`](derive.FromStr.html) and
+/// [`EnumDeserialize`](derive.EnumDeserialize.html), but not by
+/// [`UnscribePrefix`](derive.UnscribePrefix.html).
+///
+/// Annotating the enum with `#[enumscribe(nfc)]` converts both the names of the variants and the
+/// string being matched to Unicode Normalization Form C, so a name containing `"\u{e9}"` ("é" as
+/// a single character) matches a string containing `"e\u{301}"` ("e" followed by a combining
+/// acute accent) and vice versa. The names are converted at compile time, and the string is
+/// converted on the stack, before any newline normalization or case-insensitive conversion. The
+/// `other` variant still stores the string unchanged. This requires the `nfc` feature of
+/// enumscribe to be enabled, which depends on the
+/// [`unicode-normalization`](https://docs.rs/unicode-normalization) crate and requires `alloc`. It
+/// is supported by the same derives as `normalize_newlines`, as well as
+/// [`UnscribeCandidates`](derive.UnscribeCandidates.html).
+///
+/// By default, the string is matched against the names of the variants using a `match`. For enums
+/// with a very large number of variants, annotating the enum with `#[enumscribe(phf)]` causes the
+/// names to be looked up in a perfect hash map generated at compile time instead, using the
+/// [`phf`](https://docs.rs/phf) crate. This does not change which strings are matched to which
+/// variants. It requires the `phf` feature of enumscribe to be enabled, and is also supported by
+/// [`Unscribe`](derive.Unscribe.html), [`FromStr`](derive.FromStr.html) and
+/// [`EnumDeserialize`](derive.EnumDeserialize.html).
+///
+/// The `other` variant may be annotated with `#[enumscribe(other, deny_prefix = "__")]` to prevent
+/// strings starting with the given prefix from being stored in it. `None` is returned for such
+/// strings instead. Strings which match a named variant are unaffected, even if they start with
+/// the prefix. This is also supported by [`EnumDeserialize`](derive.EnumDeserialize.html), but
+/// not by [`Unscribe`](derive.Unscribe.html), which cannot fail.
+///
+/// This derive also implements `unscribe_chars`, which matches the characters of an iterator
+/// without collecting them into a `String`. The characters are collected into a buffer on the
+/// stack which is sized to fit any string that could match a variant name, and `None` is returned
+/// if there are too many of them.
+///
+/// It also implements `try_unscribe_bytes`, which matches a byte slice. The bytes are compared
+/// directly against the names of case-sensitive variants, and are only checked to be valid UTF-8
+/// if they do not match any of them and the enum has case-insensitive variants, an `other` variant
+/// or uses `normalize_newlines` or `nfc`. Bytes which are not valid UTF-8 never match anything
+/// else.
+///
+/// `is_unknown` uses the same matching as `try_unscribe`, returning `false` for strings which
+/// would produce a named variant and `true` for all others, including those which would be stored
+/// in the `other` variant.
 #[proc_macro_derive(TryUnscribe, attributes(enumscribe))]
 pub fn derive_try_unscribe(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enumscribe = parsed_enum.crate_path();
+
+    proc_try!(check_other_owned(&input.ident, &parsed_enum, "TryUnscribe"));
+
+    let is_unknown_ident = quote! { __enumscribe_is_unknown };
+
+    // Matches in the same way as `try_unscribe`, but every string which would not produce a named
+    // variant is unknown, including strings which overflow the buffer or have a denied prefix
+    let is_unknown_match = proc_try!(gen_unscribe_match(
+        &input.ident,
+        &parsed_enum,
+        &is_unknown_ident,
+        |_| quote! { false },
+        |_| quote! { true },
+        |_| Ok(quote! { _ => true }),
+        Some(quote! { true }),
+        Some(quote! { true }),
+        None,
+        false,
+        false
+    ));
+
     gen_unscribe_impl(
-        input,
-        quote! { ::enumscribe::TryUnscribe },
+        &input,
+        &parsed_enum,
+        quote! { #enumscribe::TryUnscribe },
         quote! { try_unscribe },
         quote! { ::core::option::Option<Self> },
         |constructed_named_variant| quote! { ::core::option::Option::Some(#constructed_named_variant) },
         |constructed_other_variant| quote! { ::core::option::Option::Some(#constructed_other_variant) },
         |_| Ok(quote! { _ => ::core::option::Option::None }),
-    )
-}
+        Some(quote! { ::core::option::Option::None }),
+        Some(quote! { ::core::option::Option::None }),
+        |parsed_enum| {
+            let name_cap = parsed_enum.input_capacity();
 
-/// Derives `serde::Serialize` for an enum.
-///
-/// The enum will be serialized to a string. You can specify what string should be used to
-/// represent a particular variant by using `#[enumscribe(str = "foo")]`, just like the other
-/// derive macros in this crate.
+            let bytes_ident = quote! { __enumscribe_bytes };
+
+            // Case-sensitive names take precedence over everything else, so a byte string equal
+            // to one of them can be matched without checking that it is valid UTF-8.
+            let byte_arms = gen_case_sensitive_byte_arms(parsed_enum, |constructed_variant| {
+                quote! { ::core::option::Option::Some(#constructed_variant) }
+            });
+
+            // Any other match needs the bytes as a string, either to convert them to uppercase,
+            // to normalize them or to store them in the `other` variant.
+            let fallback = if parsed_enum.has_other()
+                || parsed_enum.has_case_insensitive()
+                || parsed_enum.normalize_newlines()
+                || parsed_enum.nfc()
+            {
+                quote! {
+                    ::core::option::Option::and_then(
+                        ::core::result::Result::ok(::core::str::from_utf8(#bytes_ident)),
+                        <Self as #enumscribe::TryUnscribe>::try_unscribe,
+                    )
+                }
+            } else {
+                quote! { ::core::option::Option::None }
+            };
+
+            let bytes_match = if byte_arms.is_empty() {
+                fallback
+            } else {
+                quote! {
+                    match #bytes_ident {
+                        #(#byte_arms,)*
+                        _ => #fallback,
+                    }
+                }
+            };
+
+            quote! {
+                fn unscribe_chars<I>(__enumscribe_chars: I) -> ::core::option::Option<Self>
+                where
+                    I: ::core::iter::Iterator<Item = char>,
+                {
+                    let __enumscribe_collected = #enumscribe::internal::capped_string::CappedString::<
+                        #name_cap
+                    >::from_chars(__enumscribe_chars)?;
+
+                    <Self as #enumscribe::TryUnscribe>::try_unscribe(&__enumscribe_collected)
+                }
+
+                fn try_unscribe_bytes(#bytes_ident: &[u8]) -> ::core::option::Option<Self> {
+                    #bytes_match
+                }
+
+                #[inline]
+                fn max_match_len() -> usize {
+                    #name_cap
+                }
+
+                fn is_unknown(#is_unknown_ident: &str) -> bool {
+                    #is_unknown_match
+                }
+            }
+        },
+    )
+}
+
+/// Derives [`enumscribe::UnscribeBytes`](https://docs.rs/enumscribe/latest/enumscribe/trait.UnscribeBytes.html) for an enum. This allows a `&[u8]` to be converted
+/// to the enum using the `unscribe_bytes()` associated function.
+///
+/// The bytes are compared against the UTF-8 encoding of each variant's string, in the same way as
+/// [`TryUnscribe`](derive.TryUnscribe.html)'s `try_unscribe_bytes`. Variants annotated with
+/// `#[enumscribe(case_insensitive = "ascii")]` are compared without checking that the bytes are
+/// valid UTF-8, and other case-insensitive variants only match bytes which are valid UTF-8.
+///
+/// The enum must have a variant annotated with `#[enumscribe(other)]`, which stores any bytes that
+/// do not match a named variant, including bytes which are not valid UTF-8. Its field is created
+/// from a `&[u8]` with `Into`, so it may be a `Vec<u8>` or a `Box<[u8]>`. The `other` variant may
+/// not use `norm_field`, `optional_field` or `deny_prefix`, and the enum may not use
+/// `normalize_newlines` or `nfc`, as they need the bytes as a string. As with
+/// [`Unscribe`](derive.Unscribe.html), variants may not use `fmt_fields`, and `empty_is_other` has
+/// no effect.
+#[proc_macro_derive(UnscribeBytes, attributes(enumscribe))]
+pub fn derive_unscribe_bytes(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enum_ident = &input.ident;
+    let enumscribe = parsed_enum.crate_path();
+
+    proc_try!(check_other_owned(enum_ident, &parsed_enum, "UnscribeBytes"));
+    proc_try!(check_other_not_optional(enum_ident, &parsed_enum, "UnscribeBytes"));
+
+    let string_only_key = if parsed_enum.normalize_newlines() {
+        Some(NORMALIZE_NEWLINES)
+    } else if parsed_enum.nfc() {
+        Some(NFC)
+    } else if parsed_enum.deny_prefix().is_some() {
+        Some(DENY_PREFIX)
+    } else {
+        None
+    };
+
+    if let Some(string_only_key) = string_only_key {
+        return MacroError::new(
+            format!(
+                "cannot derive UnscribeBytes for {} because it uses {}\n\
+                 explanation: {} needs the bytes to be converted to a string, but bytes which are \
+                 not valid UTF-8 are always stored in the {} variant",
+                enum_ident, string_only_key, string_only_key, OTHER
+            ),
+            enum_ident.span(),
+        )
+        .into();
+    }
+
+    let bytes_ident = quote! { __enumscribe_bytes };
+    let str_ident = quote! { __enumscribe_str };
+
+    let mut other_variant = None;
+    let mut ascii_ci_arms = Vec::new();
+    let mut ci_arms = Vec::new();
+
+    for variant in parsed_enum.variants() {
+        let variant_ident = &variant.data.ident;
+
+        match &variant.v_type {
+            VariantType::Named(named) if named.case_insensitive() => {
+                let constructor_tokens = named.constructor().empty_toks();
+                let names = named.match_names().zip(named.match_names_upper());
+
+                for (name, name_upper) in names {
+                    // The hook is given the input rather than the name, as the other derives do.
+                    // Bytes which match a name case-insensitively are always valid UTF-8, since
+                    // only the case of ASCII letters may differ from the name for ASCII
+                    // case-insensitivity, and the string was already decoded otherwise.
+                    let hook = match parsed_enum.on_deprecated() {
+                        Some(on_deprecated) if named.deprecated() => {
+                            let input_str = if named.ascii_case_insensitive() {
+                                quote! {
+                                    ::core::result::Result::ok(::core::str::from_utf8(#bytes_ident))
+                                }
+                            } else {
+                                quote! { #str_ident }
+                            };
+                            quote! {
+                                if let ::core::option::Option::Some(__enumscribe_deprecated_str) =
+                                    #input_str
+                                {
+                                    #on_deprecated(__enumscribe_deprecated_str);
+                                }
+                            }
+                        },
+                        _ => quote! {},
+                    };
+
+                    let result = quote! {
+                        {
+                            #hook
+                            Self::#variant_ident #constructor_tokens
+                        }
+                    };
+
+                    if named.ascii_case_insensitive() {
+                        let name_bytes = Literal::byte_string(name.as_bytes());
+                        ascii_ci_arms.push(quote! {
+                            _ if <[u8]>::eq_ignore_ascii_case(#bytes_ident, #name_bytes) => #result
+                        });
+                    } else {
+                        ci_arms.push(quote! {
+                            _ if ::core::option::Option::and_then(
+                                #str_ident,
+                                |__enumscribe_str| {
+                                    #enumscribe::internal::prefix::strip_uppercase_prefix(
+                                        __enumscribe_str,
+                                        #name_upper,
+                                    )
+                                },
+                            ) == ::core::option::Option::Some("") => #result
+                        });
+                    }
+                }
+            },
+
+            VariantType::Other(other) => {
+                if other.norm_field_name().is_some() {
+                    return MacroError::new(
+                        format!(
+                            "cannot derive UnscribeBytes for {} because its {} variant uses {}\n\
+                             explanation: {} stores a lowercase copy of the string, but bytes \
+                             which are not valid UTF-8 cannot be converted to lowercase",
+                            enum_ident, OTHER, NORM_FIELD, NORM_FIELD
+                        ),
+                        variant.span,
+                    )
+                    .into();
+                }
+
+                let value = quote! { <_ as ::core::convert::Into<_>>::into(#bytes_ident) };
+                other_variant = Some(match other.field_name() {
+                    Some(field_name) => quote! { Self::#variant_ident { #field_name: #value } },
+                    None => quote! { Self::#variant_ident(#value) },
+                });
+            },
+
+            VariantType::Formatted(_) => return fmt_fields_unscribe_error(enum_ident, variant).into(),
+
+            _ => (),
+        }
+    }
+
+    let other_variant = match other_variant {
+        Some(other_variant) => other_variant,
+        None => {
+            return MacroError::new(
+                format!(
+                    "cannot derive UnscribeBytes for {} because no variant is marked as {}\n\
+                     explanation: since there is no {} variant, it cannot be guaranteed that all \
+                     bytes can be successfully converted to a variant of {}\n\
+                     hint: either introduce an {} variant, or use TryUnscribe's \
+                     try_unscribe_bytes instead",
+                    enum_ident, OTHER, OTHER, enum_ident, OTHER
+                ),
+                enum_ident.span(),
+            )
+            .into();
+        }
+    };
+
+    let byte_arms = gen_case_sensitive_byte_arms(&parsed_enum, |constructed_variant| {
+        constructed_variant
+    });
+
+    // Only convert the bytes to a string if there are variants which need it
+    let str_binding = if ci_arms.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            let #str_ident = ::core::result::Result::ok(::core::str::from_utf8(#bytes_ident));
+        }
+    };
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    (quote! {
+        #[automatically_derived]
+        impl #impl_generics #enumscribe::UnscribeBytes for #enum_ident #ty_generics #where_clause {
+            fn unscribe_bytes(#bytes_ident: &[u8]) -> Self {
+                #str_binding
+
+                match #bytes_ident {
+                    #(#byte_arms,)*
+                    #(#ascii_ci_arms,)*
+                    #(#ci_arms,)*
+                    _ => #other_variant,
+                }
+            }
+        }
+    })
+    .into()
+}
+
+/// Derives `core::str::FromStr` for an enum. This allows a `&str` to be converted to the enum
+/// using `str::parse`.
+///
+/// Strings are matched to variants in exactly the same way as
+/// [`TryUnscribe`](derive.TryUnscribe.html), so all of the same attributes are supported, including
+/// `#[enumscribe(case_insensitive)]` on either the enum or individual variants. If a string could
+/// not be matched to any variant, [`enumscribe::UnscribeError`](https://docs.rs/enumscribe/latest/enumscribe/struct.UnscribeError.html) is returned.
+#[proc_macro_derive(FromStr, attributes(enumscribe))]
+pub fn derive_from_str(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enum_ident = &input.ident;
+    let enumscribe = parsed_enum.crate_path();
+
+    proc_try!(check_other_owned(enum_ident, &parsed_enum, "FromStr"));
+
+    let to_unscribe_ident = quote! { __enumscribe_to_unscribe };
+    let err = quote! { ::core::result::Result::Err(#enumscribe::UnscribeError) };
+
+    let main_match = proc_try!(gen_unscribe_match(
+        enum_ident,
+        &parsed_enum,
+        &to_unscribe_ident,
+        |constructed_named_variant| quote! { ::core::result::Result::Ok(#constructed_named_variant) },
+        |constructed_other_variant| quote! { ::core::result::Result::Ok(#constructed_other_variant) },
+        |_| Ok(quote! { _ => #err }),
+        Some(err.clone()),
+        Some(err.clone()),
+        None,
+        false,
+        true
+    ));
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    (quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::str::FromStr for #enum_ident #ty_generics #where_clause {
+            type Err = #enumscribe::UnscribeError;
+
+            fn from_str(#to_unscribe_ident: &str) -> ::core::result::Result<Self, Self::Err> {
+                #main_match
+            }
+        }
+    })
+    .into()
+}
+
+/// Derives [`enumscribe::EnumFlags`](https://docs.rs/enumscribe/latest/enumscribe/trait.EnumFlags.html) for an enum. This allows a list of strings, such as
+/// a field containing several flags, to be converted to a `Vec` of the enum using the
+/// `parse_set()` associated function.
+///
+/// Each string is converted using the enum's `TryUnscribe` implementation, so the enum must also
+/// derive [`TryUnscribe`](derive.TryUnscribe.html), and all of its attributes apply. The first
+/// string which cannot be converted causes
+/// [`enumscribe::UnscribeError`](https://docs.rs/enumscribe/latest/enumscribe/struct.UnscribeError.html) to be returned.
+#[cfg(feature = "alloc")]
+#[proc_macro_derive(EnumFlags, attributes(enumscribe))]
+pub fn derive_enum_flags(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enum_ident = &input.ident;
+    let enumscribe = parsed_enum.crate_path();
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    (quote! {
+        #[automatically_derived]
+        impl #impl_generics #enumscribe::EnumFlags for #enum_ident #ty_generics #where_clause {
+            fn parse_set(
+                __enumscribe_items: &[&str],
+            ) -> ::core::result::Result<
+                #enumscribe::internal::alloc::Vec<Self>,
+                #enumscribe::UnscribeError
+            > {
+                ::core::iter::Iterator::collect(::core::iter::Iterator::map(
+                    <[&str]>::iter(__enumscribe_items),
+                    |__enumscribe_item| ::core::option::Option::ok_or(
+                        <Self as #enumscribe::TryUnscribe>::try_unscribe(__enumscribe_item),
+                        #enumscribe::UnscribeError,
+                    ),
+                ))
+            }
+        }
+    })
+    .into()
+}
+
+/// Derives [`enumscribe::UnscribeCandidates`](https://docs.rs/enumscribe/latest/enumscribe/trait.UnscribeCandidates.html) for an enum. This allows every variant
+/// whose string starts with a given prefix to be found using the `unscribe_candidates()`
+/// associated function.
+///
+/// The strings of the variants are given by `#[enumscribe(str = "...")]`, aliases and
+/// `#[enumscribe(case_insensitive)]` in the same way as [`Unscribe`](derive.Unscribe.html). A
+/// variant marked with `#[enumscribe(other)]` is never returned. This derive requires the `std`
+/// feature of enumscribe.
+#[cfg(feature = "std")]
+#[proc_macro_derive(UnscribeCandidates, attributes(enumscribe))]
+pub fn derive_unscribe_candidates(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enum_ident = &input.ident;
+    let enumscribe = parsed_enum.crate_path();
+
+    let prefix_ident = quote! { __enumscribe_prefix };
+    let prefix_upper_ident = quote! { __enumscribe_prefix_upper };
+    let candidates_ident = quote! { __enumscribe_candidates };
+
+    let mut pushes = Vec::new();
+    for variant in parsed_enum.variants().iter() {
+        let variant_ident = &variant.data.ident;
+
+        match &variant.v_type {
+            VariantType::Named(named) => {
+                let names = if named.uppercase_case_insensitive() {
+                    named.match_names_upper().collect::<Vec<_>>()
+                } else {
+                    named.match_names().collect::<Vec<_>>()
+                };
+
+                let starts_with = names.iter().map(|name| {
+                    if !named.case_insensitive() {
+                        quote! { <str>::starts_with(#name, #prefix_ident) }
+                    } else if named.ascii_case_insensitive() {
+                        quote! {
+                            ::core::option::Option::is_some(
+                                &#enumscribe::internal::prefix::strip_ascii_prefix(
+                                    #name,
+                                    #prefix_ident
+                                )
+                            )
+                        }
+                    } else {
+                        quote! { <str>::starts_with(#name, #prefix_upper_ident) }
+                    }
+                });
+
+                let constructor_tokens = named.constructor().empty_toks();
+                pushes.push(quote! {
+                    if #(#starts_with)||* {
+                        #enumscribe::internal::alloc::Vec::push(
+                            &mut #candidates_ident,
+                            #enum_ident::#variant_ident #constructor_tokens
+                        );
+                    }
+                });
+            },
+
+            VariantType::Ignore(_) | VariantType::Other(_) => (),
+
+            VariantType::Formatted(_) => {
+                return fmt_fields_unscribe_error(enum_ident, variant).into();
+            },
+        }
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    if pushes.is_empty() {
+        return (quote! {
+            #[automatically_derived]
+            impl #impl_generics #enumscribe::UnscribeCandidates for #enum_ident #ty_generics #where_clause {
+                fn unscribe_candidates(_: &str) -> #enumscribe::internal::alloc::Vec<Self> {
+                    #enumscribe::internal::alloc::Vec::new()
+                }
+            }
+        })
+        .into();
+    }
+
+    // The names of the variants have their newlines normalized, so the prefix must be too.
+    let normalize_prefix = if parsed_enum.normalize_newlines() {
+        quote! {
+            let #prefix_ident = &*<str>::replace(#prefix_ident, "\r\n", "\n");
+        }
+    } else {
+        quote! {}
+    };
+
+    // Likewise for NFC, which is done before normalizing newlines.
+    let normalize_prefix = if parsed_enum.nfc() {
+        quote! {
+            let #prefix_ident = &*::core::iter::Iterator::collect::<#enumscribe::internal::alloc::String>(
+                #enumscribe::internal::unicode_normalization::UnicodeNormalization::nfc(#prefix_ident)
+            );
+            #normalize_prefix
+        }
+    } else {
+        normalize_prefix
+    };
+
+    let prefix_upper = if parsed_enum.has_uppercase_case_insensitive() {
+        quote! {
+            let #prefix_upper_ident = &*<str>::to_uppercase(#prefix_ident);
+        }
+    } else {
+        quote! {}
+    };
+
+    (quote! {
+        #[automatically_derived]
+        impl #impl_generics #enumscribe::UnscribeCandidates for #enum_ident #ty_generics #where_clause {
+            fn unscribe_candidates(
+                #prefix_ident: &str
+            ) -> #enumscribe::internal::alloc::Vec<Self> {
+                #normalize_prefix
+                #prefix_upper
+                let mut #candidates_ident = #enumscribe::internal::alloc::Vec::new();
+                #(#pushes)*
+                #candidates_ident
+            }
+        }
+    })
+    .into()
+}
+
+/// Derives `enumscribe::UnscribePrefix` for an enum. This allows a variant to be parsed from the
+/// start of a `&str`, returning the remainder of the string.
+///
+/// The names of the variants are given by `#[enumscribe(str = "...")]`, aliases and
+/// `#[enumscribe(case_insensitive)]` in the same way as [`Unscribe`](derive.Unscribe.html).
+/// Names are tried from longest to shortest, so when one name is a prefix of another (such as
+/// `"<"` and `"<="`), the longer name always wins if it matches. A variant marked with
+/// `#[enumscribe(other)]` is never matched, and `None` is returned if no name matches instead.
+#[proc_macro_derive(UnscribePrefix, attributes(enumscribe))]
+pub fn derive_unscribe_prefix(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enum_ident = &input.ident;
+    let enumscribe = parsed_enum.crate_path();
+
+    let to_unscribe_ident = quote! { __enumscribe_to_unscribe };
+    let rest_ident = quote! { __enumscribe_rest };
+
+    if parsed_enum.normalize_newlines() {
+        return MacroError::new(
+            format!(
+                "cannot derive UnscribePrefix for {} because it uses {}\n\
+                 explanation: the remainder of a string cannot be returned once its newlines have \
+                 been normalized",
+                enum_ident, NORMALIZE_NEWLINES
+            ),
+            enum_ident.span(),
+        )
+        .into();
+    }
+
+    if parsed_enum.nfc() {
+        return MacroError::new(
+            format!(
+                "cannot derive UnscribePrefix for {} because it uses {}\n\
+                 explanation: the remainder of a string cannot be returned once it has been \
+                 converted to NFC",
+                enum_ident, NFC
+            ),
+            enum_ident.span(),
+        )
+        .into();
+    }
+
+    let mut prefixes = Vec::new();
+
+    for variant in parsed_enum.variants().iter() {
+        let variant_ident = &variant.data.ident;
+
+        if let VariantType::Formatted(_) = &variant.v_type {
+            return fmt_fields_unscribe_error(enum_ident, variant).into();
+        }
+
+        if let VariantType::Named(named) = &variant.v_type {
+            let constructor_tokens = named.constructor().empty_toks();
+            let constructed_variant = quote! {
+                #enum_ident::#variant_ident #constructor_tokens
+            };
+
+            let match_names = if named.uppercase_case_insensitive() {
+                named.match_names_upper().collect::<Vec<_>>()
+            } else {
+                named.match_names().collect::<Vec<_>>()
+            };
+
+            for match_name in match_names {
+                let strip_prefix = if named.ascii_case_insensitive() {
+                    quote! {
+                        #enumscribe::internal::prefix::strip_ascii_prefix(
+                            #to_unscribe_ident,
+                            #match_name
+                        )
+                    }
+                } else if named.case_insensitive() {
+                    quote! {
+                        #enumscribe::internal::prefix::strip_uppercase_prefix(
+                            #to_unscribe_ident,
+                            #match_name
+                        )
+                    }
+                } else {
+                    quote! { <str>::strip_prefix(#to_unscribe_ident, #match_name) }
+                };
+
+                prefixes.push((
+                    match_name.len(),
+                    named.case_insensitive(),
+                    strip_prefix,
+                    constructed_variant.clone(),
+                ));
+            }
+        }
+    }
+
+    // Try the longest names first, so that a name is never shadowed by a shorter name which is a
+    // prefix of it. Like `TryUnscribe`, case-sensitive names are tried before case-insensitive
+    // names of the same length. The sort is stable, so names which are otherwise equal keep their
+    // declaration order.
+    prefixes.sort_by(|(len_a, ci_a, _, _), (len_b, ci_b, _, _)| {
+        len_b.cmp(len_a).then(ci_a.cmp(ci_b))
+    });
+
+    let prefix_checks = prefixes
+        .into_iter()
+        .map(|(_, _, strip_prefix, constructed_variant)| quote! {
+            if let ::core::option::Option::Some(#rest_ident) = #strip_prefix {
+                return ::core::option::Option::Some((#constructed_variant, #rest_ident));
+            }
+        });
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    (quote! {
+        #[automatically_derived]
+        impl #impl_generics #enumscribe::UnscribePrefix for #enum_ident #ty_generics #where_clause {
+            fn unscribe_prefix(#to_unscribe_ident: &str)
+                -> ::core::option::Option<(Self, &str)>
+            {
+                #(#prefix_checks)*
+                ::core::option::Option::None
+            }
+        }
+    })
+    .into()
+}
+
+fn gen_name_match(
+    parsed_enum: &Enum,
+    enum_ident: &Ident,
+    match_against: TokenStream2,
+    derive_name: &str,
+) -> MacroResult<TokenStream2> {
+    let mut match_arms = Vec::with_capacity(parsed_enum.variants().len());
+
+    for variant in parsed_enum.variants().iter() {
+        let arm = variant.match_variant(
+            enum_ident,
+            parsed_enum.crate_path(),
+            &|_, _, name| Ok(quote! { #name }),
+            &|_, _, field| Ok(quote! {
+                <_ as ::core::convert::AsRef<str>>::as_ref(#field)
+            }),
+            &|variant, enum_ident, _| Err(fmt_fields_error(derive_name, enum_ident, variant)),
+        )?;
+
+        match arm {
+            Some((pattern, result)) => match_arms.push(quote! { #pattern => #result }),
+            None => return Err(MacroError::new(
+                format!(
+                    "cannot derive {} for {} because the variant {} is marked as {}\n\
+                     explanation: since {} is ignored, it does not have a name to compare",
+                    derive_name, enum_ident, variant.data.ident, IGNORE, variant.data.ident
+                ),
+                variant.span,
+            )),
+        }
+    }
+
+    Ok(quote! {
+        match #match_against {
+            #(#match_arms,)*
+        }
+    })
+}
+
+/// Derives `core::hash::Hash` for an enum, hashing each variant by the string it would be scribed
+/// to.
+///
+/// Variants are hashed as the string given by `#[enumscribe(str = "...")]`, or the name of the
+/// variant if the attribute is omitted. A variant marked with `#[enumscribe(other)]` is hashed as
+/// the string stored in its field, so it will have the same hash as a named variant with the same
+/// string. The field must implement `AsRef<str>`.
+///
+/// Since `Hash` must agree with `Eq`, this should always be paired with
+/// [`EnumStrEq`](derive.EnumStrEq.html) rather than `#[derive(PartialEq, Eq)]`.
+///
+/// Using `#[enumscribe(ignore)]` with this derive will cause a compile-time error, as ignored
+/// variants have no string to hash.
+#[proc_macro_derive(EnumHashByName, attributes(enumscribe))]
+pub fn derive_enum_hash_by_name(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enum_ident = &input.ident;
+
+    let name_match = proc_try!(gen_name_match(
+        &parsed_enum,
+        enum_ident,
+        quote! { self },
+        "EnumHashByName"
+    ));
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    (quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::hash::Hash for #enum_ident #ty_generics #where_clause {
+            fn hash<__H: ::core::hash::Hasher>(&self, __enumscribe_state: &mut __H) {
+                let __enumscribe_name: &str = #name_match;
+                <str as ::core::hash::Hash>::hash(__enumscribe_name, __enumscribe_state)
+            }
+        }
+    })
+    .into()
+}
+
+/// Derives [`enumscribe::ScribeFixed`](https://docs.rs/enumscribe/latest/enumscribe/trait.ScribeFixed.html) for an enum. This allows the enum's name to be written
+/// into a byte array of any width using the `scribe_fixed()` method.
+///
+/// Names are determined in the same way as for `ScribeStaticStr`. The field of a variant marked
+/// with `#[enumscribe(other)]` must implement `AsRef<str>`, and its string is used as the name.
+/// Names shorter than the array are padded with spaces, or with the ASCII character given by
+/// `#[enumscribe(pad = '...')]` on the enum, and longer names are truncated.
+///
+/// This derive does not support ignoring variants with `#[enumscribe(ignore)]`. It requires the
+/// `fixed` feature of enumscribe.
+#[cfg(feature = "fixed")]
+#[proc_macro_derive(ScribeFixed, attributes(enumscribe))]
+pub fn derive_scribe_fixed(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enum_ident = &input.ident;
+    let enumscribe = parsed_enum.crate_path();
+    let pad = parsed_enum.pad();
+
+    let name_match = proc_try!(gen_name_match(
+        &parsed_enum,
+        enum_ident,
+        quote! { self },
+        "ScribeFixed"
+    ));
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    (quote! {
+        #[automatically_derived]
+        impl #impl_generics #enumscribe::ScribeFixed for #enum_ident #ty_generics #where_clause {
+            fn scribe_fixed<const W: usize>(&self) -> [u8; W] {
+                let __enumscribe_name: &str = #name_match;
+                #enumscribe::internal::fixed::pad_or_truncate::<W>(__enumscribe_name, #pad)
+            }
+        }
+    })
+    .into()
+}
+
+/// Derives `core::cmp::PartialEq` and `core::cmp::Eq` for an enum, comparing variants by the
+/// string they would be scribed to.
 ///
-/// This derive also allows you to use `#[enumscribe(other)]` and `#[enumscribe(ignore)]`.
-/// Trying to serialize an ignored variant will result in an error being returned. Serializing
-/// an `other` variant will simply use whatever the value of its field is.
-#[cfg(feature = "serde")]
-#[proc_macro_derive(EnumSerialize, attributes(enumscribe))]
-pub fn derive_enum_serialize(input: TokenStream) -> TokenStream {
+/// Two values are equal if their strings are equal, where the string for a variant is given by
+/// `#[enumscribe(str = "...")]`, or the name of the variant if the attribute is omitted. A variant
+/// marked with `#[enumscribe(other)]` is compared using the string stored in its field, which must
+/// implement `AsRef<str>`. This means that an `other` variant storing `"foo"` is equal to a named
+/// variant whose string is `"foo"`.
+///
+/// This is consistent with [`EnumHashByName`](derive.EnumHashByName.html), so the two can be used
+/// together to store the enum in a `HashSet` or as a `HashMap` key.
+///
+/// Using `#[enumscribe(ignore)]` with this derive will cause a compile-time error, as ignored
+/// variants have no string to compare.
+#[proc_macro_derive(EnumStrEq, attributes(enumscribe))]
+pub fn derive_enum_str_eq(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enum_ident = &input.ident;
+
+    let self_name_match = proc_try!(gen_name_match(
+        &parsed_enum,
+        enum_ident,
+        quote! { self },
+        "EnumStrEq"
+    ));
+
+    let other_name_match = proc_try!(gen_name_match(
+        &parsed_enum,
+        enum_ident,
+        quote! { __enumscribe_other },
+        "EnumStrEq"
+    ));
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    (quote! {
+        #[automatically_derived]
+        impl #impl_generics ::core::cmp::PartialEq for #enum_ident #ty_generics #where_clause {
+            fn eq(&self, __enumscribe_other: &Self) -> bool {
+                let __enumscribe_self_name: &str = #self_name_match;
+                let __enumscribe_other_name: &str = #other_name_match;
+                __enumscribe_self_name == __enumscribe_other_name
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics ::core::cmp::Eq for #enum_ident #ty_generics #where_clause {}
+    })
+    .into()
+}
+
+/// Derives [`enumscribe::EnumCount`](https://docs.rs/enumscribe/latest/enumscribe/trait.EnumCount.html) for an enum. This provides the `NUM_VARIANTS` and
+/// `NUM_NAMED_VARIANTS` associated constants.
+///
+/// `NUM_VARIANTS` counts every variant of the enum. `NUM_NAMED_VARIANTS` only counts variants
+/// which have a fixed string associated with them, so it excludes any variants annotated with
+/// `#[enumscribe(ignore)]`, `#[enumscribe(other)]` or `#[enumscribe(fmt_fields)]`. This is the
+/// same set of variants as [`EnumVariantNames`](derive.EnumVariantNames.html) lists.
+#[proc_macro_derive(EnumCount, attributes(enumscribe))]
+pub fn derive_enum_count(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enum_ident = &input.ident;
+    let enumscribe = parsed_enum.crate_path();
+
+    let num_variants = parsed_enum.variants().len();
+    let num_named_variants = parsed_enum
+        .variants()
+        .iter()
+        .filter(|variant| variant.v_type.as_named().is_some())
+        .count();
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    (quote! {
+        #[automatically_derived]
+        impl #impl_generics #enumscribe::EnumCount for #enum_ident #ty_generics #where_clause {
+            const NUM_VARIANTS: usize = #num_variants;
+            const NUM_NAMED_VARIANTS: usize = #num_named_variants;
+        }
+    })
+    .into()
+}
+
+/// Derives [`enumscribe::EnumAllScribed`](https://docs.rs/enumscribe/latest/enumscribe/trait.EnumAllScribed.html) for an enum. This provides the `all_scribed()`
+/// associated function, which returns a static slice of every named variant paired with its string.
+///
+/// The slice is built at compile time, so the enum must implement `Copy`. Variants annotated with
+/// `#[enumscribe(ignore)]`, `#[enumscribe(other)]` or `#[enumscribe(fmt_fields)]` do not have a
+/// single fixed string, so they are left out of the slice. `scribe_map` has no effect on this
+/// derive.
+#[proc_macro_derive(EnumAllScribed, attributes(enumscribe))]
+pub fn derive_enum_all_scribed(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enum_ident = &input.ident;
+    let enumscribe = parsed_enum.crate_path();
+
+    let entries = parsed_enum
+        .variants()
+        .iter()
+        .filter_map(|variant| {
+            let named = variant.v_type.as_named()?;
+            let variant_ident = &variant.data.ident;
+            let constructor_tokens = named.constructor().empty_toks();
+            let name = named.name();
+            Some(quote! { (#enum_ident::#variant_ident #constructor_tokens, #name) })
+        });
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // The slice is returned directly rather than through a `const` item, since an item nested in
+    // the function could not refer to the generics of the enum. It is promoted to a static all the
+    // same, as every entry is a constant.
+    (quote! {
+        #[automatically_derived]
+        impl #impl_generics #enumscribe::EnumAllScribed for #enum_ident #ty_generics #where_clause {
+            fn all_scribed() -> &'static [(Self, &'static str)] {
+                &[#(#entries),*]
+            }
+        }
+    })
+    .into()
+}
+
+/// Derives [`enumscribe::EnumVariantNames`](https://docs.rs/enumscribe/latest/enumscribe/trait.EnumVariantNames.html) for an enum. This provides the
+/// `VARIANT_NAMES` associated constant, a fixed-size array of the string of every named variant.
+///
+/// The length of the array is the number of named variants, so the trait is implemented as
+/// `EnumVariantNames<N>` for that particular `N`. Variants annotated with `#[enumscribe(ignore)]`,
+/// `#[enumscribe(other)]` or `#[enumscribe(fmt_fields)]` do not have a single fixed string, so
+/// they are left out of the array.
+#[proc_macro_derive(EnumVariantNames, attributes(enumscribe))]
+pub fn derive_enum_variant_names(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enum_ident = &input.ident;
+    let enumscribe = parsed_enum.crate_path();
+
+    let names = parsed_enum
+        .variants()
+        .iter()
+        .filter_map(|variant| variant.v_type.as_named())
+        .map(|named| named.name())
+        .collect::<Vec<_>>();
+
+    let num_names = names.len();
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    (quote! {
+        #[automatically_derived]
+        impl #impl_generics #enumscribe::EnumVariantNames<#num_names>
+            for #enum_ident #ty_generics
+            #where_clause
+        {
+            const VARIANT_NAMES: [&'static str; #num_names] = [#(#names),*];
+        }
+    })
+    .into()
+}
+
+/// Derives `From<Other> for Enum`, where `Other` is the enum given by
+/// `#[enumscribe(extend = Other)]`. Each variant of `Other` is converted to the variant of this
+/// enum which has the same name, so this is useful when one enum is a subset of another, such as
+/// a list of commands accepted in one context which are all valid in a wider one.
+///
+/// `Other` is converted to a string with [`ScribeStaticStr`](derive.ScribeStaticStr.html), so it
+/// must derive `ScribeStaticStr`, along with [`EnumCount`](derive.EnumCount.html) and
+/// [`EnumVariantNames`](derive.EnumVariantNames.html), which are used to check at compile time
+/// that every name of `Other` is also the name of a named variant of this enum. If any name is
+/// missing, the constant evaluation of that check fails. Names are compared exactly, even if a
+/// variant is matched case-insensitively, and aliases are not considered. Every variant of `Other`
+/// must be a named variant, so it cannot have variants marked with `#[enumscribe(ignore)]`, even
+/// if they have a placeholder.
+#[proc_macro_derive(EnumExtend, attributes(enumscribe))]
+pub fn derive_enum_extend(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enum_ident = &input.ident;
+    let enumscribe = parsed_enum.crate_path();
+
+    let extend = match parsed_enum.extend() {
+        Some(extend) => extend,
+        None => {
+            return MacroError::new(
+                format!(
+                    "cannot derive EnumExtend for {} because no enum to extend was given\n\
+                     hint: add #[enumscribe({} = ...)] to {}",
+                    enum_ident, EXTEND, enum_ident
+                ),
+                enum_ident.span(),
+            )
+            .into();
+        }
+    };
+
+    let mut names = Vec::new();
+    let mut match_arms = Vec::new();
+
+    for variant in parsed_enum.variants() {
+        if let Some(named) = variant.v_type.as_named() {
+            let variant_ident = &variant.data.ident;
+            let constructor_tokens = named.constructor().empty_toks();
+            let name = named.name();
+            names.push(name);
+            match_arms.push(quote! { #name => #enum_ident::#variant_ident #constructor_tokens, });
+        }
+    }
+
+    let extend_name = quote!(#extend).to_string().replace(' ', "");
+
+    // An ignored variant of `Other` may still be scribed to its placeholder, which is not among
+    // its `VARIANT_NAMES`, so it would not be covered by the check below.
+    let unnamed_message = format!(
+        "every variant of {} must be a named variant to derive EnumExtend for {}, so it cannot have \
+         variants marked as {}",
+        extend_name, enum_ident, IGNORE
+    );
+
+    let missing_message = format!(
+        "every variant of {} must have the same name as a named variant of {}",
+        extend_name, enum_ident
+    );
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    (quote! {
+        const _: () = ::core::assert!(
+            <#extend as #enumscribe::EnumCount>::NUM_VARIANTS
+                == <#extend as #enumscribe::EnumCount>::NUM_NAMED_VARIANTS,
+            #unnamed_message
+        );
+
+        const _: () = ::core::assert!(
+            #enumscribe::internal::extend::names_subset(
+                &<#extend as #enumscribe::EnumVariantNames<{
+                    <#extend as #enumscribe::EnumCount>::NUM_NAMED_VARIANTS
+                }>>::VARIANT_NAMES,
+                &[#(#names),*],
+            ),
+            #missing_message
+        );
+
+        #[automatically_derived]
+        impl #impl_generics ::core::convert::From<#extend> for #enum_ident #ty_generics
+        #where_clause
+        {
+            fn from(value: #extend) -> Self {
+                match #enumscribe::ScribeStaticStr::scribe(&value) {
+                    #(#match_arms)*
+                    _ => ::core::unreachable!(),
+                }
+            }
+        }
+    })
+    .into()
+}
+
+/// Derives [`enumscribe::EnumMessage`](https://docs.rs/enumscribe/latest/enumscribe/trait.EnumMessage.html) for an enum. This allows a description to be associated
+/// with each variant.
+///
+/// The message for a variant is specified with `#[enumscribe(message = "...")]`. Variants without
+/// this attribute have no message, so `None` is returned for them. Messages are independent of
+/// the strings used for scribing and unscribing, so they may be used on any variant, including
+/// those annotated with `#[enumscribe(ignore)]` or `#[enumscribe(other)]`.
+#[proc_macro_derive(EnumMessage, attributes(enumscribe))]
+pub fn derive_enum_message(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enum_ident = &input.ident;
+    let enumscribe = parsed_enum.crate_path();
+
+    let match_arms = parsed_enum.variants().iter().map(|variant| {
+        let variant_ident = &variant.data.ident;
+        let message = match variant.message.as_deref() {
+            Some(message) => quote! { ::core::option::Option::Some(#message) },
+            None => quote! { ::core::option::Option::None },
+        };
+        quote! { #enum_ident::#variant_ident { .. } => #message, }
+    });
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    (quote! {
+        #[automatically_derived]
+        impl #impl_generics #enumscribe::EnumMessage for #enum_ident #ty_generics #where_clause {
+            fn message(&self) -> ::core::option::Option<&'static str> {
+                match *self {
+                    #(#match_arms)*
+                }
+            }
+        }
+    })
+    .into()
+}
+
+/// Derives [`enumscribe::EnumRustName`](https://docs.rs/enumscribe/latest/enumscribe/trait.EnumRustName.html) for an enum. This allows the identifier of each
+/// variant to be retrieved using the `rust_name()` method.
+///
+/// The identifier is independent of `#[enumscribe(str = "...")]` and `rename_all`, so it may be
+/// used alongside the scribed string for debugging. Raw identifiers are returned without their
+/// `r#` prefix. Every variant has an identifier, so this may be used on any variant, including
+/// those annotated with `#[enumscribe(ignore)]` or `#[enumscribe(other)]`.
+#[proc_macro_derive(EnumRustName, attributes(enumscribe))]
+pub fn derive_enum_rust_name(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enum_ident = &input.ident;
+    let enumscribe = parsed_enum.crate_path();
+
+    let match_arms = parsed_enum.variants().iter().map(|variant| {
+        let variant_ident = &variant.data.ident;
+        let rust_name = variant_ident.unraw().to_string();
+        quote! { #enum_ident::#variant_ident { .. } => #rust_name, }
+    });
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    (quote! {
+        #[automatically_derived]
+        impl #impl_generics #enumscribe::EnumRustName for #enum_ident #ty_generics #where_clause {
+            fn rust_name(&self) -> &'static str {
+                match *self {
+                    #(#match_arms)*
+                }
+            }
+        }
+    })
+    .into()
+}
+
+/// Derives an `is_<variant>()` method for each variant of an enum, which returns whether the enum
+/// is that variant. This is a shorthand for `matches!`, so `airport.is_heathrow()` is equivalent
+/// to `matches!(airport, Airport::Heathrow { .. })`.
+///
+/// The name of each method is `is_` followed by the snake case identifier of the variant, so a
+/// variant named `LondonCity` gets an `is_london_city()` method. This is independent of
+/// `#[enumscribe(str = "...")]` and `rename_all`, and every variant gets a method, including
+/// those annotated with `#[enumscribe(ignore)]` or `#[enumscribe(other)]`. Since no strings are
+/// involved, the variants may have any fields. The methods are
+/// `const`, and have the same visibility as the enum. Two variants whose identifiers have the same
+/// snake case form, such as `FooBar` and `Foo_Bar`, cause a compile-time error.
+#[proc_macro_derive(EnumIs, attributes(enumscribe))]
+pub fn derive_enum_is(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    // The methods only depend on the identifiers of the variants, so the enum's attributes are
+    // not parsed, allowing this to be derived for enums which could not be scribed.
+    let (enum_data, _) = proc_try!(get_enum_data(&input));
+
+    let enum_ident = &input.ident;
+    let enum_vis = &input.vis;
+
+    let fn_names = enum_data
+        .variants
+        .iter()
+        .map(|variant| {
+            let fn_name = format!(
+                "is_{}",
+                RenameVariant::Snake.apply(&variant.ident.unraw().to_string())
+            );
+            (&variant.ident, fn_name)
+        })
+        .collect::<Vec<_>>();
+
+    proc_try!(check_unique_generated_names(enum_ident, "EnumIs", "method", &fn_names));
+
+    let mut methods = Vec::with_capacity(fn_names.len());
+
+    for (variant_ident, fn_name) in fn_names.iter() {
+        let fn_ident = Ident::new(fn_name, variant_ident.span());
+        let doc = format!(
+            "Returns `true` if this is [`{}::{}`].",
+            enum_ident,
+            variant_ident.unraw()
+        );
+
+        methods.push(quote! {
+            #[doc = #doc]
+            #[must_use]
+            #[inline]
+            #enum_vis const fn #fn_ident(&self) -> bool {
+                ::core::matches!(*self, Self::#variant_ident { .. })
+            }
+        });
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    (quote! {
+        #[automatically_derived]
+        #[allow(dead_code)]
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #(#methods)*
+        }
+    })
+    .into()
+}
+
+/// Derives an associated `<VARIANT>_STR` constant for each named variant of an enum, holding the
+/// string the variant is scribed to. Since they are constants, they can be used in `const`
+/// contexts and as patterns in a `match` on a `&str`, such as `Airport::HEATHROW_STR => ...`.
+///
+/// The name of each constant is the screaming snake case identifier of the variant followed by
+/// `_STR`, so a variant named `LondonCity` gets a `LONDON_CITY_STR` constant. Its value is given
+/// by `#[enumscribe(str = "...")]` and `rename_all` in the same way as
+/// [`ScribeStaticStr`](derive.ScribeStaticStr.html), but aliases are not included. Variants
+/// annotated with `#[enumscribe(other)]`, `#[enumscribe(ignore)]` or `#[enumscribe(fmt_fields)]`
+/// have no fixed string, so they get no constant. The constants have the same visibility as the
+/// enum. As with [`EnumIs`](derive.EnumIs.html), variants which would get constants with the same
+/// name cause a compile-time error.
+#[proc_macro_derive(EnumStrConsts, attributes(enumscribe))]
+pub fn derive_enum_str_consts(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enum_ident = &input.ident;
+    let enum_vis = &input.vis;
+
+    let named_variants = parsed_enum
+        .variants()
+        .iter()
+        .filter_map(|variant| variant.v_type.as_named().map(|named| (&variant.data.ident, named)))
+        .collect::<Vec<_>>();
+
+    let const_names = named_variants
+        .iter()
+        .map(|(variant_ident, _)| {
+            let const_name = format!(
+                "{}_STR",
+                RenameVariant::ScreamingSnake.apply(&variant_ident.unraw().to_string())
+            );
+            (*variant_ident, const_name)
+        })
+        .collect::<Vec<_>>();
+
+    proc_try!(check_unique_generated_names(enum_ident, "EnumStrConsts", "constant", &const_names));
+
+    let mut consts = Vec::with_capacity(named_variants.len());
+
+    for ((variant_ident, named), (_, const_name)) in named_variants.iter().zip(const_names.iter()) {
+        let const_ident = Ident::new(const_name, variant_ident.span());
+        let name = named.name();
+        let doc = format!(
+            "The string that [`{}::{}`] is scribed to.",
+            enum_ident,
+            variant_ident.unraw()
+        );
+
+        consts.push(quote! {
+            #[doc = #doc]
+            #enum_vis const #const_ident: &'static str = #name;
+        });
+    }
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    (quote! {
+        #[automatically_derived]
+        #[allow(dead_code)]
+        impl #impl_generics #enum_ident #ty_generics #where_clause {
+            #(#consts)*
+        }
+    })
+    .into()
+}
+
+/// Derives [`enumscribe::EnumMatchStrings`](https://docs.rs/enumscribe/latest/enumscribe/trait.EnumMatchStrings.html) for an enum. This allows every string which
+/// unscribes to a variant to be retrieved using the `match_strings()` method.
+///
+/// For a named variant, this returns its string followed by its aliases, in the order they were
+/// written. Any variant annotated with `#[enumscribe(other)]`, `#[enumscribe(ignore)]` or
+/// `#[enumscribe(fmt_fields)]` returns an empty slice.
+#[proc_macro_derive(EnumMatchStrings, attributes(enumscribe))]
+pub fn derive_enum_match_strings(input: TokenStream) -> TokenStream {
     let input: DeriveInput = syn::parse(input).expect("failed to parse input");
 
     let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
-    let parsed_enum = proc_try!(enums::parse_enum(enum_data, enum_attrs));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enum_ident = &input.ident;
+    let enumscribe = parsed_enum.crate_path();
+
+    let match_arms = parsed_enum.variants().iter().map(|variant| {
+        let variant_ident = &variant.data.ident;
+        let names = variant
+            .v_type
+            .as_named()
+            .map(|named| named.names().collect::<Vec<_>>())
+            .unwrap_or_default();
+        quote! { #enum_ident::#variant_ident { .. } => &[#(#names),*], }
+    });
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    (quote! {
+        #[automatically_derived]
+        impl #impl_generics #enumscribe::EnumMatchStrings for #enum_ident #ty_generics #where_clause {
+            fn match_strings(&self) -> &'static [&'static str] {
+                match *self {
+                    #(#match_arms)*
+                }
+            }
+        }
+    })
+    .into()
+}
+
+/// Generates a `#[cfg(feature = "...")]` attribute for the `Serialize` and `Deserialize`
+/// implementations, if the enum is annotated with `#[enumscribe(serde_optional)]`. The feature is
+/// one of the crate deriving the traits, not one of enumscribe.
+#[cfg(feature = "serde")]
+fn gen_serde_cfg(parsed_enum: &Enum) -> TokenStream2 {
+    match parsed_enum.serde_optional() {
+        Some(feature) => quote! { #[cfg(feature = #feature)] },
+        None => quote! {},
+    }
+}
+
+/// Generates a `match` expression which serializes `value`, a reference to the enum, using the
+/// serializer `serializer_ident`.
+#[cfg(feature = "serde")]
+fn gen_serialize_match(
+    enum_ident: &Ident,
+    parsed_enum: &Enum,
+    value: &TokenStream2,
+    serializer_ident: &TokenStream2,
+    derive_name: &str,
+) -> MacroResult<TokenStream2> {
+    // Generates code to serialize the given value. If `with_impl` is false, the value is
+    // serialized as a `&str`; otherwise, it is serialized using its own `Serialize` implementation.
+    let serialize_value = |value: TokenStream2, with_impl: bool| {
+        let value = if with_impl {
+            value
+        } else {
+            quote! {
+                {
+                    let __enumscribe_str: &str = #value;
+                    __enumscribe_str
+                }
+            }
+        };
 
-    let enum_ident = &input.ident;
-    let serializer_ident = quote! { __enumscribe_serializer };
+        match (parsed_enum.serialize_as(), with_impl) {
+            (SerializeAs::String, false) => quote! {
+                #serializer_ident.serialize_str(#value)
+            },
+            (SerializeAs::String, true) => quote! {
+                ::serde::Serialize::serialize(#value, #serializer_ident)
+            },
+            (SerializeAs::Tagged, _) => {
+                let tag = enum_ident.to_string();
+                quote! {
+                    {
+                        let mut __enumscribe_map = ::serde::Serializer::serialize_map(
+                            #serializer_ident,
+                            ::core::option::Option::Some(1)
+                        )?;
+                        ::serde::ser::SerializeMap::serialize_entry(
+                            &mut __enumscribe_map,
+                            #tag,
+                            #value
+                        )?;
+                        ::serde::ser::SerializeMap::end(__enumscribe_map)
+                    }
+                }
+            },
+        }
+    };
 
     let mut match_arms = Vec::new();
     let mut ignore_variant = false;
@@ -677,29 +3503,72 @@ pub fn derive_enum_serialize(input: TokenStream) -> TokenStream {
         let variant_ident = &variant.data.ident;
 
         match &variant.v_type {
-            VariantType::Ignore => ignore_variant = true,
+            VariantType::Ignore(ignored) => match ignored.placeholder() {
+                Some(placeholder) => {
+                    let pattern = variant.pattern_ignoring_fields(enum_ident);
+                    let serialized = serialize_value(quote! { #placeholder }, false);
+                    match_arms.push(quote! {
+                        #pattern => #serialized
+                    })
+                },
+                None => ignore_variant = true,
+            },
 
             VariantType::Named(named) => {
                 let constructor_tokens = named.constructor().empty_toks();
                 let name = named.name();
+                let serialized = serialize_value(quote! { #name }, false);
                 match_arms.push(quote! {
-                    #enum_ident::#variant_ident #constructor_tokens =>
-                        #serializer_ident.serialize_str(#name)
+                    #enum_ident::#variant_ident #constructor_tokens => #serialized
                 })
             }
 
-            VariantType::Other(other) => match other.field_name() {
-                Some(field_name) => match_arms.push(quote! {
-                    #enum_ident::#variant_ident { #field_name } =>
-                        #serializer_ident.serialize_str(&#field_name)
-                }),
-                None => {
-                    let field_name = quote! { __enumscribe_other_inner };
-                    match_arms.push(quote! {
-                        #enum_ident::#variant_ident(#field_name) =>
-                            #serializer_ident.serialize_str(&#field_name)
-                    })
-                }
+            VariantType::Other(other) => {
+                let (pattern, serialized) = match other.scribe_as() {
+                    Some(scribe_as) => (
+                        variant.pattern_ignoring_fields(enum_ident),
+                        serialize_value(quote! { #scribe_as }, false),
+                    ),
+                    None if other.serialize_with_impl() => {
+                        let field_name = other.binding();
+                        (
+                            other.pattern(enum_ident, variant_ident),
+                            serialize_value(quote! { &#field_name }, true),
+                        )
+                    },
+                    None => {
+                        let field_value = other.field_value();
+                        let serialized = match other.serialize_transform() {
+                            Some(case) => {
+                                let transformed = gen_runtime_rename(
+                                    parsed_enum,
+                                    variant,
+                                    quote! { &#field_value },
+                                    case
+                                )?;
+                                let serialized = serialize_value(
+                                    quote! { &__enumscribe_transformed },
+                                    false
+                                );
+                                quote! {
+                                    {
+                                        let __enumscribe_transformed = #transformed;
+                                        #serialized
+                                    }
+                                }
+                            },
+                            None => serialize_value(quote! { &#field_value }, false),
+                        };
+                        (other.pattern(enum_ident, variant_ident), serialized)
+                    },
+                };
+                match_arms.push(quote! {
+                    #pattern => #serialized
+                })
+            },
+
+            VariantType::Formatted(_) => {
+                return Err(fmt_fields_error(derive_name, enum_ident, variant));
             },
         }
     }
@@ -718,22 +3587,379 @@ pub fn derive_enum_serialize(input: TokenStream) -> TokenStream {
         quote! {}
     };
 
+    Ok(quote! {
+        match #value {
+            #(#match_arms,)*
+            #ignore_arm
+        }
+    })
+}
+
+/// Generates an expression converting the string `value` to `case` at runtime, which evaluates to
+/// a `String`.
+#[cfg(feature = "serde")]
+fn gen_runtime_rename(
+    parsed_enum: &Enum,
+    variant: &Variant,
+    value: TokenStream2,
+    case: RenameVariant,
+) -> MacroResult<TokenStream2> {
+    if !cfg!(feature = "alloc") {
+        return Err(MacroError::new(
+            format!(
+                "cannot use {} for variant {} because the alloc feature is not enabled\n\
+                 explanation: the converted string must be allocated at runtime\n\
+                 hint: enable the alloc feature of enumscribe",
+                SERIALIZE_TRANSFORM,
+                variant.data.ident
+            ),
+            variant.span,
+        ));
+    }
+
+    let enumscribe = parsed_enum.crate_path();
+    let case = match case {
+        RenameVariant::Lower => quote! { Lower },
+        RenameVariant::Upper => quote! { Upper },
+        RenameVariant::Pascal => quote! { Pascal },
+        RenameVariant::Camel => quote! { Camel },
+        RenameVariant::Snake => quote! { Snake },
+        RenameVariant::ScreamingSnake => quote! { ScreamingSnake },
+        RenameVariant::Kebab => quote! { Kebab },
+        RenameVariant::ScreamingKebab => quote! { ScreamingKebab },
+    };
+
+    Ok(quote! {
+        #enumscribe::internal::rename::rename(
+            #value,
+            #enumscribe::internal::rename::Case::#case
+        )
+    })
+}
+
+/// Derives `serde::Serialize` for an enum.
+///
+/// The enum will be serialized to a string. You can specify what string should be used to
+/// represent a particular variant by using `#[enumscribe(str = "foo")]`, just like the other
+/// derive macros in this crate.
+///
+/// This derive also allows you to use `#[enumscribe(other)]` and `#[enumscribe(ignore)]`.
+/// Trying to serialize an ignored variant will result in an error being returned. Serializing
+/// an `other` variant will simply use whatever the value of its field is.
+///
+/// The field of an `other` variant is serialized as a string by default, so it must dereference to
+/// a `str`. If the variant is annotated with `#[enumscribe(other, serialize_with_impl)]`, the field
+/// will instead be serialized using its own `Serialize` implementation. Note that this means an
+/// `other` variant may be serialized as something other than a string, depending on its field's
+/// type, so it may not be possible to deserialize it again with
+/// [`EnumDeserialize`](derive.EnumDeserialize.html).
+///
+/// If the `other` variant is annotated with `#[enumscribe(other, scribe_as = "...")]`, it is
+/// serialized as the given string rather than its field. This cannot be combined with
+/// `serialize_with_impl`.
+///
+/// If the `other` variant is annotated with `#[enumscribe(other, serialize_transform)]`, its field
+/// is converted to the case given by `rename_all` on the enum (or `rename` on the variant) before
+/// it is serialized, so unrecognised strings are written in the same case as the named variants.
+/// For example, with `rename_all = "snake_case"`, a field of `"LondonCity"` is serialized as
+/// `"london_city"`. The conversion is done at runtime, so it requires the `alloc` feature of
+/// enumscribe. Only `EnumSerialize` is affected, and this cannot be combined with
+/// `serialize_with_impl` or `scribe_as`.
+///
+/// Annotating the enum with `#[enumscribe(serialize_as = "tagged")]` will cause it to be
+/// serialized as a map with a single entry, whose key is the name of the enum and whose value is
+/// the string. For example, an `Airport` enum would be serialized as `{"Airport": "LHR"}` rather
+/// than `"LHR"`. The default is `#[enumscribe(serialize_as = "string")]`.
+///
+/// Since the enum is serialized as a string by default, it can be used as the key of a map in
+/// formats such as JSON which require map keys to be strings. This is not possible when using
+/// `#[enumscribe(serialize_as = "tagged")]`, as the enum is then serialized as a map.
+///
+/// Annotating the enum with `#[enumscribe(serde_optional = "my-feature")]` causes the generated
+/// implementation to only be compiled when the feature `my-feature` of *your* crate is enabled,
+/// so serde can be an optional dependency of your crate without having to write
+/// `#[cfg_attr(feature = "my-feature", derive(EnumSerialize))]`. `#[enumscribe(serde_optional)]`
+/// on its own uses a feature named `serde`. This is also supported by
+/// [`EnumDeserialize`](derive.EnumDeserialize.html). Note that the derive itself still requires
+/// the `serde` feature of enumscribe.
+///
+/// This derive may be used on generic enums. Any bounds needed to serialize the field of the
+/// `other` variant must be given on the enum itself, e.g. `enum Code<S> where S: Deref<Target = str>`.
+#[cfg(feature = "serde")]
+#[proc_macro_derive(EnumSerialize, attributes(enumscribe))]
+pub fn derive_enum_serialize(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enum_ident = &input.ident;
+    let serializer_ident = quote! { __enumscribe_serializer };
+
+    let serialize_match = proc_try!(gen_serialize_match(
+        enum_ident,
+        &parsed_enum,
+        &quote! { self },
+        &serializer_ident,
+        "EnumSerialize"
+    ));
+
+    let serde_cfg = gen_serde_cfg(&parsed_enum);
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
     (quote! {
+        #serde_cfg
         #[automatically_derived]
-        impl ::serde::Serialize for #enum_ident {
-            fn serialize<S>(&self, #serializer_ident: S) -> ::core::result::Result<S::Ok, S::Error>
-                where S: ::serde::Serializer
+        impl #impl_generics ::serde::Serialize for #enum_ident #ty_generics #where_clause {
+            fn serialize<__S>(&self, #serializer_ident: __S)
+                -> ::core::result::Result<__S::Ok, __S::Error>
+                where __S: ::serde::Serializer
             {
-                match self {
-                    #(#match_arms,)*
-                    #ignore_arm
-                }
+                #serialize_match
             }
         }
     })
     .into()
 }
 
+/// Generates the body of a function which deserializes the enum using the deserializer
+/// `deserializer_ident`. The function must have a `'de` lifetime parameter, which is the lifetime
+/// of the deserializer.
+#[cfg(feature = "serde")]
+fn gen_deserialize_body(
+    enum_ident: &Ident,
+    generics: &syn::Generics,
+    parsed_enum: &Enum,
+    deserializer_ident: &TokenStream2,
+) -> MacroResult<TokenStream2> {
+    let enumscribe = parsed_enum.crate_path();
+
+    let (generics_decl, ty_generics, where_clause) = generics.split_for_impl();
+    let mut de_generics = generics.clone();
+    de_generics.params.insert(0, syn::parse_quote! { 'de });
+    let (de_impl_generics, _, _) = de_generics.split_for_impl();
+
+    let deserialized_cow_str_ident = quote! { __enumscribe_deserialized_cow_str };
+    let deserialized_str_ident = quote! { __enumscribe_deserialized_str };
+
+    let mut variant_strings = parsed_enum
+        .variants()
+        .iter()
+        .filter_map(|variant| match &variant.v_type {
+            VariantType::Named(named) => Some(named.name()),
+            VariantType::Ignore(ignored) => ignored.document_str(),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    if parsed_enum.sort_expected() {
+        variant_strings.sort_unstable();
+    }
+
+    let denied_expecting = match parsed_enum.deny_prefix() {
+        Some(deny_prefix) => format!("a string not starting with {:?}", deny_prefix),
+        None => String::new(),
+    };
+
+    let ci_overflow_expecting = format!(
+        "a string no longer than {} bytes when converted to uppercase",
+        parsed_enum.name_upper_capacity()
+    );
+
+    let main_match = gen_unscribe_match(
+        enum_ident,
+        parsed_enum,
+        &deserialized_str_ident,
+        |constructed_named_variant| quote! {
+            ::core::result::Result::Ok(#constructed_named_variant)
+        },
+        |constructed_other_variant| quote! {
+            ::core::result::Result::Ok(#constructed_other_variant)
+        },
+        |_| Ok(match parsed_enum.de_error() {
+            Some(de_error) => quote! {
+                _ => ::core::result::Result::Err(
+                    ::serde::de::Error::custom(::core::format_args!(
+                        "{}: `{}`",
+                        #de_error,
+                        #deserialized_str_ident
+                    ))
+                )
+            },
+            None => quote! {
+                _ => ::core::result::Result::Err(
+                    ::serde::de::Error::unknown_variant(
+                        #deserialized_str_ident,
+                        &[#(#variant_strings),*]
+                    )
+                )
+            },
+        }),
+        Some(quote! {
+            ::core::result::Result::Err(
+                ::serde::de::Error::invalid_length(
+                    #deserialized_str_ident.len(),
+                    &#ci_overflow_expecting
+                )
+            )
+        }),
+        Some(quote! {
+            ::core::result::Result::Err(
+                ::serde::de::Error::invalid_value(
+                    ::serde::de::Unexpected::Str(#deserialized_str_ident),
+                    &#denied_expecting
+                )
+            )
+        }),
+        None,
+        parsed_enum.empty_is_other(),
+        true
+    )?;
+
+    // A string which matches a case-insensitive name may be longer than the name, and normalizing
+    // it may make it shorter, so the buffer must allow for both.
+    let name_cap = parsed_enum.input_capacity();
+
+    // An `other` variant must be able to capture strings of any length, so if there is one and
+    // we have access to `String`, deserialize into a string type which can own arbitrarily long
+    // data. Otherwise, any owned string longer than `name_cap` cannot match any variant.
+    let deserialized_str_type = if cfg!(feature = "alloc") && parsed_enum.has_other() {
+        quote! { #enumscribe::internal::cow_string::CowString<'de> }
+    } else {
+        quote! { #enumscribe::internal::capped_string::CowCappedString<'de, #name_cap> }
+    };
+
+    if parsed_enum.serde_native() {
+        let enum_name = enum_ident.to_string();
+        let expecting = format!("a unit variant of {}", enum_name);
+        let enum_access_ident = quote! { __enumscribe_enum_access };
+
+        return Ok(quote! {
+            struct __EnumscribeNativeVisitor #generics_decl (
+                ::core::marker::PhantomData<fn() -> #enum_ident #ty_generics>
+            ) #where_clause;
+
+            impl #de_impl_generics ::serde::de::Visitor<'de>
+                for __EnumscribeNativeVisitor #ty_generics
+                #where_clause
+            {
+                type Value = #enum_ident #ty_generics;
+
+                fn expecting(
+                    &self,
+                    f: &mut ::core::fmt::Formatter
+                ) -> ::core::fmt::Result
+                {
+                    f.write_str(#expecting)
+                }
+
+                fn visit_enum<A>(self, #enum_access_ident: A)
+                    -> ::core::result::Result<Self::Value, A::Error>
+                    where A: ::serde::de::EnumAccess<'de>
+                {
+                    let (#deserialized_cow_str_ident, __enumscribe_variant_access) =
+                        ::serde::de::EnumAccess::variant::<#deserialized_str_type>(
+                            #enum_access_ident
+                        )?;
+
+                    ::serde::de::VariantAccess::unit_variant(__enumscribe_variant_access)?;
+
+                    let #deserialized_str_ident = &*#deserialized_cow_str_ident;
+                    #main_match
+                }
+            }
+
+            ::serde::Deserializer::deserialize_enum(
+                #deserializer_ident,
+                #enum_name,
+                &[#(#variant_strings),*],
+                __EnumscribeNativeVisitor(::core::marker::PhantomData)
+            )
+        });
+    }
+
+    Ok(match parsed_enum.serialize_as() {
+        SerializeAs::String => quote! {
+            let #deserialized_cow_str_ident = <
+                #deserialized_str_type as ::serde::Deserialize<'_>
+            >::deserialize(#deserializer_ident)?;
+
+            let #deserialized_str_ident = &*#deserialized_cow_str_ident;
+            #main_match
+        },
+
+        SerializeAs::Tagged => {
+            let tag = enum_ident.to_string();
+            let tag_cap = tag.len();
+            let expecting = format!("a map with a single key \"{}\"", tag);
+            let map_ident = quote! { __enumscribe_map };
+
+            quote! {
+                struct __EnumscribeTaggedVisitor #generics_decl (
+                    ::core::marker::PhantomData<fn() -> #enum_ident #ty_generics>
+                ) #where_clause;
+
+                impl #de_impl_generics ::serde::de::Visitor<'de>
+                    for __EnumscribeTaggedVisitor #ty_generics
+                    #where_clause
+                {
+                    type Value = #enum_ident #ty_generics;
+
+                    fn expecting(
+                        &self,
+                        f: &mut ::core::fmt::Formatter
+                    ) -> ::core::fmt::Result
+                    {
+                        f.write_str(#expecting)
+                    }
+
+                    fn visit_map<A>(self, mut #map_ident: A)
+                        -> ::core::result::Result<Self::Value, A::Error>
+                        where A: ::serde::de::MapAccess<'de>
+                    {
+                        let __enumscribe_tag = match ::serde::de::MapAccess::next_key::<
+                            #enumscribe::internal::capped_string::CowCappedString<'de, #tag_cap>
+                        >(&mut #map_ident)? {
+                            ::core::option::Option::Some(tag) => tag,
+                            ::core::option::Option::None => return ::core::result::Result::Err(
+                                ::serde::de::Error::invalid_length(0, &self)
+                            ),
+                        };
+
+                        if &*__enumscribe_tag != #tag {
+                            return ::core::result::Result::Err(
+                                ::serde::de::Error::unknown_field(&*__enumscribe_tag, &[#tag])
+                            );
+                        }
+
+                        let #deserialized_cow_str_ident =
+                            ::serde::de::MapAccess::next_value::<#deserialized_str_type>(
+                                &mut #map_ident
+                            )?;
+
+                        if ::serde::de::MapAccess::next_key::<::serde::de::IgnoredAny>(
+                            &mut #map_ident
+                        )?.is_some() {
+                            return ::core::result::Result::Err(
+                                ::serde::de::Error::invalid_length(2, &self)
+                            );
+                        }
+
+                        let #deserialized_str_ident = &*#deserialized_cow_str_ident;
+                        #main_match
+                    }
+                }
+
+                ::serde::Deserializer::deserialize_map(
+                    #deserializer_ident,
+                    __EnumscribeTaggedVisitor(::core::marker::PhantomData)
+                )
+            }
+        },
+    })
+}
+
 /// Derives `serde::Deserialize` for an enum.
 ///
 /// The enum will be deserialized from a string. If the input was not a valid string, an error
@@ -748,70 +3974,260 @@ pub fn derive_enum_serialize(input: TokenStream) -> TokenStream {
 /// which should have type `String`. If an `other` variant is not included, an error will be
 /// returned when a string could not be matched to any variant.
 ///
+/// By default, this error is serde's "unknown variant" error, which lists the names of the
+/// variants. Annotating the enum with `#[enumscribe(de_error = "unsupported airport code")]`
+/// replaces it with a custom error containing the given message followed by the string which
+/// could not be matched, e.g. ``unsupported airport code: `STN` ``. Alternatively, annotating the
+/// enum with `#[enumscribe(sort_expected)]` keeps serde's error but lists the names in
+/// alphabetical order rather than the order the variants are declared in, which is easier to read
+/// for large enums.
+///
 /// This derive also allows you to use `#[enumscribe(ignore)]`. No string will ever deserialize
-/// to an ignored variant.
+/// to an ignored variant. An ignored variant may be annotated with
+/// `#[enumscribe(ignore, document_str = "...")]` to list the given string among the expected names
+/// in serde's "unknown variant" error, as a hint for users who can see the variant in the source.
+/// The string is still never matched, so deserializing it returns the same error. It has no
+/// effect when `de_error` is used.
+///
+/// The implementation can be made to depend on a feature of your crate with
+/// `#[enumscribe(serde_optional = "...")]`, in the same way as
+/// [`EnumSerialize`](derive.EnumSerialize.html).
+///
+/// This derive may be used on generic enums. Any bounds needed to construct the `other` variant
+/// from a `&str` must be given on the enum itself, e.g. `enum Code<S> where S: for<'a> From<&'a str>`.
+///
+/// Annotating the enum with `#[enumscribe(empty_is_other)]` causes an empty string to always be
+/// stored in the `other` variant, even if a variant is named `""`. If there is no `other` variant,
+/// deserializing an empty string returns the same error as any other unmatched string. This only
+/// affects `EnumDeserialize`; [`Unscribe`](derive.Unscribe.html) and
+/// [`TryUnscribe`](derive.TryUnscribe.html) still match empty strings against the names.
+///
+/// If the enum is annotated with `#[enumscribe(ci_overflow = "error")]`, an error will be returned
+/// when a string is too long to be converted to uppercase for case-insensitive matching, rather
+/// than using the `other` variant. See [`TryUnscribe`](derive.TryUnscribe.html) for details.
+///
+/// If the `other` variant is annotated with `#[enumscribe(other, deny_prefix = "...")]`, an error
+/// is returned when deserializing a string which would be stored in the `other` variant but starts
+/// with the given prefix. See [`TryUnscribe`](derive.TryUnscribe.html) for details.
+///
+/// If the enum is annotated with `#[enumscribe(serialize_as = "tagged")]`, the enum will be
+/// deserialized from a map with a single entry, whose key is the name of the enum and whose
+/// value is the string, matching the format produced by
+/// [`EnumSerialize`](derive.EnumSerialize.html).
+///
+/// If the enum is annotated with `#[enumscribe(serde_native)]`, it will instead be deserialized
+/// with `Deserializer::deserialize_enum`, in the same representation as a unit variant of an enum
+/// deriving serde's own `Deserialize`. In JSON, this accepts both `"LHR"` and `{"LHR": null}`.
+/// The name is still matched against the enumscribe names of the variants, so `other`,
+/// `case_insensitive` and the other attributes work as usual. This only affects
+/// `EnumDeserialize`, and cannot be combined with `#[enumscribe(serialize_as = "tagged")]`. Formats
+/// which identify variants by their index rather than their name, such as bincode, are not
+/// supported.
 #[cfg(feature = "serde")]
 #[proc_macro_derive(EnumDeserialize, attributes(enumscribe))]
 pub fn derive_enum_deserialize(input: TokenStream) -> TokenStream {
     let input: DeriveInput = syn::parse(input).expect("failed to parse input");
 
     let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
-    let parsed_enum = proc_try!(enums::parse_enum(enum_data, enum_attrs));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
 
     let enum_ident = &input.ident;
 
-    let deserializer_ident = quote! { __enumscribe_deserializer };
-    let deserialized_cow_capped_str_ident = quote! { __enumscribe_deserialized_cow_capped_str };
-    let deserialized_str_ident = quote! { __enumscribe_deserialized_str };
+    proc_try!(check_other_owned(enum_ident, &parsed_enum, "EnumDeserialize"));
 
-    let variant_strings = parsed_enum
-        .variants()
-        .iter()
-        .map(|variant| match &variant.v_type {
-            VariantType::Named(named) => Some(named.name()),
-            _ => None,
-        })
-        .filter_map(|name| name)
-        .collect::<Vec<_>>();
+    // The `'de` lifetime is added to the enum's own generic parameters for the `Deserialize` impl.
+    // Any bounds needed to construct the `other` variant from a `&str` must be given on the enum.
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+    let mut de_generics = input.generics.clone();
+    de_generics.params.insert(0, syn::parse_quote! { 'de });
+    let (de_impl_generics, _, _) = de_generics.split_for_impl();
 
-    let main_match = proc_try!(gen_unscribe_match(
+    let deserializer_ident = quote! { __enumscribe_deserializer };
+    let deserialize_body = proc_try!(gen_deserialize_body(
         enum_ident,
+        &input.generics,
         &parsed_enum,
-        &deserialized_str_ident,
-        |constructed_named_variant| quote! {
-            ::core::result::Result::Ok(#constructed_named_variant)
-        },
-        |constructed_other_variant| quote! {
-            ::core::result::Result::Ok(#constructed_other_variant)
-        },
-        |_| Ok(quote! {
-            _ => ::core::result::Result::Err(
-                ::serde::de::Error::unknown_variant(
-                    #deserialized_str_ident,
-                    &[#(#variant_strings),*]
-                )
-            )
-        }),
+        &deserializer_ident
     ));
 
-    let name_cap = parsed_enum.name_capacity();
+    let serde_cfg = gen_serde_cfg(&parsed_enum);
 
     (quote! {
+        #serde_cfg
         #[automatically_derived]
-        impl<'de> ::serde::Deserialize<'de> for #enum_ident {
-            fn deserialize<D>(#deserializer_ident: D) -> ::core::result::Result<Self, D::Error>
+        impl #de_impl_generics ::serde::Deserialize<'de> for #enum_ident #ty_generics
+            #where_clause
+        {
+            fn deserialize<__D>(#deserializer_ident: __D)
+                -> ::core::result::Result<Self, __D::Error>
+                where __D: ::serde::Deserializer<'de>
+            {
+                #deserialize_body
+            }
+        }
+    })
+    .into()
+}
+
+/// Generates a module for use with serde's `#[serde(with = "...")]` field attribute, which
+/// serializes and deserializes the enum in the same way as
+/// [`EnumSerialize`](derive.EnumSerialize.html) and [`EnumDeserialize`](derive.EnumDeserialize.html).
+///
+/// This is useful when the enum cannot implement `Serialize` and `Deserialize` itself, for example
+/// because it already has implementations which use a different format. The module is named after
+/// the enum in snake case followed by `_serde`, so an enum named `Airport` gets a module named
+/// `airport_serde`. Another name can be given with `#[enumscribe(serde_with = "...")]`. The module
+/// has the same visibility as the enum, and must be declared alongside it in a module rather than
+/// inside a function.
+///
+/// The module also contains an `option` submodule for fields of type `Option<Enum>`, which can be
+/// used with `#[serde(with = "airport_serde::option")]`.
+///
+/// The same attributes as `EnumSerialize` and `EnumDeserialize` are supported, including
+/// `#[enumscribe(serialize_as = "tagged")]` and `#[enumscribe(serde_optional)]`. Unlike those
+/// derives, the enum may not have any generic parameters.
+#[cfg(feature = "serde")]
+#[proc_macro_derive(EnumSerdeWith, attributes(enumscribe))]
+pub fn derive_enum_serde_with(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).expect("failed to parse input");
+
+    let (enum_data, enum_attrs) = proc_try!(get_enum_data(&input));
+    let parsed_enum = proc_try!(parse_enum(enum_data, enum_attrs));
+
+    let enum_ident = &input.ident;
+    let enum_vis = &input.vis;
+
+    proc_try!(check_other_owned(enum_ident, &parsed_enum, "EnumSerdeWith"));
+
+    // The generated functions name the enum's type directly, so it cannot have any parameters
+    if !input.generics.params.is_empty() {
+        return MacroError::new(
+            format!(
+                "cannot derive EnumSerdeWith for {} because it has generic parameters\n\
+                 explanation: the functions in the generated module take and return {} without \
+                 any parameters\n\
+                 hint: try deriving EnumSerialize and EnumDeserialize instead",
+                enum_ident, enum_ident
+            ),
+            input.generics.span(),
+        )
+        .into();
+    }
+
+    let module_ident = match parsed_enum.serde_with() {
+        Some(module_ident) => module_ident.clone(),
+        None => Ident::new(
+            &format!("{}_serde", RenameVariant::Snake.apply(&enum_ident.to_string())),
+            enum_ident.span(),
+        ),
+    };
+
+    let value_ident = quote! { __enumscribe_value };
+    let serializer_ident = quote! { __enumscribe_serializer };
+    let deserializer_ident = quote! { __enumscribe_deserializer };
+
+    let serialize_match = proc_try!(gen_serialize_match(
+        enum_ident,
+        &parsed_enum,
+        &value_ident,
+        &serializer_ident,
+        "EnumSerdeWith"
+    ));
+
+    let deserialize_body = proc_try!(gen_deserialize_body(
+        enum_ident,
+        &input.generics,
+        &parsed_enum,
+        &deserializer_ident
+    ));
+
+    let module_doc = format!(
+        "Serializes and deserializes [`{}`] as a string, for use with `#[serde(with = \"{}\")]`.",
+        enum_ident, module_ident
+    );
+    let option_doc = format!(
+        "Serializes and deserializes `Option<{}>`, for use with `#[serde(with = \"{}::option\")]`.",
+        enum_ident, module_ident
+    );
+
+    let serde_cfg = gen_serde_cfg(&parsed_enum);
+
+    (quote! {
+        #serde_cfg
+        #[doc = #module_doc]
+        #[allow(dead_code)]
+        #enum_vis mod #module_ident {
+            use super::#enum_ident;
+
+            /// Serializes the given value as a string.
+            pub fn serialize<S>(
+                #value_ident: &#enum_ident,
+                #serializer_ident: S
+            ) -> ::core::result::Result<S::Ok, S::Error>
+                where S: ::serde::Serializer
+            {
+                #serialize_match
+            }
+
+            /// Deserializes a value from a string.
+            pub fn deserialize<'de, D>(
+                #deserializer_ident: D
+            ) -> ::core::result::Result<#enum_ident, D::Error>
                 where D: ::serde::Deserializer<'de>
             {
-                let #deserialized_cow_capped_str_ident = <
-                    ::enumscribe
-                        ::internal
-                        ::capped_string
-                        ::CowCappedString<'de, #name_cap>
-                    as ::serde::Deserialize<'_>
-                >::deserialize(#deserializer_ident)?;
-
-                let #deserialized_str_ident = &*#deserialized_cow_capped_str_ident;
-                #main_match
+                #deserialize_body
+            }
+
+            #[doc = #option_doc]
+            pub mod option {
+                use super::#enum_ident;
+
+                struct __EnumscribeSerializeWith<'a>(&'a #enum_ident);
+
+                impl ::serde::Serialize for __EnumscribeSerializeWith<'_> {
+                    fn serialize<S>(&self, #serializer_ident: S) -> ::core::result::Result<S::Ok, S::Error>
+                        where S: ::serde::Serializer
+                    {
+                        super::serialize(self.0, #serializer_ident)
+                    }
+                }
+
+                struct __EnumscribeDeserializeWith(#enum_ident);
+
+                impl<'de> ::serde::Deserialize<'de> for __EnumscribeDeserializeWith {
+                    fn deserialize<D>(#deserializer_ident: D) -> ::core::result::Result<Self, D::Error>
+                        where D: ::serde::Deserializer<'de>
+                    {
+                        super::deserialize(#deserializer_ident).map(__EnumscribeDeserializeWith)
+                    }
+                }
+
+                /// Serializes the given value as a string, or as none if it is `None`.
+                pub fn serialize<S>(
+                    #value_ident: &::core::option::Option<#enum_ident>,
+                    #serializer_ident: S
+                ) -> ::core::result::Result<S::Ok, S::Error>
+                    where S: ::serde::Serializer
+                {
+                    match #value_ident {
+                        ::core::option::Option::Some(#value_ident) => {
+                            #serializer_ident.serialize_some(&__EnumscribeSerializeWith(#value_ident))
+                        },
+                        ::core::option::Option::None => #serializer_ident.serialize_none(),
+                    }
+                }
+
+                /// Deserializes an optional value from a string or none.
+                pub fn deserialize<'de, D>(
+                    #deserializer_ident: D
+                ) -> ::core::result::Result<::core::option::Option<#enum_ident>, D::Error>
+                    where D: ::serde::Deserializer<'de>
+                {
+                    let #value_ident = <
+                        ::core::option::Option<__EnumscribeDeserializeWith> as ::serde::Deserialize<'de>
+                    >::deserialize(#deserializer_ident)?;
+                    ::core::result::Result::Ok(#value_ident.map(|#value_ident| #value_ident.0))
+                }
             }
         }
     })
@@ -844,3 +4260,31 @@ fn get_enum_data(input: &DeriveInput) -> MacroResult<(&DataEnum, &[Attribute])>
 
     Ok((enum_data, &input.attrs))
 }
+
+/// Returns an error if two variants of the enum would generate items with the same name. Each
+/// element of `names` is a variant's identifier and the name of the item generated for it, and
+/// `kind` describes the item, such as "method".
+fn check_unique_generated_names(
+    enum_ident: &Ident,
+    derive_name: &str,
+    kind: &str,
+    names: &[(&Ident, String)],
+) -> MacroResult<()> {
+    let mut taken_names = HashMap::new();
+
+    for (variant_ident, name) in names {
+        if let Some(other_ident) = taken_names.insert(name, variant_ident) {
+            return Err(MacroError::new(
+                format!(
+                    "cannot derive {} for {} because the variants {} and {} would both have a \
+                     {} named {}\n\
+                     hint: rename one of the variants",
+                    derive_name, enum_ident, other_ident, variant_ident, kind, name
+                ),
+                variant_ident.span(),
+            ));
+        }
+    }
+
+    Ok(())
+}
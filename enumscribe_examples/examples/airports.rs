@@ -29,6 +29,9 @@ fn main() {
     let other = Airport::Other("Dedicated EasyJet-only airport".to_owned());
     println!("Hello, {:?}!", other.try_scribe());
 
+    let birmingham = Airport::BirminghamInternational;
+    println!("Hello, {:?}!", birmingham.try_scribe());
+
     println!();
 
     println!("{:?}", Airport::unscribe("LHR"));
@@ -81,6 +81,7 @@ fn other_example() {
 fn ignore_example() {
     use enumscribe::TryScribeStaticStr;
 
+    #[allow(dead_code)]
     #[derive(TryScribeStaticStr, PartialEq, Eq, Debug)]
     enum Airport {
         #[enumscribe(str = "LHR")]
@@ -0,0 +1,12 @@
+use enumscribe::TryUnscribe;
+
+#[derive(TryUnscribe)]
+enum Airport {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+    #[enumscribe(str = "LGW")]
+    #[enumscribe(alias = "LHR")]
+    Gatwick,
+}
+
+fn main() {}
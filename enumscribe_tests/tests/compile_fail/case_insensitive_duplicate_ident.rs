@@ -0,0 +1,12 @@
+use enumscribe::TryUnscribe;
+
+#[derive(TryUnscribe)]
+#[enumscribe(case_insensitive, accept_ident)]
+enum Airport {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+    #[enumscribe(str = "heathrow")]
+    Gatwick,
+}
+
+fn main() {}
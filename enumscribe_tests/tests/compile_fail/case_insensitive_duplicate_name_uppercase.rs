@@ -0,0 +1,11 @@
+use enumscribe::TryUnscribe;
+
+#[derive(TryUnscribe)]
+enum Word {
+    #[enumscribe(str = "groß", case_insensitive)]
+    Gross,
+    #[enumscribe(str = "gross", case_insensitive)]
+    Gross2,
+}
+
+fn main() {}
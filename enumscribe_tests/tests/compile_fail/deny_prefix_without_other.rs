@@ -0,0 +1,9 @@
+use enumscribe::TryUnscribe;
+
+#[derive(TryUnscribe)]
+enum Airport {
+    #[enumscribe(str = "LHR", deny_prefix = "__")]
+    Heathrow,
+}
+
+fn main() {}
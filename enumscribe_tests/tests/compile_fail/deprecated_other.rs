@@ -0,0 +1,14 @@
+use enumscribe::Unscribe;
+
+fn log_deprecated(_: &str) {}
+
+#[derive(Unscribe)]
+#[enumscribe(on_deprecated = "log_deprecated")]
+enum Airport {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+    #[enumscribe(other, deprecated)]
+    Other(String),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use enumscribe::EnumDeserialize;
+
+#[derive(EnumDeserialize)]
+enum Airport<'a> {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+    #[enumscribe(other, raw_field = "raw", norm_field = "norm")]
+    Other { raw: String, norm: &'a str },
+}
+
+fn main() {}
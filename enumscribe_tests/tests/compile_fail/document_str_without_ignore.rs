@@ -0,0 +1,9 @@
+use enumscribe::EnumDeserialize;
+
+#[derive(EnumDeserialize)]
+enum Airport {
+    #[enumscribe(str = "LHR", document_str = "Heathrow")]
+    Heathrow,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use enumscribe::TryUnscribe;
+
+#[derive(TryUnscribe)]
+enum Airport {
+    #[enumscribe(str = "LHR")]
+    #[cfg_attr(all(), enumscribe(str = "EGLL"))]
+    Heathrow,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use enumscribe::Unscribe;
+
+#[derive(Unscribe)]
+enum Airport {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+    #[enumscribe(str = "LHR")]
+    Gatwick,
+    #[enumscribe(other)]
+    Other(String),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use enumscribe::EnumIs;
+
+#[derive(EnumIs)]
+#[allow(non_camel_case_types)]
+enum Word {
+    FooBar,
+    Foo_Bar,
+}
+
+fn main() {}
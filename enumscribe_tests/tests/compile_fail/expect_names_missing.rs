@@ -0,0 +1,12 @@
+use enumscribe::ScribeStaticStr;
+
+#[derive(ScribeStaticStr)]
+#[enumscribe(expect_names("LHR", "LGW", "LTN", "STN", "LTN"))]
+enum Airport {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+    #[enumscribe(str = "LGW")]
+    Gatwick,
+}
+
+fn main() {}
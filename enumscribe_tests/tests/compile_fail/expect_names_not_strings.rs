@@ -0,0 +1,10 @@
+use enumscribe::ScribeStaticStr;
+
+#[derive(ScribeStaticStr)]
+#[enumscribe(expect_names("LHR", 1))]
+enum Airport {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use enumscribe::ScribeStaticStr;
+
+#[derive(ScribeStaticStr)]
+#[enumscribe(expect_names("LHR", "LGW"))]
+enum Airport {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+    #[enumscribe(str = "LGW")]
+    Gatwick,
+    #[enumscribe(str = "STN")]
+    Stansted,
+}
+
+fn main() {}
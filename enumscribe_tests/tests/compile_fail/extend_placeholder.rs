@@ -0,0 +1,20 @@
+use enumscribe::{EnumCount, EnumExtend, EnumVariantNames, ScribeStaticStr};
+
+#[derive(ScribeStaticStr, EnumCount, EnumVariantNames)]
+enum LondonAirport {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+    #[enumscribe(ignore, placeholder = "N/A")]
+    Secret,
+}
+
+#[derive(EnumExtend)]
+#[enumscribe(extend = LondonAirport)]
+enum Airport {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+    #[enumscribe(str = "MAN")]
+    Manchester,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use enumscribe::EnumExtend;
+
+#[derive(EnumExtend)]
+enum Airport {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use enumscribe::ScribeCowStr;
+
+#[derive(ScribeCowStr)]
+enum Address {
+    #[enumscribe(str = "localhost", fmt_fields)]
+    Localhost,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use enumscribe::FromStr;
+
+#[derive(FromStr)]
+enum Airport {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+    #[enumscribe(other, optional_field)]
+    Other(Option<&'static str>),
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use enumscribe::TryUnscribe;
+
+#[derive(TryUnscribe)]
+#[enumscribe(lint)]
+enum Letter {
+    #[enumscribe(str = "a", case_insensitive = "ascii")]
+    A,
+    #[enumscribe(str = "é", case_insensitive = "ascii")]
+    EAcute,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use enumscribe::TryUnscribe;
+
+#[derive(TryUnscribe)]
+#[enumscribe(lint)]
+enum Status {
+    #[enumscribe(str = "ok", case_insensitive)]
+    Ok,
+    #[enumscribe(str = "404", case_insensitive)]
+    NotFound,
+}
+
+fn main() {}
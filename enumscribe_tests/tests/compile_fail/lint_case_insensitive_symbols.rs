@@ -0,0 +1,12 @@
+use enumscribe::TryUnscribe;
+
+#[derive(TryUnscribe)]
+#[enumscribe(lint)]
+enum Arrow {
+    #[enumscribe(str = "->", alias = "=>", case_insensitive = "ascii")]
+    Right,
+    #[enumscribe(str = "<-")]
+    Left,
+}
+
+fn main() {}
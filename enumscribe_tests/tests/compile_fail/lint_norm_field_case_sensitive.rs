@@ -0,0 +1,12 @@
+use enumscribe::Unscribe;
+
+#[derive(Unscribe)]
+#[enumscribe(lint)]
+enum Airport {
+    #[enumscribe(str = "404", alias = "LHR")]
+    Heathrow,
+    #[enumscribe(other, raw_field = "raw", norm_field = "norm")]
+    Other { raw: String, norm: String },
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use enumscribe::TryUnscribe;
+
+#[derive(TryUnscribe)]
+#[enumscribe(nfc)]
+enum Word {
+    #[enumscribe(str = "caf\u{e9}")]
+    Composed,
+    #[enumscribe(str = "cafe\u{301}")]
+    Decomposed,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use enumscribe::TryUnscribe;
+
+#[derive(TryUnscribe)]
+#[enumscribe(normalize_newlines)]
+enum Line {
+    #[enumscribe(str = "end\n")]
+    Unix,
+    #[enumscribe(str = "end\r\n")]
+    Windows,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use enumscribe::ScribeCowStr;
+
+#[derive(ScribeCowStr)]
+enum Airport {
+    #[enumscribe(str = "LHR", optional_field)]
+    Heathrow,
+    #[enumscribe(other)]
+    Other(String),
+}
+
+fn main() {}
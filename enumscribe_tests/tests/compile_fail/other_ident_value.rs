@@ -0,0 +1,9 @@
+use enumscribe::TryUnscribe;
+
+#[derive(TryUnscribe)]
+enum Airport {
+    #[enumscribe(other = maybe)]
+    Heathrow,
+}
+
+fn main() {}
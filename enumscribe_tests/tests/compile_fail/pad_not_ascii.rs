@@ -0,0 +1,10 @@
+use enumscribe::ScribeFixed;
+
+#[derive(ScribeFixed)]
+#[enumscribe(pad = 'é')]
+enum Airport {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use enumscribe::ScribeStaticStr;
+
+#[derive(ScribeStaticStr)]
+enum Airport {
+    #[enumscribe(str = "LHR", placeholder = "N/A")]
+    Heathrow,
+}
+
+fn main() {}
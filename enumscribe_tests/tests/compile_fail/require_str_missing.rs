@@ -0,0 +1,11 @@
+use enumscribe::ScribeStaticStr;
+
+#[derive(ScribeStaticStr)]
+#[enumscribe(require_str)]
+enum Airport {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+    Gatwick,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use enumscribe::EnumSerialize;
+
+#[derive(EnumSerialize)]
+enum Airport {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+    #[enumscribe(other, scribe_as = "<redacted>", serialize_with_impl)]
+    Other(String),
+}
+
+fn main() {}
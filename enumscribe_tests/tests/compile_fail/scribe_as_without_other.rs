@@ -0,0 +1,9 @@
+use enumscribe::ScribeCowStr;
+
+#[derive(ScribeCowStr)]
+enum Airport {
+    #[enumscribe(str = "LHR", scribe_as = "<redacted>")]
+    Heathrow,
+}
+
+fn main() {}
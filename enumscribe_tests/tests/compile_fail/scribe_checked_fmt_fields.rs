@@ -0,0 +1,14 @@
+use enumscribe::ScribeCowStr;
+
+#[derive(ScribeCowStr)]
+#[enumscribe(scribe_checked)]
+enum Address {
+    #[enumscribe(str = "localhost")]
+    Localhost,
+    #[enumscribe(str = "port:{}", fmt_fields)]
+    Port(u16),
+    #[enumscribe(other)]
+    Other(String),
+}
+
+fn main() {}
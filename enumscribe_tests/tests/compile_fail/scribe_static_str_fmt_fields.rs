@@ -0,0 +1,11 @@
+use enumscribe::ScribeStaticStr;
+
+#[derive(ScribeStaticStr)]
+enum Address {
+    #[enumscribe(str = "localhost")]
+    Localhost,
+    #[enumscribe(str = "port:{}", fmt_fields)]
+    Port(u16),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use enumscribe::ScribeStaticStr;
+
+#[derive(ScribeStaticStr)]
+enum Airport {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+    #[enumscribe(ignore)]
+    Secret(i32),
+}
+
+fn main() {}
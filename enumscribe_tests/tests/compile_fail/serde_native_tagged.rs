@@ -0,0 +1,10 @@
+use enumscribe::EnumDeserialize;
+
+#[derive(EnumDeserialize)]
+#[enumscribe(serde_native, serialize_as = "tagged")]
+enum Airport {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use enumscribe::EnumSerdeWith;
+
+#[derive(EnumSerdeWith)]
+pub enum Code<S>
+where
+    S: From<String>,
+{
+    #[enumscribe(str = "ok")]
+    Ok,
+    #[enumscribe(other)]
+    Other(S),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use enumscribe::EnumSerialize;
+
+#[derive(EnumSerialize)]
+enum Airport {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+    #[enumscribe(other, serialize_transform)]
+    Other(String),
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use enumscribe::ScribeStaticStr;
+
+#[derive(ScribeStaticStr)]
+enum Airport {
+    #[enumscribe(str = concat!("L", b"HR"))]
+    Heathrow,
+}
+
+fn main() {}
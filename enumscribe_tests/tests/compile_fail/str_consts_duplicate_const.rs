@@ -0,0 +1,11 @@
+use enumscribe::EnumStrConsts;
+
+#[derive(EnumStrConsts)]
+#[allow(non_camel_case_types)]
+enum Airport {
+    LondonCity,
+    #[enumscribe(str = "LCY")]
+    London_City,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use enumscribe::TryUnscribe;
+
+#[derive(TryUnscribe)]
+enum Airport {
+    #[enumscribe(str = 5)]
+    Heathrow,
+}
+
+fn main() {}
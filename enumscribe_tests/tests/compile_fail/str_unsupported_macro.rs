@@ -0,0 +1,9 @@
+use enumscribe::ScribeStaticStr;
+
+#[derive(ScribeStaticStr)]
+enum Airport {
+    #[enumscribe(str = format!("LHR"))]
+    Heathrow,
+}
+
+fn main() {}
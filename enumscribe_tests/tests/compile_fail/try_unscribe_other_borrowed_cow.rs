@@ -0,0 +1,13 @@
+use std::borrow::Cow;
+
+use enumscribe::TryUnscribe;
+
+#[derive(TryUnscribe)]
+enum Airport<'a> {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+    #[enumscribe(other)]
+    Other { code: Cow<'a, str> },
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use enumscribe::TryUnscribe;
+
+#[derive(TryUnscribe)]
+#[enumscribe(case_insensitve, rename_al = "snake_case")]
+enum Airport {
+    Heathrow,
+}
+
+#[derive(TryUnscribe)]
+enum Website {
+    #[enumscribe(str = "github.com", alais = "www.github.com", depreciated)]
+    Github,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use enumscribe::UnscribeBytes;
+
+#[derive(UnscribeBytes)]
+enum Address {
+    #[enumscribe(str = "port:{}", fmt_fields)]
+    Port(u16),
+    #[enumscribe(other)]
+    Other(Vec<u8>),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use enumscribe::UnscribeBytes;
+
+#[derive(UnscribeBytes)]
+enum Airport {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+    #[enumscribe(other, raw_field = "raw", norm_field = "norm")]
+    Other { raw: Vec<u8>, norm: Vec<u8> },
+}
+
+fn main() {}
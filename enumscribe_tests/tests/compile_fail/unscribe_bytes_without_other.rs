@@ -0,0 +1,9 @@
+use enumscribe::UnscribeBytes;
+
+#[derive(UnscribeBytes)]
+enum Airport {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+}
+
+fn main() {}
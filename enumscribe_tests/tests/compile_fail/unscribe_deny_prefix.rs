@@ -0,0 +1,11 @@
+use enumscribe::Unscribe;
+
+#[derive(Unscribe)]
+enum Airport {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+    #[enumscribe(other, deny_prefix = "__")]
+    Other(String),
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use enumscribe::{ScribeCowStr, Unscribe};
+
+#[derive(ScribeCowStr, Unscribe)]
+enum Address {
+    #[enumscribe(str = "localhost")]
+    Localhost,
+    #[enumscribe(str = "port:{}", fmt_fields)]
+    Port(u16),
+    #[enumscribe(other)]
+    Other(String),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use enumscribe::Unscribe;
+
+#[derive(Unscribe)]
+enum Airport<'a> {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+    #[enumscribe(other)]
+    Other(&'a str),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use enumscribe::UnscribePrefix;
+
+#[derive(UnscribePrefix)]
+#[enumscribe(nfc)]
+enum Word {
+    #[enumscribe(str = "caf\u{e9}")]
+    Cafe,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use enumscribe::UnscribePrefix;
+
+#[derive(UnscribePrefix)]
+#[enumscribe(normalize_newlines)]
+enum Line {
+    #[enumscribe(str = "end\n")]
+    End,
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+use enumscribe::{ScribeCowStr, TryScribeCowStr, TryUnscribe};
+
+#[derive(TryScribeCowStr, TryUnscribe)]
+#[enumscribe(expect_names("LTN", "LHR", "Gatwick"))]
+enum Airport {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+    Gatwick,
+    #[enumscribe(str = "LTN", case_insensitive, accept_ident)]
+    Luton,
+    #[enumscribe(ignore)]
+    Secret(i32),
+    #[enumscribe(other)]
+    Other(String),
+}
+
+#[derive(ScribeCowStr)]
+#[enumscribe(rename_all = "snake_case", expect_names("local_host"))]
+enum Address {
+    LocalHost,
+    #[enumscribe(str = "port:{}", fmt_fields)]
+    Port(u16),
+}
+
+#[derive(ScribeCowStr)]
+#[enumscribe(expect_names())]
+enum Empty {
+    #[enumscribe(other)]
+    Other(String),
+}
+
+fn main() {}
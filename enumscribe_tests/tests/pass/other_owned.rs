@@ -0,0 +1,13 @@
+use enumscribe::{EnumDeserialize, FromStr, TryUnscribe, Unscribe};
+
+#[derive(Unscribe, TryUnscribe, FromStr, EnumDeserialize)]
+enum Airport {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+    #[enumscribe(other)]
+    Other(Box<str>),
+}
+
+fn main() {
+    assert!(matches!(Airport::unscribe("STN"), Airport::Other(code) if &*code == "STN"));
+}
@@ -0,0 +1,24 @@
+use enumscribe::{ScribeStaticStr, TryScribeCowStr};
+
+#[derive(ScribeStaticStr)]
+#[enumscribe(require_str, rename_all = "snake_case")]
+enum Address {
+    LocalHost,
+    #[enumscribe(str = "remote")]
+    RemoteHost,
+}
+
+#[derive(TryScribeCowStr)]
+#[enumscribe(require_str)]
+enum Airport {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+    #[enumscribe(rename = "UPPERCASE")]
+    Gatwick,
+    #[enumscribe(ignore)]
+    Secret(i32),
+    #[enumscribe(other)]
+    Other(String),
+}
+
+fn main() {}
@@ -0,0 +1,66 @@
+use enumscribe::{EnumAllScribed, ScribeStaticStr, TryScribeStaticStr};
+
+#[test]
+fn test_enum_all_scribed() {
+    #[derive(EnumAllScribed, ScribeStaticStr, Clone, Copy, PartialEq, Eq, Debug)]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "foo")]
+        V1,
+        V2(),
+        V3 {},
+    }
+
+    assert_eq!(E0::all_scribed(), &[
+        (E0::V0, "V0"),
+        (E0::V1, "foo"),
+        (E0::V2(), "V2"),
+        (E0::V3 {}, "V3"),
+    ]);
+
+    for (variant, name) in E0::all_scribed() {
+        assert_eq!(variant.scribe(), *name);
+    }
+
+    #[derive(EnumAllScribed, TryScribeStaticStr, Clone, Copy, PartialEq, Eq, Debug)]
+    #[enumscribe(rename_all = "snake_case")]
+    #[allow(dead_code)]
+    enum E1 {
+        #[enumscribe(ignore)]
+        V0,
+        FooBar,
+        #[enumscribe(ignore)]
+        V2(i32),
+        BazQux,
+    }
+
+    assert_eq!(E1::all_scribed(), &[(E1::FooBar, "foo_bar"), (E1::BazQux, "baz_qux")]);
+
+    for (variant, name) in E1::all_scribed() {
+        assert_eq!(variant.try_scribe(), Some(*name));
+    }
+
+    #[derive(EnumAllScribed, Clone, Copy, PartialEq, Eq, Debug)]
+    #[allow(dead_code)]
+    enum E2 {
+        #[enumscribe(ignore)]
+        V0,
+    }
+
+    assert!(E2::all_scribed().is_empty());
+}
+
+#[test]
+fn test_enum_all_scribed_generic() {
+    #[derive(EnumAllScribed, Clone, Copy, PartialEq, Eq, Debug)]
+    #[allow(dead_code)]
+    enum E0<T: Copy + 'static> {
+        V0,
+        #[enumscribe(str = "foo")]
+        V1,
+        #[enumscribe(ignore)]
+        V2(T),
+    }
+
+    assert_eq!(E0::<u32>::all_scribed(), &[(E0::V0, "V0"), (E0::V1, "foo")]);
+}
@@ -0,0 +1,121 @@
+use std::cell::RefCell;
+
+use enumscribe::{ScribeBytes, UnscribeBytes};
+
+#[derive(ScribeBytes, UnscribeBytes, PartialEq, Eq, Debug)]
+enum Airport {
+    #[enumscribe(str = "LHR", alias = "EGLL")]
+    Heathrow,
+    #[enumscribe(str = "LGW", case_insensitive = "ascii")]
+    Gatwick,
+    #[enumscribe(str = "Straße", case_insensitive)]
+    Strasse,
+    #[enumscribe(other)]
+    Other(Vec<u8>),
+}
+
+#[test]
+fn test_unscribe_bytes() {
+    assert_eq!(Airport::unscribe_bytes(b"LHR"), Airport::Heathrow);
+    assert_eq!(Airport::unscribe_bytes(b"EGLL"), Airport::Heathrow);
+    assert_eq!(Airport::unscribe_bytes(b"lhr"), Airport::Other(b"lhr".to_vec()));
+
+    assert_eq!(Airport::unscribe_bytes(b"LGW"), Airport::Gatwick);
+    assert_eq!(Airport::unscribe_bytes(b"lGw"), Airport::Gatwick);
+
+    assert_eq!(Airport::unscribe_bytes("STRASSE".as_bytes()), Airport::Strasse);
+    assert_eq!(Airport::unscribe_bytes("straße".as_bytes()), Airport::Strasse);
+    assert_eq!(Airport::unscribe_bytes(b"stras"), Airport::Other(b"stras".to_vec()));
+
+    assert_eq!(Airport::unscribe_bytes(b""), Airport::Other(Vec::new()));
+    assert_eq!(Airport::unscribe_bytes(b"STN"), Airport::Other(b"STN".to_vec()));
+}
+
+#[test]
+fn test_unscribe_bytes_non_utf8() {
+    assert_eq!(Airport::unscribe_bytes(b"\xff"), Airport::Other(vec![0xff]));
+    assert_eq!(Airport::unscribe_bytes(b"LHR\xff"), Airport::Other(b"LHR\xff".to_vec()));
+    assert_eq!(Airport::unscribe_bytes(b"LG\xc3"), Airport::Other(b"LG\xc3".to_vec()));
+    assert_eq!(
+        Airport::unscribe_bytes(b"STRA\xc3"),
+        Airport::Other(b"STRA\xc3".to_vec())
+    );
+}
+
+#[test]
+fn test_scribe_bytes() {
+    assert_eq!(Airport::Heathrow.scribe_bytes(), b"LHR");
+    assert_eq!(Airport::Gatwick.scribe_bytes(), b"LGW");
+    assert_eq!(Airport::Strasse.scribe_bytes(), "Straße".as_bytes());
+    assert_eq!(Airport::Other(b"STN".to_vec()).scribe_bytes(), b"STN");
+    assert_eq!(Airport::Other(vec![0xff, 0xfe]).scribe_bytes(), b"\xff\xfe");
+
+    for bytes in [&b"LHR"[..], b"lgw", b"\xff\xfe", b"STRA\xc3"].iter() {
+        let airport = Airport::unscribe_bytes(bytes);
+        assert_eq!(Airport::unscribe_bytes(airport.scribe_bytes()), airport);
+    }
+}
+
+#[test]
+fn test_unscribe_bytes_fields() {
+    #[derive(ScribeBytes, UnscribeBytes, PartialEq, Eq, Debug)]
+    #[enumscribe(empty_is_other)]
+    enum Frame {
+        #[enumscribe(str = "")]
+        Empty,
+        #[enumscribe(str = "PING")]
+        Ping,
+        #[enumscribe(ignore, placeholder = "PONG")]
+        Pong,
+        #[enumscribe(other)]
+        Unknown { payload: Box<[u8]> },
+    }
+
+    assert_eq!(Frame::unscribe_bytes(b"PING"), Frame::Ping);
+    assert_eq!(Frame::unscribe_bytes(b"PONG"), Frame::Unknown { payload: b"PONG"[..].into() });
+    // As with `Unscribe`, `empty_is_other` only affects `EnumDeserialize`
+    assert_eq!(Frame::unscribe_bytes(b""), Frame::Empty);
+    assert_eq!(Frame::Empty.scribe_bytes(), b"");
+    assert_eq!(Frame::Pong.scribe_bytes(), b"PONG");
+    assert_eq!(Frame::Unknown { payload: Box::new([0x80]) }.scribe_bytes(), b"\x80");
+}
+
+thread_local! {
+    static DEPRECATED_NAMES: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+fn record_deprecated(name: &str) {
+    DEPRECATED_NAMES.with(|names| names.borrow_mut().push(name.to_owned()));
+}
+
+fn take_deprecated() -> Vec<String> {
+    DEPRECATED_NAMES.with(|names| names.borrow_mut().drain(..).collect())
+}
+
+#[test]
+fn test_unscribe_bytes_on_deprecated() {
+    #[derive(UnscribeBytes, PartialEq, Eq, Debug)]
+    #[enumscribe(on_deprecated = "record_deprecated")]
+    enum E0 {
+        #[enumscribe(str = "old", alias = "older", case_insensitive = "ascii", deprecated)]
+        V0,
+        #[enumscribe(str = "straße", case_insensitive, deprecated)]
+        V1,
+        #[enumscribe(str = "egll", deprecated)]
+        V2,
+        #[enumscribe(str = "new", case_insensitive)]
+        V3,
+        #[enumscribe(other)]
+        V4(Vec<u8>),
+    }
+
+    assert_eq!(E0::unscribe_bytes(b"NEW"), E0::V3);
+    assert_eq!(E0::unscribe_bytes(b"oLd\xff"), E0::V4(b"oLd\xff".to_vec()));
+    assert!(take_deprecated().is_empty());
+
+    assert_eq!(E0::unscribe_bytes(b"oLd"), E0::V0);
+    assert_eq!(E0::unscribe_bytes(b"OLDER"), E0::V0);
+    assert_eq!(E0::unscribe_bytes("STRASSE".as_bytes()), E0::V1);
+    assert_eq!(E0::unscribe_bytes(b"egll"), E0::V2);
+    assert_eq!(take_deprecated(), ["oLd", "OLDER", "STRASSE", "egll"]);
+}
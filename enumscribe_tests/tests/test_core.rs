@@ -0,0 +1,249 @@
+use enumscribe_core::{parse_enum, VariantConstructor, VariantType};
+use syn::{parse_quote, Attribute, DataEnum};
+
+fn data_enum(variants: syn::punctuated::Punctuated<syn::Variant, syn::Token![,]>) -> DataEnum {
+    DataEnum {
+        enum_token: Default::default(),
+        brace_token: Default::default(),
+        variants,
+    }
+}
+
+#[test]
+fn test_parse_enum() {
+    let data = data_enum(parse_quote! {
+        #[enumscribe(str = "LHR", alias = "EGLL")]
+        Heathrow,
+        #[enumscribe(case_insensitive)]
+        Gatwick(),
+        #[enumscribe(ignore)]
+        Unknown,
+        #[enumscribe(other)]
+        Other(String)
+    });
+    let attrs: Vec<Attribute> = vec![parse_quote! { #[enumscribe(rename_all = "snake_case")] }];
+
+    let parsed_enum = parse_enum(&data, &attrs).unwrap();
+    let variants = parsed_enum.variants();
+    assert_eq!(variants.len(), 4);
+    assert!(parsed_enum.has_other());
+    assert!(parsed_enum.has_case_insensitive());
+
+    let heathrow = variants[0].v_type.as_named().unwrap();
+    assert_eq!(heathrow.name(), "LHR");
+    assert_eq!(heathrow.names().collect::<Vec<_>>(), vec!["LHR", "EGLL"]);
+    assert!(!heathrow.case_insensitive());
+    assert!(matches!(heathrow.constructor(), VariantConstructor::None));
+
+    let gatwick = variants[1].v_type.as_named().unwrap();
+    assert_eq!(gatwick.name(), "gatwick");
+    assert_eq!(gatwick.match_names_upper().collect::<Vec<_>>(), vec!["GATWICK"]);
+    assert!(gatwick.case_insensitive());
+    assert!(matches!(gatwick.constructor(), VariantConstructor::Paren(0)));
+
+    assert!(matches!(variants[2].v_type, VariantType::Ignore(_)));
+    assert_eq!(variants[2].data.ident, "Unknown");
+
+    match &variants[3].v_type {
+        VariantType::Other(other) => assert!(other.field_name().is_none()),
+        _ => panic!("expected an other variant"),
+    }
+}
+
+#[test]
+fn test_parse_enum_error() {
+    let data = data_enum(parse_quote! {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(str = "foo")]
+        V1
+    });
+
+    let err = parse_enum(&data, &[]).err().unwrap();
+    assert_eq!(err.to_string(), "duplicate name \"foo\"");
+
+    let data = data_enum(parse_quote! { V0 });
+    let attrs: Vec<Attribute> = vec![parse_quote! { #[enumscribe(no_such_key)] }];
+
+    assert!(parse_enum(&data, &attrs).is_err());
+
+    // Every unexpected key is reported, in the order they were written
+    let attrs: Vec<Attribute> = vec![parse_quote! { #[enumscribe(key_b, key_a = "a")] }];
+    let err = parse_enum(&data, &attrs).err().unwrap();
+    assert_eq!(err.to_string(), "unexpected key: key_b\nunexpected key: key_a");
+    assert_eq!(err.iter().count(), 2);
+}
+
+#[test]
+fn test_parse_enum_ascii_case_insensitive() {
+    let data = data_enum(parse_quote! {
+        #[enumscribe(str = "gro\u{df}")]
+        V0,
+        #[enumscribe(str = "GROSS")]
+        V1,
+        #[enumscribe(str = "lhr", case_sensitive)]
+        V2
+    });
+    let attrs: Vec<Attribute> = vec![parse_quote! { #[enumscribe(case_insensitive = "ascii")] }];
+
+    // No uppercase buffer is needed, and a matching string is never longer than the name
+    let parsed_enum = parse_enum(&data, &attrs).unwrap();
+    assert!(parsed_enum.has_case_insensitive());
+    assert!(!parsed_enum.has_uppercase_case_insensitive());
+    assert_eq!(parsed_enum.name_upper_capacity(), 0);
+    assert_eq!(parsed_enum.match_capacity(), 5);
+    let v0 = parsed_enum.variants()[0].v_type.as_named().unwrap();
+    assert!(v0.case_insensitive() && v0.ascii_case_insensitive());
+    let v2 = parsed_enum.variants()[2].v_type.as_named().unwrap();
+    assert!(!v2.case_insensitive() && !v2.ascii_case_insensitive());
+
+    // "groß" and "GROSS" are only equal when all case is ignored
+    let attrs: Vec<Attribute> = vec![parse_quote! { #[enumscribe(case_insensitive)] }];
+    assert!(parse_enum(&data, &attrs).is_err());
+    let attrs: Vec<Attribute> = vec![parse_quote! { #[enumscribe(case_insensitive = false)] }];
+    assert!(!parse_enum(&data, &attrs).unwrap().has_case_insensitive());
+
+    let attrs: Vec<Attribute> = vec![parse_quote! { #[enumscribe(case_insensitive = "unicode")] }];
+    let err = parse_enum(&data, &attrs).err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "invalid case_insensitive value \"unicode\" (allowed values are: true, false, ascii)"
+    );
+}
+
+#[test]
+fn test_parse_enum_ascii_case_insensitive_variants() {
+    let data = data_enum(parse_quote! {
+        #[enumscribe(str = "gro\u{df}", case_insensitive = "ascii")]
+        V0,
+        #[enumscribe(str = "lhr", case_insensitive)]
+        V1,
+        #[enumscribe(str = "lgw", case_insensitive = "ascii")]
+        V2
+    });
+
+    // Only the names which are converted to uppercase need to fit in the buffer
+    let parsed_enum = parse_enum(&data, &[]).unwrap();
+    assert!(parsed_enum.has_uppercase_case_insensitive());
+    assert_eq!(parsed_enum.name_upper_capacity(), 3);
+    assert_eq!(parsed_enum.match_capacity(), 12);
+    assert!(parsed_enum.variants()[0].v_type.as_named().unwrap().ascii_case_insensitive());
+    assert!(parsed_enum.variants()[1].v_type.as_named().unwrap().uppercase_case_insensitive());
+
+    // A string could match both an ASCII and a Unicode case-insensitive name which are equal when
+    // converted to uppercase
+    let data = data_enum(parse_quote! {
+        #[enumscribe(str = "gro\u{df}", case_insensitive = "ascii")]
+        V0,
+        #[enumscribe(str = "GROSS", case_insensitive)]
+        V1
+    });
+    assert!(parse_enum(&data, &[]).is_err());
+
+    let data = data_enum(parse_quote! {
+        #[enumscribe(str = "gro\u{df}", case_insensitive = "ascii")]
+        V0,
+        #[enumscribe(str = "GROSS", case_insensitive = "ascii")]
+        V1,
+        #[enumscribe(str = "LHR", case_insensitive = "ascii")]
+        V2,
+        #[enumscribe(str = "lhr", case_insensitive = "ascii")]
+        V3
+    });
+    let err = parse_enum(&data, &[]).err().unwrap();
+    assert!(err.to_string().starts_with("duplicate name \"lhr\""));
+}
+
+#[test]
+fn test_parse_enum_lint_case_insensitive() {
+    let data = data_enum(parse_quote! {
+        #[enumscribe(str = "404", case_insensitive)]
+        NotFound,
+        #[enumscribe(str = "+", case_insensitive = "ascii")]
+        Plus
+    });
+    let lint: Vec<Attribute> = vec![parse_quote! { #[enumscribe(lint)] }];
+
+    // Without lint, a pointless case_insensitive is allowed
+    assert!(parse_enum(&data, &[]).is_ok());
+
+    let err = parse_enum(&data, &lint).err().unwrap();
+    assert!(err
+        .to_string()
+        .starts_with("the variant NotFound is marked as case_insensitive, but none of its names"));
+
+    // Names with cased characters, including aliases, and enum-level case_insensitive are allowed
+    let data = data_enum(parse_quote! {
+        #[enumscribe(str = "404", alias = "not_found", case_insensitive)]
+        NotFound,
+        #[enumscribe(str = "\u{e9}", case_insensitive)]
+        Accent,
+        #[enumscribe(str = "500")]
+        ServerError
+    });
+    let lint: Vec<Attribute> = vec![parse_quote! { #[enumscribe(lint, case_insensitive)] }];
+    assert!(parse_enum(&data, &lint).is_ok());
+
+    // In ascii mode, only ASCII letters count as cased characters
+    let data = data_enum(parse_quote! {
+        #[enumscribe(str = "ok", case_insensitive = "ascii")]
+        Ok,
+        #[enumscribe(str = "\u{e9}", case_insensitive = "ascii")]
+        Accent
+    });
+    let lint: Vec<Attribute> = vec![parse_quote! { #[enumscribe(lint)] }];
+    let err = parse_enum(&data, &lint).err().unwrap();
+    assert!(err
+        .to_string()
+        .starts_with("the variant Accent is marked as case_insensitive, but none of its names"));
+    assert!(err.to_string().contains("have ASCII letters"));
+
+    let lint: Vec<Attribute> = vec![parse_quote! { #[enumscribe(lint, case_insensitive = "ascii")] }];
+    let data = data_enum(parse_quote! {
+        #[enumscribe(str = "\u{e9}", case_insensitive)]
+        Accent
+    });
+    assert!(parse_enum(&data, &lint).is_err());
+}
+
+#[test]
+fn test_parse_enum_lint_norm_field() {
+    let data = data_enum(parse_quote! {
+        #[enumscribe(str = "404")]
+        NotFound,
+        #[enumscribe(str = "lhr")]
+        Heathrow,
+        #[enumscribe(other, raw_field = "raw", norm_field = "norm")]
+        Other { raw: String, norm: String }
+    });
+    let lint: Vec<Attribute> = vec![parse_quote! { #[enumscribe(lint)] }];
+
+    // Without lint, case-sensitive names are allowed alongside norm_field
+    assert!(parse_enum(&data, &[]).is_ok());
+
+    let err = parse_enum(&data, &lint).err().unwrap();
+    assert!(err
+        .to_string()
+        .starts_with("the variant Heathrow has the case-sensitive name \"lhr\", but Other stores"));
+    assert!(err.to_string().contains("strings such as \"LHR\" do not match \"lhr\" and are stored in Other"));
+
+    // Case-insensitive names, and names without cased characters, are allowed
+    let data = data_enum(parse_quote! {
+        #[enumscribe(str = "404")]
+        NotFound,
+        #[enumscribe(str = "LHR", case_insensitive)]
+        Heathrow,
+        #[enumscribe(other, raw_field = "raw", norm_field = "norm")]
+        Other { raw: String, norm: String }
+    });
+    assert!(parse_enum(&data, &lint).is_ok());
+
+    // Without norm_field, the other variant stores strings as they are
+    let data = data_enum(parse_quote! {
+        #[enumscribe(str = "LHR")]
+        Heathrow,
+        #[enumscribe(other)]
+        Other(String)
+    });
+    assert!(parse_enum(&data, &lint).is_ok());
+}
@@ -0,0 +1,59 @@
+use enumscribe::EnumCount;
+
+#[test]
+fn test_enum_count() {
+    #[derive(EnumCount)]
+    #[allow(dead_code)]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "foo")]
+        V1,
+        V2(),
+        #[enumscribe(ignore)]
+        V3(i32),
+        V4 {},
+        #[enumscribe(ignore)]
+        V5,
+        #[enumscribe(other)]
+        V6(String),
+    }
+
+    assert_eq!(E0::NUM_VARIANTS, 7);
+    assert_eq!(E0::NUM_NAMED_VARIANTS, 4);
+
+    #[derive(EnumCount)]
+    #[allow(dead_code)]
+    enum E1 {
+        V0,
+    }
+
+    assert_eq!(E1::NUM_VARIANTS, 1);
+    assert_eq!(E1::NUM_NAMED_VARIANTS, 1);
+
+    #[derive(EnumCount)]
+    #[allow(dead_code)]
+    enum E2 {
+        #[enumscribe(other)]
+        V0(String),
+    }
+
+    assert_eq!(E2::NUM_VARIANTS, 1);
+    assert_eq!(E2::NUM_NAMED_VARIANTS, 0);
+
+    let buf = [0u8; E0::NUM_NAMED_VARIANTS];
+    assert_eq!(buf.len(), 4);
+}
+
+#[test]
+fn test_enum_count_generic() {
+    #[derive(EnumCount)]
+    #[allow(dead_code)]
+    enum E0<T, const N: usize> {
+        V0,
+        #[enumscribe(ignore)]
+        V1([T; N]),
+    }
+
+    assert_eq!(E0::<u8, 2>::NUM_VARIANTS, 2);
+    assert_eq!(E0::<u8, 2>::NUM_NAMED_VARIANTS, 1);
+}
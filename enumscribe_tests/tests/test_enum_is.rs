@@ -0,0 +1,70 @@
+use enumscribe::EnumIs;
+
+#[test]
+fn test_enum_is() {
+    #[derive(EnumIs)]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "foo")]
+        LondonCity,
+        V2(i32),
+        V3 { x: i32 },
+        #[enumscribe(ignore)]
+        Ignored,
+        #[enumscribe(other)]
+        Other(String),
+        r#Type,
+    }
+
+    let values = [
+        E0::V0,
+        E0::LondonCity,
+        E0::V2(1),
+        E0::V3 { x: 2 },
+        E0::Ignored,
+        E0::Other("baa".to_owned()),
+        E0::Type,
+    ];
+
+    let predicates: [fn(&E0) -> bool; 7] = [
+        E0::is_v0,
+        E0::is_london_city,
+        E0::is_v2,
+        E0::is_v3,
+        E0::is_ignored,
+        E0::is_other,
+        E0::is_type,
+    ];
+
+    for (i, value) in values.iter().enumerate() {
+        for (j, predicate) in predicates.iter().enumerate() {
+            assert_eq!(predicate(value), i == j);
+        }
+    }
+
+    if let E0::V2(x) | E0::V3 { x } = values[2] {
+        assert_eq!(x, 1);
+    }
+    if let E0::Other(s) = &values[5] {
+        assert_eq!(s, "baa");
+    }
+}
+
+#[test]
+fn test_enum_is_const_generic() {
+    #[derive(EnumIs)]
+    enum E0<T> {
+        #[enumscribe(str = "some")]
+        Present(T),
+        Missing,
+    }
+
+    const MISSING: [bool; 2] = [E0::<u8>::Missing.is_present(), E0::<u8>::Missing.is_missing()];
+    assert_eq!(MISSING, [false, true]);
+
+    assert!(E0::Present(5u8).is_present());
+    assert!(!E0::Present(5u8).is_missing());
+    if let E0::Present(x) = E0::Present(5u8) {
+        assert_eq!(x, 5);
+    }
+}
@@ -0,0 +1,56 @@
+use enumscribe::{EnumCount, EnumExtend, EnumVariantNames, ScribeStaticStr};
+
+#[derive(ScribeStaticStr, EnumCount, EnumVariantNames, Clone, Copy, PartialEq, Eq, Debug)]
+enum LondonAirport {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+    #[enumscribe(str = "LGW")]
+    Gatwick,
+}
+
+#[derive(EnumExtend, PartialEq, Eq, Debug)]
+#[enumscribe(extend = LondonAirport)]
+#[allow(dead_code)]
+enum Airport {
+    #[enumscribe(str = "LGW")]
+    LondonGatwick,
+    #[enumscribe(str = "MAN")]
+    Manchester,
+    #[enumscribe(str = "LHR")]
+    LondonHeathrow,
+    #[enumscribe(ignore)]
+    Secret,
+}
+
+#[test]
+fn test_extend() {
+    assert_eq!(Airport::from(LondonAirport::Heathrow), Airport::LondonHeathrow);
+    assert_eq!(Airport::from(LondonAirport::Gatwick), Airport::LondonGatwick);
+
+    let airport: Airport = LondonAirport::Gatwick.into();
+    assert_eq!(airport, Airport::LondonGatwick);
+}
+
+#[test]
+fn test_extend_fields() {
+    #[derive(ScribeStaticStr, EnumCount, EnumVariantNames)]
+    #[enumscribe(rename_all = "snake_case")]
+    enum Command {
+        Start,
+        Stop,
+    }
+
+    #[derive(EnumExtend, PartialEq, Eq, Debug)]
+    #[enumscribe(extend = "Command", rename_all = "snake_case")]
+    #[allow(dead_code)]
+    enum ExtendedCommand {
+        Start(),
+        Stop {},
+        Restart,
+        #[enumscribe(other)]
+        Unknown(String),
+    }
+
+    assert_eq!(ExtendedCommand::from(Command::Start), ExtendedCommand::Start());
+    assert_eq!(ExtendedCommand::from(Command::Stop), ExtendedCommand::Stop {});
+}
@@ -0,0 +1,59 @@
+use enumscribe::{EnumFlags, TryUnscribe, UnscribeError};
+
+#[test]
+fn test_enum_flags() {
+    #[derive(EnumFlags, TryUnscribe, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "read")]
+        V0,
+        #[enumscribe(str = "write", case_insensitive)]
+        V1,
+        #[enumscribe(str = "execute")]
+        V2,
+    }
+
+    assert_eq!(E0::parse_set(&[]), Ok(vec![]));
+    assert_eq!(E0::parse_set(&["read"]), Ok(vec![E0::V0]));
+    assert_eq!(
+        E0::parse_set(&["execute", "WRITE", "read"]),
+        Ok(vec![E0::V2, E0::V1, E0::V0])
+    );
+    assert_eq!(E0::parse_set(&["read", "read"]), Ok(vec![E0::V0, E0::V0]));
+    assert_eq!(E0::parse_set(&["READ"]), Err(UnscribeError));
+    assert_eq!(E0::parse_set(&["read", "delete", "write"]), Err(UnscribeError));
+    assert_eq!(E0::parse_set(&["delete", "read", "write"]), Err(UnscribeError));
+    assert_eq!(E0::parse_set(&["read", "write", ""]), Err(UnscribeError));
+
+    let items = ["write".to_owned(), "execute".to_owned()];
+    let items = items.iter().map(String::as_str).collect::<Vec<_>>();
+    assert_eq!(E0::parse_set(&items), Ok(vec![E0::V1, E0::V2]));
+
+    #[derive(EnumFlags, TryUnscribe, Eq, PartialEq, Debug)]
+    enum E1 {
+        #[enumscribe(str = "read")]
+        V0,
+        #[enumscribe(other)]
+        V1(String),
+    }
+
+    assert_eq!(
+        E1::parse_set(&["read", "delete"]),
+        Ok(vec![E1::V0, E1::V1("delete".to_owned())])
+    );
+}
+
+#[test]
+fn test_enum_flags_generic() {
+    #[derive(EnumFlags, TryUnscribe, Eq, PartialEq, Debug)]
+    enum E0<T: for<'a> From<&'a str>> {
+        #[enumscribe(str = "read")]
+        V0,
+        #[enumscribe(other)]
+        V1(T),
+    }
+
+    assert_eq!(
+        E0::<Box<str>>::parse_set(&["read", "delete"]),
+        Ok(vec![E0::V0, E0::V1("delete".into())])
+    );
+}
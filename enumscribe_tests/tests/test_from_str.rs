@@ -0,0 +1,59 @@
+use enumscribe::UnscribeError;
+
+#[test]
+fn test_from_str() {
+    #[derive(enumscribe::FromStr, Eq, PartialEq, Debug)]
+    #[enumscribe(case_insensitive)]
+    enum E0 {
+        #[enumscribe(str = "LHR")]
+        V0,
+        #[enumscribe(str = "lgw")]
+        V1,
+        #[enumscribe(str = "LTN", case_sensitive)]
+        V2,
+        V3,
+    }
+
+    assert_eq!("LHR".parse::<E0>(), Ok(E0::V0));
+    assert_eq!("lhr".parse::<E0>(), Ok(E0::V0));
+    assert_eq!("LhR".parse::<E0>(), Ok(E0::V0));
+    assert_eq!("LGW".parse::<E0>(), Ok(E0::V1));
+    assert_eq!("lgw".parse::<E0>(), Ok(E0::V1));
+    assert_eq!("LTN".parse::<E0>(), Ok(E0::V2));
+    assert_eq!("ltn".parse::<E0>(), Err(UnscribeError));
+    assert_eq!("v3".parse::<E0>(), Ok(E0::V3));
+    assert_eq!("V3".parse::<E0>(), Ok(E0::V3));
+    assert_eq!("STN".parse::<E0>(), Err(UnscribeError));
+    assert_eq!("".parse::<E0>(), Err(UnscribeError));
+
+    #[derive(enumscribe::FromStr, Eq, PartialEq, Debug)]
+    enum E1 {
+        #[enumscribe(str = "LHR")]
+        V0,
+        #[enumscribe(str = "lgw", case_insensitive)]
+        V1,
+        #[enumscribe(other, deny_prefix = "__")]
+        V2(String),
+    }
+
+    assert_eq!("LHR".parse::<E1>(), Ok(E1::V0));
+    assert_eq!("lhr".parse::<E1>(), Ok(E1::V2("lhr".to_owned())));
+    assert_eq!("LGW".parse::<E1>(), Ok(E1::V1));
+    assert_eq!("__lgw".parse::<E1>(), Err(UnscribeError));
+
+    assert_eq!(UnscribeError.to_string(), "string does not match any variant");
+}
+
+#[test]
+fn test_from_str_generic() {
+    #[derive(enumscribe::FromStr, Eq, PartialEq, Debug)]
+    enum E0<T: for<'a> From<&'a str>> {
+        #[enumscribe(str = "LHR", case_insensitive)]
+        V0,
+        #[enumscribe(other)]
+        V1(T),
+    }
+
+    assert_eq!("lhr".parse::<E0<String>>(), Ok(E0::V0));
+    assert_eq!("LGW".parse::<E0<String>>(), Ok(E0::V1("LGW".to_owned())));
+}
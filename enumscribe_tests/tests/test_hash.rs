@@ -0,0 +1,81 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+
+use enumscribe::{EnumHashByName, EnumStrEq};
+
+fn hash_of<T: Hash + ?Sized>(x: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    x.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn test_hash_by_name() {
+    #[derive(EnumHashByName, EnumStrEq, Debug)]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "foo")]
+        V1,
+        V2(),
+        #[enumscribe(str = "baa", case_insensitive)]
+        V3 {},
+        #[enumscribe(other)]
+        V4(String),
+    }
+
+    assert_eq!(hash_of(&E0::V0), hash_of("V0"));
+    assert_eq!(hash_of(&E0::V1), hash_of("foo"));
+    assert_eq!(hash_of(&E0::V2()), hash_of("V2"));
+    assert_eq!(hash_of(&E0::V3 {}), hash_of("baa"));
+    assert_eq!(hash_of(&E0::V4("lorem".to_owned())), hash_of("lorem"));
+
+    assert_eq!(E0::V0, E0::V0);
+    assert_ne!(E0::V0, E0::V1);
+    assert_eq!(E0::V1, E0::V4("foo".to_owned()));
+    assert_eq!(hash_of(&E0::V1), hash_of(&E0::V4("foo".to_owned())));
+    assert_ne!(E0::V3 {}, E0::V4("BAA".to_owned()));
+    assert_eq!(E0::V4("x".to_owned()), E0::V4("x".to_owned()));
+    assert_ne!(E0::V4("x".to_owned()), E0::V4("y".to_owned()));
+
+    let mut set = HashSet::new();
+    assert!(set.insert(E0::V0));
+    assert!(set.insert(E0::V1));
+    assert!(!set.insert(E0::V4("foo".to_owned())));
+    assert!(set.insert(E0::V4("ipsum".to_owned())));
+    assert!(!set.insert(E0::V4("ipsum".to_owned())));
+    assert!(!set.insert(E0::V4("V0".to_owned())));
+    assert_eq!(set.len(), 3);
+    assert!(set.contains(&E0::V4("V0".to_owned())));
+    assert!(set.contains(&E0::V1));
+    assert!(!set.contains(&E0::V2()));
+
+    #[derive(EnumHashByName, EnumStrEq, Debug)]
+    enum E1 {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(other)]
+        V1 { s: String },
+    }
+
+    assert_eq!(E1::V0, E1::V1 { s: "foo".to_owned() });
+    assert_eq!(hash_of(&E1::V1 { s: "baa".to_owned() }), hash_of("baa"));
+}
+
+#[test]
+fn test_hash_by_name_generic() {
+    #[derive(EnumHashByName, EnumStrEq, Debug)]
+    enum E0<T: AsRef<str>> {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(other)]
+        V1(T),
+    }
+
+    assert_eq!(E0::V0, E0::V1("foo"));
+    assert_ne!(E0::V0, E0::V1("baa"));
+    assert_eq!(hash_of(&E0::V1("baa".to_owned())), hash_of("baa"));
+
+    let set = vec![E0::V0, E0::V1("foo"), E0::V1("baa")].into_iter().collect::<HashSet<_>>();
+    assert_eq!(set.len(), 2);
+}
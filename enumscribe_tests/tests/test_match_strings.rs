@@ -0,0 +1,63 @@
+use enumscribe::{EnumMatchStrings, TryUnscribe};
+
+#[test]
+fn test_enum_match_strings() {
+    #[derive(EnumMatchStrings, TryUnscribe, Eq, PartialEq, Debug)]
+    #[allow(dead_code)]
+    enum E0 {
+        #[enumscribe(str = "LHR", alias = "EGLL", alias = "heathrow")]
+        Heathrow,
+        #[enumscribe(str = "LGW", case_insensitive)]
+        Gatwick,
+        V2(),
+        #[enumscribe(accept_ident, alias = "luton")]
+        #[enumscribe(str = "LTN")]
+        Luton,
+        #[enumscribe(ignore)]
+        V4(i32),
+        V5 {},
+    }
+
+    assert_eq!(E0::Heathrow.match_strings(), ["LHR", "EGLL", "heathrow"]);
+    assert_eq!(E0::Gatwick.match_strings(), ["LGW"]);
+    assert_eq!(E0::V2().match_strings(), ["V2"]);
+    assert_eq!(E0::Luton.match_strings(), ["LTN", "luton", "Luton"]);
+    assert!(E0::V4(1).match_strings().is_empty());
+    assert_eq!(E0::V5 {}.match_strings(), ["V5"]);
+
+    for e in [E0::Heathrow, E0::Gatwick, E0::V2(), E0::Luton, E0::V5 {}].iter() {
+        for s in e.match_strings() {
+            assert_eq!(E0::try_unscribe(s).as_ref(), Some(e));
+        }
+    }
+
+    #[derive(EnumMatchStrings)]
+    #[allow(dead_code)]
+    enum E1 {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(str = "port:{}", fmt_fields)]
+        V1(u16),
+        #[enumscribe(other)]
+        V2(String),
+    }
+
+    assert_eq!(E1::V0.match_strings(), ["foo"]);
+    assert!(E1::V1(8080).match_strings().is_empty());
+    assert!(E1::V2("foo".to_owned()).match_strings().is_empty());
+}
+
+#[test]
+fn test_enum_match_strings_generic() {
+    #[derive(EnumMatchStrings)]
+    #[allow(dead_code)]
+    enum E0<T> {
+        #[enumscribe(str = "LHR", alias = "EGLL")]
+        V0,
+        #[enumscribe(ignore)]
+        V1(T),
+    }
+
+    assert_eq!(E0::<u8>::V0.match_strings(), ["LHR", "EGLL"]);
+    assert!(E0::V1(0u8).match_strings().is_empty());
+}
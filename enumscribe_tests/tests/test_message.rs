@@ -0,0 +1,55 @@
+use enumscribe::EnumMessage;
+
+#[test]
+fn test_enum_message() {
+    #[derive(EnumMessage)]
+    #[allow(dead_code)]
+    enum E0 {
+        #[enumscribe(message = "the first variant")]
+        V0,
+        #[enumscribe(str = "foo", message = "蟹")]
+        V1,
+        V2(),
+        #[enumscribe(ignore, message = "an ignored variant")]
+        V3(i32),
+        V4 {},
+        #[enumscribe(message = "")]
+        V5 {},
+        #[enumscribe(other, message = "anything else")]
+        V6(String),
+    }
+
+    assert_eq!(E0::V0.message(), Some("the first variant"));
+    assert_eq!(E0::V1.message(), Some("蟹"));
+    assert_eq!(E0::V2().message(), None);
+    assert_eq!(E0::V3(5).message(), Some("an ignored variant"));
+    assert_eq!(E0::V4 {}.message(), None);
+    assert_eq!(E0::V5 {}.message(), Some(""));
+    assert_eq!(E0::V6("lorem".to_owned()).message(), Some("anything else"));
+
+    #[derive(EnumMessage)]
+    #[allow(dead_code)]
+    enum E1 {
+        V0,
+        #[enumscribe(str = "foo")]
+        V1,
+    }
+
+    assert_eq!(E1::V0.message(), None);
+    assert_eq!(E1::V1.message(), None);
+}
+
+#[test]
+fn test_enum_message_generic() {
+    #[derive(EnumMessage)]
+    #[allow(dead_code)]
+    enum E0<T> {
+        #[enumscribe(message = "the first variant")]
+        V0,
+        #[enumscribe(ignore)]
+        V1(T),
+    }
+
+    assert_eq!(E0::<u8>::V0.message(), Some("the first variant"));
+    assert_eq!(E0::V1(0u8).message(), None);
+}
@@ -0,0 +1,131 @@
+use enumscribe::{EnumDeserialize, TryUnscribe, Unscribe};
+
+// Defines the same enums twice, once using `match` and once using a perfect hash map for
+// unscribing, so that the two code generation paths can be compared.
+macro_rules! define_enums {
+    ($e0:ident, $e1:ident, $e2:ident $(, $attr:meta)*) => {
+        #[derive(Unscribe, EnumDeserialize, Eq, PartialEq, Debug)]
+        #[enumscribe(accept_ident $(, $attr)*)]
+        enum $e0 {
+            #[enumscribe(str = "LHR")]
+            Heathrow,
+            #[enumscribe(str = "LGW", case_insensitive)]
+            Gatwick,
+            #[enumscribe(str = "ltn", case_insensitive)]
+            Luton,
+            #[enumscribe(str = "STN")]
+            Stansted,
+            #[enumscribe(str = "LCY", case_insensitive, accept_ident = false)]
+            LondonCity,
+            #[enumscribe(str = "groß", case_insensitive)]
+            Gross,
+            #[enumscribe(str = "蟹")]
+            Crab,
+            #[enumscribe(str = "")]
+            Empty,
+            #[enumscribe(ignore)]
+            #[allow(dead_code)]
+            Ignored(i32),
+            #[enumscribe(other, raw_field = "raw", norm_field = "norm")]
+            Other { raw: String, norm: String },
+        }
+
+        #[derive(TryUnscribe, EnumDeserialize, Eq, PartialEq, Debug)]
+        #[enumscribe(ci_overflow = "error" $(, $attr)*)]
+        enum $e1 {
+            V0,
+            #[enumscribe(str = "baa")]
+            V1,
+            #[enumscribe(str = "baz", case_insensitive)]
+            V2,
+            #[enumscribe(other, deny_prefix = "__")]
+            V3(String),
+        }
+
+        #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+        #[enumscribe(case_insensitive $(, $attr)*)]
+        enum $e2 {
+            V0,
+            #[enumscribe(str = "foo")]
+            V1,
+            #[enumscribe(str = "FOO2")]
+            V2,
+            #[enumscribe(str = "Foo", case_sensitive)]
+            V3,
+        }
+    };
+}
+
+define_enums!(MatchE0, MatchE1, MatchE2);
+define_enums!(PhfE0, PhfE1, PhfE2, phf);
+
+const INPUTS: &[&str] = &[
+    "", " ", "LHR", "lhr", "Heathrow", "heathrow", "LGW", "lgw", "LgW", "Gatwick", "GATWICK",
+    "ltn", "LTN", "Luton", "luton", "STN", "stn", "Stansted", "LCY", "lcy", "LondonCity",
+    "groß", "GROSS", "gross", "Gross", "蟹", "Crab", "Empty", "Ignored", "Other", "lorem ipsum",
+    "V0", "v0", "baa", "BAA", "baz", "BAZ", "bAz", "V3", "__", "__baa", "__V0", "_baa",
+    "a string which is much longer than any of the names", "foo", "FOO", "foo2", "Foo2", "FOO2",
+    "V1", "v2", "Foo",
+];
+
+#[test]
+fn test_phf_matches_match() {
+    for input in INPUTS {
+        assert_eq!(
+            format!("{:?}", MatchE0::unscribe(input)),
+            format!("{:?}", PhfE0::unscribe(input)),
+            "{:?}", input
+        );
+
+        assert_eq!(
+            format!("{:?}", MatchE1::try_unscribe(input)),
+            format!("{:?}", PhfE1::try_unscribe(input)),
+            "{:?}", input
+        );
+
+        assert_eq!(
+            format!("{:?}", MatchE2::try_unscribe(input)),
+            format!("{:?}", PhfE2::try_unscribe(input)),
+            "{:?}", input
+        );
+
+        let json = serde_json::to_string(input).unwrap();
+
+        assert_eq!(
+            format!("{:?}", serde_json::from_str::<MatchE0>(&json).ok()),
+            format!("{:?}", serde_json::from_str::<PhfE0>(&json).ok()),
+            "{:?}", input
+        );
+
+        assert_eq!(
+            format!("{:?}", serde_json::from_str::<MatchE1>(&json).ok()),
+            format!("{:?}", serde_json::from_str::<PhfE1>(&json).ok()),
+            "{:?}", input
+        );
+    }
+}
+
+#[test]
+fn test_phf_unscribe() {
+    assert_eq!(PhfE0::unscribe("LHR"), PhfE0::Heathrow);
+    assert_eq!(PhfE0::unscribe("Heathrow"), PhfE0::Heathrow);
+    assert_eq!(PhfE0::unscribe("lgw"), PhfE0::Gatwick);
+    assert_eq!(PhfE0::unscribe("GROSS"), PhfE0::Gross);
+    assert_eq!(PhfE0::unscribe(""), PhfE0::Empty);
+    assert_eq!(
+        PhfE0::unscribe("lhr"),
+        PhfE0::Other { raw: "lhr".to_owned(), norm: "lhr".to_owned() }
+    );
+    assert_eq!(PhfE0::unscribe_tagged("蟹"), (PhfE0::Crab, false));
+
+    assert_eq!(PhfE1::try_unscribe("V0"), Some(PhfE1::V0));
+    assert_eq!(PhfE1::try_unscribe("BaZ"), Some(PhfE1::V2));
+    assert_eq!(PhfE1::try_unscribe("lor"), Some(PhfE1::V3("lor".to_owned())));
+    assert_eq!(PhfE1::try_unscribe("__l"), None);
+    assert_eq!(PhfE1::try_unscribe("lorem ipsum"), None);
+
+    assert_eq!(PhfE2::try_unscribe("Foo2"), Some(PhfE2::V2));
+    assert_eq!(PhfE2::try_unscribe("foo3"), None);
+    assert_eq!(PhfE2::try_unscribe("Foo"), Some(PhfE2::V3));
+    assert_eq!(PhfE2::try_unscribe("FOO"), Some(PhfE2::V1));
+}
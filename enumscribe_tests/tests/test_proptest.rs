@@ -0,0 +1,95 @@
+use enumscribe::{ScribeCowStr, ScribeStaticStr, TryUnscribe, Unscribe};
+use proptest::prelude::*;
+
+#[derive(ScribeCowStr, Unscribe, TryUnscribe, Eq, PartialEq, Debug)]
+enum E0 {
+    V0,
+    #[enumscribe(str = "foo")]
+    V1,
+    #[enumscribe(str = "baa", case_insensitive)]
+    V2,
+    #[enumscribe(str = "straße", case_insensitive)]
+    V3,
+    #[enumscribe(str = "蟹")]
+    V4,
+    #[enumscribe(str = "ΣΊΣΥΦΟΣ", case_insensitive)]
+    V5,
+    #[enumscribe(other)]
+    V6(String),
+}
+
+#[derive(ScribeStaticStr, TryUnscribe, Eq, PartialEq, Debug)]
+#[enumscribe(case_insensitive)]
+enum E1 {
+    V0,
+    #[enumscribe(str = "foo", case_sensitive)]
+    V1,
+    #[enumscribe(str = "baa")]
+    V2,
+    #[enumscribe(str = "straße")]
+    V3,
+    #[enumscribe(str = "ǅ")]
+    V4,
+}
+
+const NAMES: [&str; 9] = ["V0", "foo", "baa", "straße", "STRASSE", "蟹", "ΣΊΣΥΦΟΣ", "σίσυφος", "ǅ"];
+
+/// Strings which are equal to, or a small number of edits away from, one of the names used by the
+/// enums under test. Case-insensitive matching is most likely to go wrong for these strings.
+fn near_match() -> impl Strategy<Value = String> {
+    (
+        prop::sample::select(&NAMES[..]),
+        prop::collection::vec(any::<bool>(), 0..8),
+        "[a-zA-Zß]{0,2}",
+        "[a-zA-Zß]{0,2}",
+    )
+        .prop_map(|(name, flips, prefix, suffix)| {
+            let mut s = prefix;
+            for (i, c) in name.chars().enumerate() {
+                if flips.get(i).copied().unwrap_or(false) {
+                    if c.is_lowercase() {
+                        s.extend(c.to_uppercase());
+                    } else {
+                        s.extend(c.to_lowercase());
+                    }
+                } else {
+                    s.push(c);
+                }
+            }
+            s.push_str(&suffix);
+            s
+        })
+}
+
+fn any_input() -> impl Strategy<Value = String> {
+    prop_oneof![near_match(), any::<String>()]
+}
+
+proptest! {
+    #[test]
+    fn test_unscribe_scribe_idempotent(s in any_input()) {
+        let unscribed = E0::unscribe(&s);
+        let scribed = unscribed.scribe();
+        prop_assert_eq!(E0::unscribe(&scribed), unscribed);
+    }
+
+    #[test]
+    fn test_unscribe_other_preserves_input(s in any_input()) {
+        if let E0::V6(stored) = E0::unscribe(&s) {
+            prop_assert_eq!(&stored, &s);
+            prop_assert_eq!(E0::V6(stored).scribe(), s);
+        }
+    }
+
+    #[test]
+    fn test_try_unscribe_agrees_with_unscribe(s in any_input()) {
+        prop_assert_eq!(E0::try_unscribe(&s), Some(E0::unscribe(&s)));
+    }
+
+    #[test]
+    fn test_try_unscribe_round_trip(s in any_input()) {
+        if let Some(unscribed) = E1::try_unscribe(&s) {
+            prop_assert_eq!(E1::try_unscribe(unscribed.scribe()), Some(unscribed));
+        }
+    }
+}
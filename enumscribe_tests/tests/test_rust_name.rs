@@ -0,0 +1,47 @@
+use enumscribe::{EnumRustName, TryScribeCowStr};
+
+#[test]
+fn test_enum_rust_name() {
+    #[derive(EnumRustName, TryScribeCowStr)]
+    #[enumscribe(rename_all = "snake_case")]
+    #[allow(dead_code)]
+    enum E0 {
+        #[enumscribe(str = "LHR")]
+        Heathrow,
+        GatwickAirport,
+        V2(),
+        #[enumscribe(ignore)]
+        V3(i32),
+        V4 {},
+        r#Type,
+        #[enumscribe(other)]
+        V6(String),
+    }
+
+    assert_eq!(E0::Heathrow.rust_name(), "Heathrow");
+    assert_eq!(E0::Heathrow.try_scribe().as_deref(), Some("LHR"));
+    assert_eq!(E0::GatwickAirport.rust_name(), "GatwickAirport");
+    assert_eq!(E0::GatwickAirport.try_scribe().as_deref(), Some("gatwick_airport"));
+    assert_eq!(E0::V2().rust_name(), "V2");
+    assert_eq!(E0::V3(5).rust_name(), "V3");
+    assert_eq!(E0::V3(5).try_scribe(), None);
+    assert_eq!(E0::V4 {}.rust_name(), "V4");
+    assert_eq!(E0::Type.rust_name(), "Type");
+    assert_eq!(E0::V6("STN".to_owned()).rust_name(), "V6");
+    assert_eq!(E0::V6("STN".to_owned()).try_scribe().as_deref(), Some("STN"));
+}
+
+#[test]
+fn test_enum_rust_name_generic() {
+    #[derive(EnumRustName)]
+    #[allow(dead_code)]
+    enum E0<T, const N: usize> {
+        #[enumscribe(str = "LHR")]
+        Heathrow,
+        #[enumscribe(ignore)]
+        Other([T; N]),
+    }
+
+    assert_eq!(E0::<u8, 0>::Heathrow.rust_name(), "Heathrow");
+    assert_eq!(E0::Other([0u8; 2]).rust_name(), "Other");
+}
@@ -1,11 +1,13 @@
 use std::borrow::Cow;
 
 use enumscribe::{
-    ScribeCowStr, ScribeStaticStr, ScribeString, TryScribeCowStr, TryScribeStaticStr,
-    TryScribeString,
+    scribe_join, DynScribe, EnumCount, EnumVariantNames, IntoCowStr, OtherShadowsNamed,
+    ScribeBoxStr, ScribeCasedCowStr, ScribeChecked, ScribeCowStr, ScribeError, ScribeInterned,
+    ScribeStaticBytes, ScribeStaticStr, ScribeString, TryScribeBoxStr, TryScribeCowStr,
+    TryScribeStaticStr, TryScribeString, Unscribe,
 };
 
-const TEST_STRINGS: [&'static str; 6] =
+const TEST_STRINGS: [&str; 6] =
     ["", "\0", "foo", "baa", "Hello, world!", "こんにちは、世界"];
 
 #[test]
@@ -31,6 +33,178 @@ fn test_scribe_static_str() {
     assert_eq!(E0::V5 {}.scribe(), "baz");
 }
 
+#[test]
+fn test_scribe_with_ci() {
+    #[derive(ScribeStaticStr, Eq, PartialEq, Debug)]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "foo", case_insensitive)]
+        V1,
+        #[enumscribe(case_insensitive)]
+        V2(),
+        #[enumscribe(str = "baa", case_sensitive)]
+        V3 {},
+    }
+
+    assert_eq!(E0::V0.scribe_with_ci(), ("V0", false));
+    assert_eq!(E0::V1.scribe_with_ci(), ("foo", true));
+    assert_eq!(E0::V2().scribe_with_ci(), ("V2", true));
+    assert_eq!(E0::V3 {}.scribe_with_ci(), ("baa", false));
+
+    #[derive(ScribeStaticStr, Eq, PartialEq, Debug)]
+    #[enumscribe(case_insensitive)]
+    enum E1 {
+        V0,
+        #[enumscribe(str = "foo", case_sensitive)]
+        V1,
+        #[enumscribe(str = "baa")]
+        V2,
+    }
+
+    assert_eq!(E1::V0.scribe_with_ci(), ("V0", true));
+    assert_eq!(E1::V1.scribe_with_ci(), ("foo", false));
+    assert_eq!(E1::V2.scribe_with_ci(), ("baa", true));
+
+    #[derive(ScribeStaticStr, Eq, PartialEq, Debug)]
+    enum E2 {
+        V0,
+    }
+
+    assert_eq!(E2::V0.scribe_with_ci(), ("V0", false));
+}
+
+#[test]
+fn test_scribe_static_str_overlapping_names() {
+    #[derive(ScribeStaticStr, ScribeStaticBytes, Clone, Copy, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "application/json")]
+        V0,
+        #[enumscribe(str = "application/json-patch+json")]
+        V1,
+        #[enumscribe(str = "json")]
+        V2,
+        #[enumscribe(str = "application/")]
+        V3,
+        #[enumscribe(str = "")]
+        V4,
+        #[enumscribe(str = "こんにちは、世界")]
+        V5,
+        #[enumscribe(str = "世界")]
+        V6,
+    }
+
+    let expected = [
+        (E0::V0, "application/json"),
+        (E0::V1, "application/json-patch+json"),
+        (E0::V2, "json"),
+        (E0::V3, "application/"),
+        (E0::V4, ""),
+        (E0::V5, "こんにちは、世界"),
+        (E0::V6, "世界"),
+    ];
+
+    for (variant, name) in expected.iter() {
+        assert_eq!(variant.scribe(), *name);
+        assert_eq!(variant.scribe_bytes(), name.as_bytes());
+    }
+}
+
+#[test]
+fn test_scribe_static_str_lookup_table() {
+    // Copy enums with only unit variants are scribed by looking up the variant's index, so
+    // compare them against identical enums which are not Copy, which are scribed with a match.
+    #[derive(ScribeStaticStr, Clone, Copy)]
+    enum Table {
+        #[enumscribe(str = "LHR")]
+        Heathrow,
+        Gatwick,
+        #[enumscribe(ignore, placeholder = "???")]
+        Secret,
+        #[enumscribe(str = "LCY", case_insensitive)]
+        LondonCity,
+    }
+
+    #[derive(ScribeStaticStr)]
+    enum Match {
+        #[enumscribe(str = "LHR")]
+        Heathrow,
+        Gatwick,
+        #[enumscribe(ignore, placeholder = "???")]
+        Secret,
+        #[enumscribe(str = "LCY", case_insensitive)]
+        LondonCity,
+    }
+
+    let table = [Table::Heathrow, Table::Gatwick, Table::Secret, Table::LondonCity];
+    let matched = [Match::Heathrow, Match::Gatwick, Match::Secret, Match::LondonCity];
+    for (table, matched) in table.iter().zip(matched.iter()) {
+        assert_eq!(table.scribe(), matched.scribe());
+        assert_eq!(table.scribe_with_ci(), matched.scribe_with_ci());
+    }
+    assert_eq!(Table::Secret.scribe(), "???");
+
+    // The looked-up strings are consecutive slices of one string
+    let heathrow = Table::Heathrow.scribe();
+    assert_eq!(heathrow.as_ptr().wrapping_add(heathrow.len()), Table::Gatwick.scribe().as_ptr());
+
+    // Explicit discriminants are not the index of the variant, so these are scribed with a match.
+    #[derive(ScribeStaticStr, Clone, Copy)]
+    #[repr(u8)]
+    enum Discriminants {
+        #[enumscribe(str = "ten")]
+        Ten = 10,
+        #[enumscribe(str = "zero")]
+        Zero = 0,
+        #[enumscribe(str = "one")]
+        One,
+    }
+
+    assert_eq!(Discriminants::Ten.scribe(), "ten");
+    assert_eq!(Discriminants::Zero.scribe(), "zero");
+    assert_eq!(Discriminants::One.scribe(), "one");
+
+    // Variants with parentheses or braces cannot be cast to an integer.
+    #[derive(ScribeStaticStr, Clone, Copy)]
+    enum Fields {
+        #[enumscribe(str = "unit")]
+        Unit,
+        #[enumscribe(str = "paren")]
+        Paren(),
+        #[enumscribe(str = "brace")]
+        Brace {},
+    }
+
+    assert_eq!(Fields::Unit.scribe(), "unit");
+    assert_eq!(Fields::Paren().scribe(), "paren");
+    assert_eq!(Fields::Brace {}.scribe(), "brace");
+}
+
+#[test]
+fn test_scribe_static_bytes() {
+    #[derive(ScribeStaticBytes, Eq, PartialEq, Debug)]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "foo")]
+        V1,
+        V2(),
+        #[enumscribe(str = "baa")]
+        V3(),
+        V4 {},
+        #[enumscribe(str = "\0")]
+        V5 {},
+        #[enumscribe(str = "蟹")]
+        V6,
+    }
+
+    assert_eq!(E0::V0.scribe_bytes(), b"V0");
+    assert_eq!(E0::V1.scribe_bytes(), b"foo");
+    assert_eq!(E0::V2().scribe_bytes(), b"V2");
+    assert_eq!(E0::V3().scribe_bytes(), b"baa");
+    assert_eq!(E0::V4 {}.scribe_bytes(), b"V4");
+    assert_eq!(E0::V5 {}.scribe_bytes(), b"\0");
+    assert_eq!(E0::V6.scribe_bytes(), "蟹".as_bytes());
+}
+
 #[test]
 fn test_try_scribe_static_str() {
     #[derive(TryScribeStaticStr, Eq, PartialEq, Debug)]
@@ -196,6 +370,101 @@ fn test_try_scribe_string() {
     assert_eq!(E2::V2.try_scribe(), None);
 }
 
+#[test]
+fn test_scribe_box_str() {
+    #[derive(ScribeBoxStr, Eq, PartialEq, Debug)]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "foo")]
+        V1,
+        V2(),
+        #[enumscribe(str = "baz")]
+        V3 {},
+    }
+
+    assert_eq!(E0::V0.scribe(), Box::from("V0"));
+    assert_eq!(E0::V1.scribe(), Box::from("foo"));
+    assert_eq!(E0::V2().scribe(), Box::from("V2"));
+    assert_eq!(E0::V3 {}.scribe(), Box::from("baz"));
+
+    #[derive(ScribeBoxStr, Eq, PartialEq, Debug)]
+    enum E1 {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(other)]
+        V1(String),
+    }
+
+    assert_eq!(E1::V0.scribe(), Box::from("foo"));
+    for &x in &TEST_STRINGS {
+        assert_eq!(E1::V1(x.to_owned()).scribe(), Box::from(x));
+    }
+
+    #[derive(ScribeBoxStr, Eq, PartialEq, Debug)]
+    enum E2 {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(other)]
+        V1 { s: String },
+    }
+
+    assert_eq!(E2::V0.scribe(), Box::from("foo"));
+    for &x in &TEST_STRINGS {
+        assert_eq!(E2::V1 { s: x.to_owned() }.scribe(), Box::from(x));
+    }
+
+    fn shout(name: &'static str) -> Cow<'static, str> {
+        Cow::Owned(name.to_uppercase())
+    }
+
+    #[derive(ScribeBoxStr, Eq, PartialEq, Debug)]
+    #[enumscribe(scribe_map = "shout")]
+    enum E3 {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(str = "port:{}", fmt_fields)]
+        V1(u16),
+    }
+
+    assert_eq!(E3::V0.scribe(), Box::from("FOO"));
+    assert_eq!(E3::V1(8080).scribe(), Box::from("port:8080"));
+}
+
+#[test]
+fn test_try_scribe_box_str() {
+    #[derive(TryScribeBoxStr, Eq, PartialEq, Debug)]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "foo")]
+        V1,
+        #[enumscribe(ignore)]
+        V2,
+        #[enumscribe(ignore)]
+        V3(i32, i32),
+    }
+
+    assert_eq!(E0::V0.try_scribe(), Some(Box::from("V0")));
+    assert_eq!(E0::V1.try_scribe(), Some(Box::from("foo")));
+    assert_eq!(E0::V2.try_scribe(), None);
+    assert_eq!(E0::V3(123, 456).try_scribe(), None);
+
+    #[derive(TryScribeBoxStr, Eq, PartialEq, Debug)]
+    enum E1 {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(other)]
+        V1(String),
+        #[enumscribe(ignore)]
+        V2,
+    }
+
+    assert_eq!(E1::V0.try_scribe(), Some(Box::from("foo")));
+    for &x in &TEST_STRINGS {
+        assert_eq!(E1::V1(x.to_owned()).try_scribe(), Some(Box::from(x)));
+    }
+    assert_eq!(E1::V2.try_scribe(), None);
+}
+
 #[test]
 fn test_scribe_cow_str() {
     #[derive(ScribeCowStr, Eq, PartialEq, Debug)]
@@ -249,6 +518,110 @@ fn test_scribe_cow_str() {
             Cow::Owned::<'static, str>(x.to_owned())
         );
     }
+
+    #[derive(ScribeCowStr, Eq, PartialEq, Debug)]
+    enum E3 {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(other, raw_field = "raw", norm_field = "norm")]
+        V1 { raw: String, norm: String },
+    }
+
+    assert_eq!(E3::V0.scribe(), "foo".to_owned());
+    for &x in &TEST_STRINGS {
+        assert_eq!(
+            E3::V1 { raw: x.to_owned(), norm: x.to_lowercase() }.scribe(),
+            Cow::Owned::<'static, str>(x.to_owned())
+        );
+    }
+}
+
+#[test]
+fn test_scribe_cased_cow_str() {
+    #[derive(ScribeCasedCowStr, Eq, PartialEq, Debug)]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "fOo")]
+        V1,
+        V2(),
+        #[enumscribe(str = "straße")]
+        V3 {},
+        #[enumscribe(str = "ΣΊΣΥΦΟΣ")]
+        V4,
+        #[enumscribe(str = "\0")]
+        V5,
+        #[enumscribe(other)]
+        V6(String),
+    }
+
+    assert_eq!(E0::V0.scribe_lower(), Cow::Borrowed("v0"));
+    assert_eq!(E0::V0.scribe_upper(), Cow::Borrowed("V0"));
+    assert_eq!(E0::V1.scribe_lower(), Cow::Borrowed("foo"));
+    assert_eq!(E0::V1.scribe_upper(), Cow::Borrowed("FOO"));
+    assert_eq!(E0::V2().scribe_lower(), Cow::Borrowed("v2"));
+    assert_eq!(E0::V2().scribe_upper(), Cow::Borrowed("V2"));
+    assert_eq!(E0::V3 {}.scribe_lower(), Cow::Borrowed("straße"));
+    assert_eq!(E0::V3 {}.scribe_upper(), Cow::Borrowed("STRASSE"));
+    assert_eq!(E0::V4.scribe_lower(), Cow::Borrowed("σίσυφος"));
+    assert_eq!(E0::V4.scribe_upper(), Cow::Borrowed("ΣΊΣΥΦΟΣ"));
+    assert_eq!(E0::V5.scribe_lower(), Cow::Borrowed("\0"));
+    assert_eq!(E0::V5.scribe_upper(), Cow::Borrowed("\0"));
+
+    for &x in &TEST_STRINGS {
+        assert_eq!(
+            E0::V6(x.to_owned()).scribe_lower(),
+            Cow::Owned::<'static, str>(x.to_lowercase())
+        );
+        assert_eq!(
+            E0::V6(x.to_owned()).scribe_upper(),
+            Cow::Owned::<'static, str>(x.to_uppercase())
+        );
+    }
+
+    assert_eq!(
+        E0::V6("Grüße".to_owned()).scribe_upper(),
+        Cow::Owned::<'static, str>("GRÜSSE".to_owned())
+    );
+    assert_eq!(
+        E0::V6("ÀÉÎ".to_owned()).scribe_lower(),
+        Cow::Owned::<'static, str>("àéî".to_owned())
+    );
+
+    #[derive(ScribeCasedCowStr, Eq, PartialEq, Debug)]
+    enum E1 {
+        #[enumscribe(other)]
+        V0 { s: String },
+    }
+
+    assert_eq!(
+        E1::V0 { s: "Foo".to_owned() }.scribe_lower(),
+        Cow::Owned::<'static, str>("foo".to_owned())
+    );
+}
+
+#[test]
+fn test_try_scribe_result() {
+    #[derive(TryScribeCowStr, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(ignore)]
+        V1(i32),
+        #[enumscribe(ignore, placeholder = "N/A")]
+        V2,
+        #[enumscribe(other)]
+        V3(String),
+    }
+
+    assert_eq!(E0::V0.try_scribe_result(), Ok(Cow::Borrowed("foo")));
+    assert_eq!(E0::V1(123).try_scribe_result(), Err(ScribeError::Ignored));
+    assert_eq!(E0::V2.try_scribe_result(), Ok(Cow::Borrowed("N/A")));
+    assert_eq!(
+        E0::V3("baa".to_owned()).try_scribe_result(),
+        Ok(Cow::Owned::<'static, str>("baa".to_owned()))
+    );
+
+    assert_eq!(ScribeError::Ignored.to_string(), "variant is ignored and has no string");
 }
 
 #[test]
@@ -330,3 +703,637 @@ fn test_try_scribe_cow_str() {
     }
     assert_eq!(E2::V2.try_scribe(), None);
 }
+
+#[test]
+fn test_bare_attribute() {
+    /// Doc comments mentioning `#[enumscribe]` do not affect anything.
+    #[derive(ScribeStaticStr, Eq, PartialEq, Debug)]
+    #[enumscribe]
+    enum E0 {
+        #[enumscribe]
+        V0,
+        #[enumscribe]
+        #[enumscribe(str = "foo")]
+        V1,
+        /// `#[enumscribe(str = "baa")]`
+        #[enumscribe()]
+        V2,
+    }
+
+    assert_eq!(E0::V0.scribe(), "V0");
+    assert_eq!(E0::V1.scribe(), "foo");
+    assert_eq!(E0::V2.scribe(), "V2");
+}
+
+#[test]
+fn test_punctuated_names() {
+    #[derive(ScribeStaticStr, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "a=b,c")]
+        V0,
+        #[enumscribe(str = ",")]
+        V1,
+        #[enumscribe(str = "=")]
+        V2,
+    }
+
+    assert_eq!(E0::V0.scribe(), "a=b,c");
+    assert_eq!(E0::V1.scribe(), ",");
+    assert_eq!(E0::V2.scribe(), "=");
+}
+
+#[test]
+fn test_non_exhaustive() {
+    #[derive(ScribeStaticStr, TryScribeStaticStr, ScribeStaticBytes, Eq, PartialEq, Debug)]
+    #[non_exhaustive]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "foo")]
+        V1,
+        #[non_exhaustive]
+        V2 {},
+    }
+
+    assert_eq!(E0::V0.scribe(), "V0");
+    assert_eq!(E0::V1.scribe(), "foo");
+    assert_eq!(E0::V2 {}.scribe(), "V2");
+    assert_eq!(E0::V1.try_scribe(), Some("foo"));
+    assert_eq!(E0::V1.scribe_bytes(), b"foo");
+
+    #[derive(ScribeCowStr, TryScribeCowStr, ScribeString, TryScribeString, Eq, PartialEq, Debug)]
+    #[non_exhaustive]
+    enum E1 {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(other)]
+        V1(String),
+    }
+
+    assert_eq!(ScribeString::scribe(&E1::V0), "foo".to_owned());
+    assert_eq!(
+        TryScribeString::try_scribe(&E1::V1("baa".to_owned())),
+        Some("baa".to_owned())
+    );
+    assert_eq!(
+        ScribeCowStr::scribe(&E1::V1("baa".to_owned())),
+        Cow::Owned::<'static, str>("baa".to_owned())
+    );
+    assert_eq!(
+        TryScribeCowStr::try_scribe(&E1::V0),
+        Some(Cow::Borrowed("foo"))
+    );
+}
+
+#[test]
+fn test_dyn_scribe() {
+    #[derive(ScribeCowStr, Eq, PartialEq, Debug)]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "foo")]
+        V1,
+    }
+
+    #[derive(ScribeCowStr, Eq, PartialEq, Debug)]
+    enum E1 {
+        #[enumscribe(str = "baa")]
+        V0 {},
+        #[enumscribe(other)]
+        V1(String),
+    }
+
+    let values: Vec<Box<dyn DynScribe>> = vec![
+        Box::new(E0::V0),
+        Box::new(E1::V0 {}),
+        Box::new(E0::V1),
+        Box::new(E1::V1("lorem".to_owned())),
+    ];
+
+    let scribed = values.iter().map(|value| value.scribe_dyn()).collect::<Vec<_>>();
+    assert_eq!(scribed, [
+        Cow::Borrowed("V0"),
+        Cow::Borrowed("baa"),
+        Cow::Borrowed("foo"),
+        Cow::Owned::<'static, str>("lorem".to_owned()),
+    ]);
+
+    let value: &dyn DynScribe = &E0::V1;
+    assert_eq!(value.scribe_dyn(), Cow::Borrowed("foo"));
+}
+
+mod scribe_maps {
+    use std::borrow::Cow;
+
+    pub fn identity(name: &'static str) -> Cow<'static, str> {
+        Cow::Borrowed(name)
+    }
+
+    pub fn uppercase(name: &'static str) -> Cow<'static, str> {
+        Cow::Owned(name.to_uppercase())
+    }
+}
+
+#[test]
+fn test_scribe_map() {
+    #[derive(ScribeCowStr, ScribeString, Unscribe, Eq, PartialEq, Debug)]
+    #[enumscribe(scribe_map = "scribe_maps::identity")]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "foo")]
+        V1,
+        #[enumscribe(other)]
+        V2(String),
+    }
+
+    assert_eq!(ScribeCowStr::scribe(&E0::V0), Cow::Borrowed("V0"));
+    assert_eq!(ScribeCowStr::scribe(&E0::V1), Cow::Borrowed("foo"));
+    assert_eq!(ScribeCowStr::scribe(&E0::V2("baa".to_owned())), Cow::Borrowed("baa"));
+    assert_eq!(ScribeString::scribe(&E0::V1), "foo".to_owned());
+
+    #[derive(ScribeCowStr, ScribeString, Unscribe, Eq, PartialEq, Debug)]
+    #[enumscribe(scribe_map = "crate::scribe_maps::uppercase")]
+    enum E1 {
+        V0,
+        #[enumscribe(str = "foo")]
+        V1,
+        #[enumscribe(other)]
+        V2(String),
+    }
+
+    assert_eq!(ScribeCowStr::scribe(&E1::V0), Cow::Borrowed("V0"));
+    assert_eq!(ScribeCowStr::scribe(&E1::V1), Cow::Borrowed("FOO"));
+    assert_eq!(ScribeCowStr::scribe(&E1::V2("baa".to_owned())), Cow::Borrowed("baa"));
+    assert_eq!(ScribeString::scribe(&E1::V0), "V0".to_owned());
+    assert_eq!(ScribeString::scribe(&E1::V1), "FOO".to_owned());
+    assert_eq!(ScribeString::scribe(&E1::V2("baa".to_owned())), "baa".to_owned());
+
+    // Unscribing is unaffected by the map
+    assert_eq!(E1::unscribe("foo"), E1::V1);
+    assert_eq!(E1::unscribe("FOO"), E1::V2("FOO".to_owned()));
+
+    #[derive(TryScribeCowStr, TryScribeString, Eq, PartialEq, Debug)]
+    #[enumscribe(scribe_map = "scribe_maps::uppercase")]
+    enum E2 {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(ignore)]
+        V1,
+        #[enumscribe(other)]
+        V2(String),
+    }
+
+    assert_eq!(TryScribeCowStr::try_scribe(&E2::V0), Some(Cow::Borrowed("FOO")));
+    assert_eq!(TryScribeCowStr::try_scribe(&E2::V1), None);
+    assert_eq!(TryScribeCowStr::try_scribe(&E2::V2("baa".to_owned())), Some(Cow::Borrowed("baa")));
+    assert_eq!(TryScribeString::try_scribe(&E2::V0), Some("FOO".to_owned()));
+    assert_eq!(TryScribeString::try_scribe(&E2::V1), None);
+}
+
+#[test]
+fn test_scribe_fmt_fields() {
+    #[derive(ScribeCowStr, ScribeString, EnumCount, EnumVariantNames, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "localhost")]
+        V0,
+        #[enumscribe(str = "port:{}", fmt_fields)]
+        V1(u16),
+        #[enumscribe(str = "{}:{}", fmt_fields)]
+        V2(&'static str, u16),
+        #[enumscribe(str = "{}.{}.{}.{}:{}", fmt_fields)]
+        V3 { a: u8, b: u8, c: u8, d: u8, port: u16 },
+        #[enumscribe(str = "{{{}}}", fmt_fields)]
+        V4 { inner: char },
+        #[enumscribe(other)]
+        V5(String),
+    }
+
+    assert_eq!(ScribeCowStr::scribe(&E0::V0), Cow::Borrowed("localhost"));
+    assert_eq!(
+        ScribeCowStr::scribe(&E0::V1(8080)),
+        Cow::Owned::<'static, str>("port:8080".to_owned())
+    );
+    assert_eq!(ScribeCowStr::scribe(&E0::V2("example.com", 443)), "example.com:443");
+    assert_eq!(
+        ScribeCowStr::scribe(&E0::V3 { a: 127, b: 0, c: 0, d: 1, port: 80 }),
+        "127.0.0.1:80"
+    );
+    assert_eq!(ScribeCowStr::scribe(&E0::V4 { inner: 'x' }), "{x}");
+    assert_eq!(ScribeCowStr::scribe(&E0::V5("lorem".to_owned())), "lorem");
+
+    assert_eq!(ScribeString::scribe(&E0::V1(8080)), "port:8080".to_owned());
+    assert_eq!(ScribeString::scribe(&E0::V2("example.com", 443)), "example.com:443".to_owned());
+
+    assert!(matches!(ScribeCowStr::scribe(&E0::V1(0)), Cow::Owned(_)));
+    // fmt_fields variants have no fixed string, so they are not counted as named
+    assert_eq!(E0::NUM_NAMED_VARIANTS, 1);
+    assert_eq!(
+        <E0 as EnumVariantNames<{ E0::NUM_NAMED_VARIANTS }>>::VARIANT_NAMES.len(),
+        E0::NUM_NAMED_VARIANTS
+    );
+    assert_eq!(<E0 as EnumVariantNames<{ E0::NUM_NAMED_VARIANTS }>>::VARIANT_NAMES, ["localhost"]);
+
+    #[derive(TryScribeCowStr, TryScribeString, Eq, PartialEq, Debug)]
+    enum E1 {
+        #[enumscribe(str = "port:{}", fmt_fields)]
+        V0(u16),
+        #[enumscribe(str = "user:{}", fmt_fields)]
+        V1 { name: String },
+        #[enumscribe(ignore)]
+        V2(u16),
+    }
+
+    assert_eq!(TryScribeCowStr::try_scribe(&E1::V0(22)), Some(Cow::Owned("port:22".to_owned())));
+    assert_eq!(
+        TryScribeCowStr::try_scribe(&E1::V1 { name: "root".to_owned() }),
+        Some(Cow::Owned("user:root".to_owned()))
+    );
+    assert_eq!(TryScribeCowStr::try_scribe(&E1::V2(22)), None);
+    assert_eq!(TryScribeString::try_scribe(&E1::V0(22)), Some("port:22".to_owned()));
+    assert_eq!(TryScribeString::try_scribe(&E1::V2(22)), None);
+}
+
+#[test]
+fn test_debug_as_scribe() {
+    #[derive(ScribeCowStr, Eq, PartialEq)]
+    #[enumscribe(debug_as_scribe)]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "foo")]
+        V1,
+        #[enumscribe(str = "port:{}", fmt_fields)]
+        V2(u16),
+        #[enumscribe(other)]
+        V3(String),
+    }
+
+    let values = [E0::V0, E0::V1, E0::V2(8080), E0::V3("lorem \"ipsum\"".to_owned())];
+    for value in values.iter() {
+        assert_eq!(format!("{:?}", value), value.scribe());
+    }
+
+    assert_eq!(format!("{:?}", E0::V1), "foo");
+    assert_eq!(format!("{:?}", E0::V2(8080)), "port:8080");
+    assert_eq!(format!("{:?}", E0::V3("lorem \"ipsum\"".to_owned())), "lorem \"ipsum\"");
+    assert_eq!(format!("{:?}", [E0::V0, E0::V1]), "[V0, foo]");
+
+    #[derive(ScribeCowStr)]
+    #[enumscribe(debug_as_scribe, scribe_map = "scribe_maps::uppercase")]
+    enum E1 {
+        #[enumscribe(str = "foo")]
+        V0,
+    }
+
+    assert_eq!(format!("{:?}", E1::V0), "FOO");
+}
+
+#[test]
+fn test_scribe_interned() {
+    #[derive(ScribeInterned)]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "foo")]
+        V1,
+        V2(),
+        V3 {},
+        #[enumscribe(str = "port:{}", fmt_fields)]
+        V4(u16),
+        #[enumscribe(other)]
+        V5(String),
+    }
+
+    assert_eq!(E0::V0.scribe_interned(), "V0");
+    assert_eq!(E0::V1.scribe_interned(), "foo");
+    assert_eq!(E0::V2().scribe_interned(), "V2");
+    assert_eq!(E0::V3 {}.scribe_interned(), "V3");
+    assert_eq!(E0::V4(8080).scribe_interned(), "port:8080");
+
+    for &x in TEST_STRINGS.iter() {
+        let interned_1 = E0::V5(x.to_owned()).scribe_interned();
+        let interned_2 = E0::V5(x.to_owned()).scribe_interned();
+        assert_eq!(interned_1, x);
+        assert!(std::ptr::eq(interned_1, interned_2));
+    }
+
+    let port_1 = E0::V4(443).scribe_interned();
+    let port_2 = E0::V4(443).scribe_interned();
+    assert!(std::ptr::eq(port_1, port_2));
+
+    // Strings are interned by value, so equal strings from different variants share a reference.
+    assert!(std::ptr::eq(E0::V5("port:443".to_owned()).scribe_interned(), port_1));
+    assert!(!std::ptr::eq(E0::V5("port:444".to_owned()).scribe_interned(), port_1));
+
+    #[derive(ScribeInterned)]
+    enum E1 {
+        #[enumscribe(other)]
+        V0(Box<str>),
+    }
+
+    let interned: &'static str = {
+        let e = E1::V0("lorem ipsum".into());
+        e.scribe_interned()
+    };
+    assert_eq!(interned, "lorem ipsum");
+}
+
+#[test]
+fn test_scribe_join() {
+    #[derive(ScribeCowStr)]
+    enum Airport {
+        #[enumscribe(str = "LHR")]
+        Heathrow,
+        #[enumscribe(str = "LGW")]
+        Gatwick,
+        #[enumscribe(str = "LTN")]
+        Luton,
+        #[enumscribe(other)]
+        Other(String),
+    }
+
+    let airports = [Airport::Heathrow, Airport::Gatwick, Airport::Luton];
+    assert_eq!(scribe_join(&airports, ","), "LHR,LGW,LTN");
+    assert_eq!(scribe_join(&airports, ", "), "LHR, LGW, LTN");
+    assert_eq!(scribe_join(&airports, ""), "LHRLGWLTN");
+    assert_eq!(scribe_join(&airports[..1], ","), "LHR");
+    assert_eq!(scribe_join::<Airport>(&[], ","), "");
+
+    let airports = [
+        Airport::Other("STN".to_owned()),
+        Airport::Other("".to_owned()),
+        Airport::Gatwick,
+    ];
+    assert_eq!(scribe_join(&airports, ","), "STN,,LGW");
+}
+
+#[test]
+fn test_scribe_other_scribe_as() {
+    #[derive(
+        ScribeStaticStr,
+        ScribeString,
+        TryScribeString,
+        ScribeCowStr,
+        TryScribeCowStr,
+        ScribeCasedCowStr,
+        ScribeInterned,
+        Unscribe,
+        Eq,
+        PartialEq,
+        Debug
+    )]
+    enum E0 {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(other, scribe_as = "<Redacted>")]
+        V1(String),
+    }
+
+    assert_eq!(ScribeStaticStr::scribe(&E0::V0), "foo");
+    assert_eq!(ScribeCowStr::scribe(&E0::V0), Cow::Borrowed("foo"));
+
+    for &x in TEST_STRINGS.iter() {
+        let other = E0::unscribe(x);
+        if x == "foo" {
+            assert_eq!(other, E0::V0);
+            continue;
+        }
+        assert_eq!(other, E0::V1(x.to_owned()));
+
+        assert_eq!(ScribeStaticStr::scribe(&other), "<Redacted>");
+        assert_eq!(ScribeString::scribe(&other), "<Redacted>");
+        assert_eq!(TryScribeString::try_scribe(&other), Some("<Redacted>".to_owned()));
+        assert_eq!(ScribeCowStr::scribe(&other), Cow::Borrowed("<Redacted>"));
+        assert_eq!(TryScribeCowStr::try_scribe(&other), Some(Cow::Borrowed("<Redacted>")));
+        assert_eq!(other.scribe_lower(), Cow::Borrowed("<redacted>"));
+        assert_eq!(other.scribe_upper(), Cow::Borrowed("<REDACTED>"));
+        assert_eq!(other.scribe_interned(), "<Redacted>");
+    }
+
+    #[derive(ScribeCowStr, Unscribe, Eq, PartialEq)]
+    #[enumscribe(debug_as_scribe)]
+    enum E1 {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(other, raw_field = "raw", norm_field = "norm", scribe_as = "")]
+        V1 { raw: String, norm: String },
+    }
+
+    let other = E1::unscribe("Secret");
+    assert!(matches!(&other, E1::V1 { raw, norm } if raw == "Secret" && norm == "secret"));
+    assert_eq!(other.scribe(), Cow::Borrowed(""));
+    assert_eq!(format!("{:?}", other), "");
+    assert_eq!(format!("{:?}", E1::V0), "foo");
+}
+
+#[test]
+fn test_scribe_ignore_placeholder() {
+    #[derive(
+        ScribeStaticStr,
+        ScribeStaticBytes,
+        TryScribeStaticStr,
+        ScribeCowStr,
+        TryScribeString,
+        Unscribe,
+        Eq,
+        PartialEq,
+        Debug
+    )]
+    enum E0 {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(ignore, placeholder = "N/A")]
+        V1(i32),
+        #[enumscribe(ignore, placeholder = "")]
+        V2 { x: bool },
+        #[enumscribe(other, scribe_as = "?")]
+        V3(String),
+    }
+
+    assert_eq!(ScribeStaticStr::scribe(&E0::V0), "foo");
+    assert_eq!(ScribeStaticStr::scribe(&E0::V1(5)), "N/A");
+    assert_eq!(ScribeStaticStr::scribe(&E0::V2 { x: true }), "");
+    assert_eq!(E0::V1(5).scribe_bytes(), b"N/A");
+    assert_eq!(TryScribeStaticStr::try_scribe(&E0::V1(5)), Some("N/A"));
+    assert_eq!(ScribeCowStr::scribe(&E0::V1(5)), Cow::Borrowed("N/A"));
+    assert_eq!(TryScribeString::try_scribe(&E0::V2 { x: false }), Some("".to_owned()));
+
+    // Ignored variants are still never unscribed, even from their placeholder.
+    assert_eq!(E0::unscribe("N/A"), E0::V3("N/A".to_owned()));
+    assert_eq!(E0::unscribe(""), E0::V3("".to_owned()));
+
+    #[derive(TryScribeStaticStr, Eq, PartialEq, Debug)]
+    #[allow(dead_code)]
+    enum E1 {
+        #[enumscribe(ignore, placeholder = "N/A")]
+        V0,
+        #[enumscribe(ignore)]
+        V1,
+    }
+
+    assert_eq!(E1::V0.try_scribe(), Some("N/A"));
+    assert_eq!(E1::V1.try_scribe(), None);
+}
+
+#[test]
+fn test_scribe_checked() {
+    #[derive(ScribeCowStr, Eq, PartialEq, Debug)]
+    #[enumscribe(scribe_checked)]
+    enum E0 {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(str = "baa", alias = "qux")]
+        V1,
+        #[enumscribe(str = "Hello", case_insensitive)]
+        V2,
+        #[enumscribe(ignore, placeholder = "ignored")]
+        V3,
+        #[enumscribe(other)]
+        V4(String),
+    }
+
+    assert_eq!(E0::V0.scribe_checked().as_deref(), Ok("foo"));
+    assert_eq!(E0::V1.scribe_checked().as_deref(), Ok("baa"));
+    assert_eq!(E0::V2.scribe_checked().as_deref(), Ok("Hello"));
+    assert_eq!(E0::V3.scribe_checked().as_deref(), Ok("ignored"));
+
+    assert_eq!(E0::V4("bar".to_owned()).scribe_checked().as_deref(), Ok("bar"));
+    assert_eq!(E0::V4("FOO".to_owned()).scribe_checked().as_deref(), Ok("FOO"));
+    assert_eq!(E0::V4("ignored".to_owned()).scribe_checked().as_deref(), Ok("ignored"));
+    assert_eq!(E0::V4(String::new()).scribe_checked().as_deref(), Ok(""));
+
+    assert_eq!(E0::V4("foo".to_owned()).scribe_checked(), Err(OtherShadowsNamed));
+    assert_eq!(E0::V4("baa".to_owned()).scribe_checked(), Err(OtherShadowsNamed));
+    assert_eq!(E0::V4("qux".to_owned()).scribe_checked(), Err(OtherShadowsNamed));
+    assert_eq!(E0::V4("Hello".to_owned()).scribe_checked(), Err(OtherShadowsNamed));
+    assert_eq!(E0::V4("hELLO".to_owned()).scribe_checked(), Err(OtherShadowsNamed));
+
+    #[derive(ScribeCowStr, Eq, PartialEq, Debug)]
+    #[enumscribe(scribe_checked)]
+    enum E1 {
+        V0,
+        #[enumscribe(str = "foo")]
+        V1,
+    }
+
+    assert_eq!(E1::V0.scribe_checked().as_deref(), Ok("V0"));
+    assert_eq!(E1::V1.scribe_checked().as_deref(), Ok("foo"));
+}
+
+#[test]
+fn test_into_cow_str() {
+    fn describe(name: impl Into<Cow<'static, str>>) -> Cow<'static, str> {
+        name.into()
+    }
+
+    #[derive(ScribeCowStr, IntoCowStr, Eq, PartialEq, Debug)]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "foo")]
+        V1,
+        #[enumscribe(ignore, placeholder = "ignored")]
+        #[allow(dead_code)]
+        V2(u8),
+        #[enumscribe(other)]
+        V3(String),
+    }
+
+    assert_eq!(describe(&E0::V0), Cow::Borrowed("V0"));
+    assert_eq!(describe(&E0::V1), Cow::Borrowed("foo"));
+    assert_eq!(describe(&E0::V2(1)), Cow::Borrowed("ignored"));
+    assert_eq!(describe(&E0::V3("bar".to_owned())), Cow::<'static, str>::Owned("bar".to_owned()));
+
+    let variant = E0::V1;
+    let scribed: Cow<'static, str> = Cow::from(&variant);
+    assert_eq!(scribed, "foo");
+    assert_eq!(variant, E0::V1);
+}
+
+#[test]
+fn test_scribe_other_optional_field() {
+    #[derive(ScribeCowStr, ScribeString, ScribeCasedCowStr, Unscribe, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(other, optional_field)]
+        V1(Option<String>),
+    }
+
+    assert_eq!(ScribeCowStr::scribe(&E0::V0), Cow::Borrowed("foo"));
+    assert_eq!(ScribeCowStr::scribe(&E0::V1(Some("Bar".to_owned()))), "Bar");
+    assert_eq!(ScribeCowStr::scribe(&E0::V1(None)), "");
+    assert_eq!(ScribeString::scribe(&E0::V1(Some("Bar".to_owned()))), "Bar".to_owned());
+    assert_eq!(E0::V1(Some("Bar".to_owned())).scribe_upper(), "BAR");
+
+    for s in ["", "foo", "bar"].iter() {
+        assert_eq!(ScribeCowStr::scribe(&E0::unscribe(s)), *s);
+    }
+
+    #[derive(TryScribeCowStr, TryScribeString, Eq, PartialEq, Debug)]
+    enum E1 {
+        #[enumscribe(ignore)]
+        V0,
+        #[enumscribe(other, optional_field, placeholder = "<none>")]
+        V1 { s: Option<Box<str>> },
+    }
+
+    assert_eq!(TryScribeCowStr::try_scribe(&E1::V0), None);
+    assert_eq!(
+        TryScribeCowStr::try_scribe(&E1::V1 { s: Some("bar".into()) }).as_deref(),
+        Some("bar")
+    );
+    assert_eq!(TryScribeCowStr::try_scribe(&E1::V1 { s: None }).as_deref(), Some("<none>"));
+    assert_eq!(TryScribeString::try_scribe(&E1::V1 { s: None }), Some("<none>".to_owned()));
+}
+
+#[test]
+fn test_scribe_generic_other() {
+    #[derive(ScribeCowStr, ScribeString, TryScribeCowStr, TryScribeString, Debug)]
+    enum E0<T: Into<String>> {
+        V0,
+        #[enumscribe(str = "foo")]
+        V1,
+        #[enumscribe(other)]
+        V2(T),
+    }
+
+    assert_eq!(ScribeCowStr::scribe(&E0::<&'static str>::V0), Cow::Borrowed::<str>("V0"));
+    assert_eq!(ScribeString::scribe(&E0::<&'static str>::V1), "foo");
+    assert_eq!(ScribeCowStr::scribe(&E0::V2("bar")), "bar");
+    assert_eq!(ScribeString::scribe(&E0::V2("bar")), "bar");
+    assert_eq!(TryScribeCowStr::try_scribe(&E0::V2("bar")), Some(Cow::Owned("bar".to_owned())));
+    assert_eq!(TryScribeString::try_scribe(&E0::V2("bar")), Some("bar".to_owned()));
+
+    let owned = E0::V2("baz".to_owned());
+    assert_eq!(ScribeCowStr::scribe(&owned), "baz");
+    assert_eq!(ScribeString::scribe(&owned), "baz");
+    assert_eq!(TryScribeString::try_scribe(&owned), Some("baz".to_owned()));
+    assert_eq!(ScribeCowStr::scribe(&E0::<String>::V1), "foo");
+
+    #[derive(TryScribeCowStr)]
+    #[allow(dead_code)]
+    enum E1<T>
+    where
+        T: Into<String>,
+    {
+        #[enumscribe(ignore)]
+        V0,
+        #[enumscribe(other)]
+        V1 { inner: T },
+    }
+
+    assert_eq!(E1::V1 { inner: Cow::Borrowed("qux") }.try_scribe(), Some(Cow::Owned("qux".to_owned())));
+    assert_eq!(E1::<String>::V0.try_scribe(), None);
+
+    #[derive(ScribeCowStr, ScribeCasedCowStr, IntoCowStr)]
+    #[enumscribe(scribe_checked, debug_as_scribe)]
+    enum E2<T: Into<String>> {
+        #[enumscribe(str = "Foo")]
+        V0,
+        #[enumscribe(other)]
+        V1(T),
+    }
+
+    assert_eq!(E2::<&'static str>::V0.scribe_lower(), "foo");
+    assert_eq!(E2::V1("Bar").scribe_upper(), "BAR");
+    assert_eq!(E2::V1("Bar").scribe_checked(), Ok(Cow::Owned("Bar".to_owned())));
+    assert_eq!(E2::V1("Foo".to_owned()).scribe_checked(), Err(OtherShadowsNamed));
+    assert_eq!(Cow::from(&E2::V1("baz")), "baz");
+    assert_eq!(format!("{:?}", E2::V1("baz")), "baz");
+}
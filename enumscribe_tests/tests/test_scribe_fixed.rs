@@ -0,0 +1,75 @@
+use enumscribe::ScribeFixed;
+
+#[test]
+fn test_scribe_fixed() {
+    #[derive(ScribeFixed)]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "foo")]
+        V1,
+        #[enumscribe(str = "lorem ipsum")]
+        V2(),
+        #[enumscribe(other)]
+        V3(String),
+    }
+
+    // Names shorter than the width are padded with spaces
+    assert_eq!(&E0::V0.scribe_fixed::<4>(), b"V0  ");
+    assert_eq!(&E0::V1.scribe_fixed::<8>(), b"foo     ");
+    assert_eq!(&E0::V3("abc".to_owned()).scribe_fixed::<6>(), b"abc   ");
+
+    // Names exactly as long as the width are unchanged
+    assert_eq!(&E0::V1.scribe_fixed::<3>(), b"foo");
+
+    // Names longer than the width are truncated
+    assert_eq!(&E0::V2().scribe_fixed::<5>(), b"lorem");
+    assert_eq!(&E0::V3("consectetur".to_owned()).scribe_fixed::<6>(), b"consec");
+    assert_eq!(&E0::V1.scribe_fixed::<0>(), b"");
+}
+
+#[test]
+fn test_scribe_fixed_pad() {
+    #[derive(ScribeFixed)]
+    #[enumscribe(pad = '0')]
+    enum E0 {
+        #[enumscribe(str = "12")]
+        V0,
+        #[enumscribe(str = "12345")]
+        V1,
+        #[enumscribe(other)]
+        V2(String),
+    }
+
+    assert_eq!(&E0::V0.scribe_fixed::<4>(), b"1200");
+    assert_eq!(&E0::V1.scribe_fixed::<4>(), b"1234");
+    assert_eq!(&E0::V2(String::new()).scribe_fixed::<3>(), b"000");
+}
+
+#[test]
+fn test_scribe_fixed_char_boundary() {
+    #[derive(ScribeFixed)]
+    #[enumscribe(pad = '.')]
+    enum E0 {
+        #[enumscribe(str = "café")]
+        V0,
+    }
+
+    assert_eq!(&E0::V0.scribe_fixed::<6>(), "café.".as_bytes());
+    assert_eq!(&E0::V0.scribe_fixed::<5>(), "café".as_bytes());
+    // "é" is two bytes, so it is dropped rather than split
+    assert_eq!(&E0::V0.scribe_fixed::<4>(), b"caf.");
+}
+
+#[test]
+fn test_scribe_fixed_generic() {
+    #[derive(ScribeFixed)]
+    enum E0<T: AsRef<str>> {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(other)]
+        V1(T),
+    }
+
+    assert_eq!(&E0::<String>::V0.scribe_fixed::<4>(), b"foo ");
+    assert_eq!(&E0::V1("lorem ipsum").scribe_fixed::<5>(), b"lorem");
+}
@@ -0,0 +1,51 @@
+// Neither scribe trait is imported, to check that `scribe!` does not need them to be in scope.
+use std::borrow::Cow;
+
+use enumscribe::scribe;
+
+#[test]
+fn test_scribe_macro() {
+    #[derive(enumscribe::ScribeStaticStr)]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "foo")]
+        V1,
+    }
+
+    let v0: &'static str = scribe!(E0::V0);
+    assert_eq!(v0, "V0");
+    assert_eq!(scribe!(E0::V1), "foo");
+
+    #[derive(enumscribe::ScribeCowStr)]
+    enum E1 {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(other)]
+        V1(String),
+    }
+
+    let v0: Cow<'static, str> = scribe!(E1::V0);
+    assert_eq!(v0, Cow::Borrowed("foo"));
+    assert_eq!(scribe!(E1::V1("baa".to_owned())), Cow::<str>::Owned("baa".to_owned()));
+
+    // `ScribeStaticStr` takes precedence when both are implemented
+    #[derive(enumscribe::ScribeStaticStr, enumscribe::ScribeCowStr)]
+    enum E2 {
+        #[enumscribe(str = "foo")]
+        V0,
+    }
+
+    let v0: &'static str = scribe!(E2::V0);
+    assert_eq!(v0, "foo");
+
+    // The value is only borrowed
+    let e1 = E1::V1("baz".to_owned());
+    assert_eq!(scribe!(e1), "baz");
+    let e1_ref = &e1;
+    assert_eq!(scribe!(*e1_ref), "baz");
+    assert_eq!(format!("{}-{}", scribe!(E0::V1), scribe!(e1)), "foo-baz");
+
+    // The macro's helpers do not shadow names used in the expression
+    struct Wrap(E0);
+    assert_eq!(scribe!(Wrap(E0::V1).0), "foo");
+}
@@ -1,4 +1,8 @@
-use enumscribe::EnumDeserialize;
+use std::fmt::Debug;
+
+use enumscribe::{EnumDeserialize, EnumSerdeWith, EnumSerialize, Unscribe};
+use serde::de::{value::StringDeserializer, DeserializeOwned, IntoDeserializer};
+use serde::Deserialize;
 
 #[test]
 fn test_deserialize() {
@@ -23,3 +27,1300 @@ fn test_deserialize() {
     assert_eq!(serde_json::from_str::<E0>(r#""BaZ\n""#).unwrap(), E0::V2);
     assert_eq!(serde_json::from_str::<E0>(r#""\u87f9""#).unwrap(), E0::V3);
 }
+
+#[test]
+fn test_tagged_round_trip() {
+    #[derive(EnumSerialize, EnumDeserialize, Eq, PartialEq, Debug)]
+    #[enumscribe(serialize_as = "tagged")]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "baa", case_insensitive)]
+        V1,
+        #[enumscribe(other)]
+        V2(String),
+    }
+
+    assert_eq!(serde_json::to_string(&E0::V0).unwrap(), r#"{"E0":"V0"}"#);
+    assert_eq!(serde_json::to_string(&E0::V1).unwrap(), r#"{"E0":"baa"}"#);
+    assert_eq!(
+        serde_json::to_string(&E0::V2("lorem".to_owned())).unwrap(),
+        r#"{"E0":"lorem"}"#
+    );
+
+    assert_eq!(serde_json::from_str::<E0>(r#"{"E0":"V0"}"#).unwrap(), E0::V0);
+    assert_eq!(serde_json::from_str::<E0>(r#"{"E0":"BAA"}"#).unwrap(), E0::V1);
+    assert_eq!(
+        serde_json::from_str::<E0>(r#"{"E0":"lorem"}"#).unwrap(),
+        E0::V2("lorem".to_owned())
+    );
+    assert_eq!(
+        serde_json::from_str::<E0>(r#" { "E0" : "V0" } "#).unwrap(),
+        E0::V0
+    );
+
+    assert!(serde_json::from_str::<E0>(r#""V0""#).is_err());
+    assert!(serde_json::from_str::<E0>(r#"{}"#).is_err());
+    assert!(serde_json::from_str::<E0>(r#"{"E1":"V0"}"#).is_err());
+    assert!(serde_json::from_str::<E0>(r#"{"E0":"V0","E0":"V0"}"#).is_err());
+    assert!(serde_json::from_str::<E0>(r#"{"E0":0}"#).is_err());
+
+    for value in [E0::V0, E0::V1, E0::V2("ipsum".to_owned())] {
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(serde_json::from_str::<E0>(&json).unwrap(), value);
+    }
+
+    #[derive(EnumSerialize, EnumDeserialize, Eq, PartialEq, Debug)]
+    #[enumscribe(serialize_as = "string")]
+    enum E1 {
+        V0,
+    }
+
+    assert_eq!(serde_json::to_string(&E1::V0).unwrap(), r#""V0""#);
+    assert_eq!(serde_json::from_str::<E1>(r#""V0""#).unwrap(), E1::V0);
+}
+
+#[test]
+fn test_punctuated_names() {
+    #[derive(EnumSerialize, EnumDeserialize, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "a=b,c")]
+        V0,
+        #[enumscribe(str = "x, y = z", case_insensitive)]
+        V1,
+        #[enumscribe(str = ",")]
+        V2,
+    }
+
+    assert_eq!(serde_json::to_string(&E0::V0).unwrap(), r#""a=b,c""#);
+    assert_eq!(serde_json::to_string(&E0::V1).unwrap(), r#""x, y = z""#);
+    assert_eq!(serde_json::to_string(&E0::V2).unwrap(), r#"",""#);
+
+    assert_eq!(serde_json::from_str::<E0>(r#""a=b,c""#).unwrap(), E0::V0);
+    assert_eq!(serde_json::from_str::<E0>(r#""X, Y = Z""#).unwrap(), E0::V1);
+    assert_eq!(serde_json::from_str::<E0>(r#"",""#).unwrap(), E0::V2);
+    assert!(serde_json::from_str::<E0>(r#""a=b""#).is_err());
+
+    let err = serde_json::from_str::<E0>(r#""a""#).unwrap_err().to_string();
+    assert!(
+        err.starts_with("unknown variant `a`, expected one of `a=b,c`, `x, y = z`, `,`"),
+        "unexpected error message: {}",
+        err
+    );
+}
+
+#[test]
+fn test_serialize_other_with_impl() {
+    #[derive(EnumSerialize)]
+    enum E0 {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(other, serialize_with_impl)]
+        V1(serde_json::Value),
+    }
+
+    assert_eq!(serde_json::to_string(&E0::V0).unwrap(), r#""foo""#);
+    assert_eq!(
+        serde_json::to_string(&E0::V1(serde_json::json!("baa"))).unwrap(),
+        r#""baa""#
+    );
+    assert_eq!(
+        serde_json::to_string(&E0::V1(serde_json::json!(123))).unwrap(),
+        "123"
+    );
+    assert_eq!(
+        serde_json::to_string(&E0::V1(serde_json::json!({ "x": [1, 2] }))).unwrap(),
+        r#"{"x":[1,2]}"#
+    );
+
+    #[derive(EnumSerialize)]
+    #[enumscribe(serialize_as = "tagged")]
+    enum E1 {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(other, serialize_with_impl)]
+        V1 { value: serde_json::Value },
+    }
+
+    assert_eq!(serde_json::to_string(&E1::V0).unwrap(), r#"{"E1":"foo"}"#);
+    assert_eq!(
+        serde_json::to_string(&E1::V1 { value: serde_json::json!(null) }).unwrap(),
+        r#"{"E1":null}"#
+    );
+
+    #[derive(EnumSerialize)]
+    enum E2 {
+        #[enumscribe(other)]
+        V0(String),
+    }
+
+    assert_eq!(serde_json::to_string(&E2::V0("123".to_owned())).unwrap(), r#""123""#);
+}
+
+#[test]
+fn test_serialize_other_scribe_as() {
+    #[derive(EnumSerialize, EnumDeserialize, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(other, scribe_as = "<redacted>")]
+        V1(String),
+    }
+
+    let other = serde_json::from_str::<E0>(r#""hunter2""#).unwrap();
+    assert_eq!(other, E0::V1("hunter2".to_owned()));
+    assert_eq!(serde_json::to_string(&other).unwrap(), r#""<redacted>""#);
+    assert_eq!(serde_json::to_string(&E0::V0).unwrap(), r#""foo""#);
+
+    #[allow(dead_code)]
+    #[derive(EnumSerialize)]
+    #[enumscribe(serialize_as = "tagged")]
+    enum E1 {
+        #[enumscribe(other, scribe_as = "<redacted>")]
+        V0 { value: String },
+    }
+
+    assert_eq!(
+        serde_json::to_string(&E1::V0 { value: "hunter2".to_owned() }).unwrap(),
+        r#"{"E1":"<redacted>"}"#
+    );
+}
+
+#[test]
+fn test_serialize_other_transform() {
+    #[derive(EnumSerialize, EnumDeserialize, Eq, PartialEq, Debug)]
+    #[enumscribe(rename_all = "snake_case")]
+    enum E0 {
+        LondonCity,
+        #[enumscribe(other, serialize_transform)]
+        Other(String),
+    }
+
+    assert_eq!(serde_json::to_string(&E0::LondonCity).unwrap(), r#""london_city""#);
+    assert_eq!(
+        serde_json::to_string(&E0::Other("LondonStansted".to_owned())).unwrap(),
+        r#""london_stansted""#
+    );
+    assert_eq!(
+        serde_json::to_string(&E0::Other("EAST_MIDLANDS".to_owned())).unwrap(),
+        r#""east_midlands""#
+    );
+
+    // Only serializing is affected, so the field keeps its original case when deserialized
+    let e0 = serde_json::from_str::<E0>(r#""LondonStansted""#).unwrap();
+    assert_eq!(e0, E0::Other("LondonStansted".to_owned()));
+    assert_eq!(serde_json::to_string(&e0).unwrap(), r#""london_stansted""#);
+
+    #[derive(EnumSerialize)]
+    #[enumscribe(rename_all = "SCREAMING-KEBAB-CASE", serialize_as = "tagged")]
+    enum E1 {
+        #[enumscribe(str = "LHR")]
+        Heathrow,
+        #[enumscribe(other, serialize_transform, rename = "PascalCase")]
+        Other { code: Box<str> },
+    }
+
+    assert_eq!(serde_json::to_string(&E1::Heathrow).unwrap(), r#"{"E1":"LHR"}"#);
+    assert_eq!(
+        serde_json::to_string(&E1::Other { code: "london_city".into() }).unwrap(),
+        r#"{"E1":"LondonCity"}"#
+    );
+
+    #[derive(EnumSerialize)]
+    #[enumscribe(rename_all = "kebab-case")]
+    enum E2 {
+        EastMidlands,
+        #[enumscribe(other)]
+        Other(String),
+    }
+
+    assert_eq!(serde_json::to_string(&E2::EastMidlands).unwrap(), r#""east-midlands""#);
+    assert_eq!(
+        serde_json::to_string(&E2::Other("LondonCity".to_owned())).unwrap(),
+        r#""LondonCity""#
+    );
+}
+
+#[test]
+fn test_serde_other_optional_field() {
+    #[derive(EnumSerialize, EnumDeserialize, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(other, optional_field, placeholder = "?")]
+        V1(Option<String>),
+    }
+
+    assert_eq!(serde_json::from_str::<E0>(r#""foo""#).unwrap(), E0::V0);
+    assert_eq!(serde_json::from_str::<E0>(r#""bar""#).unwrap(), E0::V1(Some("bar".to_owned())));
+    assert_eq!(serde_json::from_str::<E0>(r#""""#).unwrap(), E0::V1(None));
+
+    assert_eq!(serde_json::to_string(&E0::V1(Some("bar".to_owned()))).unwrap(), r#""bar""#);
+    assert_eq!(serde_json::to_string(&E0::V1(None)).unwrap(), r#""?""#);
+
+    #[derive(EnumSerialize, Eq, PartialEq, Debug)]
+    enum E1 {
+        #[enumscribe(other, optional_field, serialize_with_impl)]
+        V0(Option<String>),
+    }
+
+    assert_eq!(serde_json::to_string(&E1::V0(Some("bar".to_owned()))).unwrap(), r#""bar""#);
+    assert_eq!(serde_json::to_string(&E1::V0(None)).unwrap(), "null");
+}
+
+#[test]
+fn test_deserialize_generic() {
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    enum E0<S>
+    where
+        S: for<'a> From<&'a str>,
+    {
+        #[enumscribe(str = "foo", case_insensitive)]
+        V0,
+        #[enumscribe(other)]
+        V1(S),
+    }
+
+    assert_eq!(serde_json::from_str::<E0<String>>(r#""FOO""#).unwrap(), E0::V0);
+    assert_eq!(
+        serde_json::from_str::<E0<String>>(r#""baa""#).unwrap(),
+        E0::V1("baa".to_owned())
+    );
+    assert_eq!(
+        serde_json::from_reader::<_, E0<Box<str>>>(&br#""baa""#[..]).unwrap(),
+        E0::V1("baa".into())
+    );
+
+    #[allow(dead_code)]
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    #[enumscribe(serialize_as = "tagged")]
+    enum E1<T, const N: usize> {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(str = "baa")]
+        V1,
+        #[enumscribe(ignore)]
+        V2(T, [u8; N]),
+    }
+
+    assert_eq!(serde_json::from_str::<E1<bool, 4>>(r#"{"E1":"foo"}"#).unwrap(), E1::V0);
+    assert_eq!(serde_json::from_str::<E1<(), 0>>(r#"{"E1":"baa"}"#).unwrap(), E1::V1);
+    assert!(serde_json::from_str::<E1<(), 0>>(r#"{"E1":"V2"}"#).is_err());
+}
+
+#[test]
+fn test_serialize_generic() {
+    #[derive(EnumSerialize, EnumDeserialize, Eq, PartialEq, Debug)]
+    enum E0<S>
+    where
+        S: for<'a> From<&'a str> + std::ops::Deref<Target = str>,
+    {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(other)]
+        V1(S),
+    }
+
+    assert_eq!(serde_json::to_string(&E0::<String>::V0).unwrap(), r#""foo""#);
+    assert_eq!(serde_json::to_string(&E0::V1("baa".to_owned())).unwrap(), r#""baa""#);
+    assert_eq!(serde_json::to_string(&E0::<Box<str>>::V1("baa".into())).unwrap(), r#""baa""#);
+
+    let value = E0::<String>::V1("baz".to_owned());
+    let serialized = serde_json::to_string(&value).unwrap();
+    assert_eq!(serde_json::from_str::<E0<String>>(&serialized).unwrap(), value);
+
+    #[allow(dead_code)]
+    #[derive(EnumSerialize)]
+    #[enumscribe(serialize_as = "tagged")]
+    enum E1<T, const N: usize> {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(ignore)]
+        V1(T, [u8; N]),
+    }
+
+    assert_eq!(serde_json::to_string(&E1::<bool, 4>::V0).unwrap(), r#"{"E1":"foo"}"#);
+    assert!(serde_json::to_string(&E1::V1((), [0; 2])).is_err());
+}
+
+#[test]
+fn test_serialize_ignore_placeholder() {
+    #[derive(EnumSerialize, EnumDeserialize, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(ignore, placeholder = "N/A")]
+        V1(i32),
+        #[enumscribe(ignore)]
+        V2,
+    }
+
+    assert_eq!(serde_json::to_string(&E0::V1(5)).unwrap(), r#""N/A""#);
+    assert!(serde_json::to_string(&E0::V2).is_err());
+    assert!(serde_json::from_str::<E0>(r#""N/A""#).is_err());
+}
+
+#[test]
+fn test_deserialize_rename_all_case_insensitive_expansion() {
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    #[enumscribe(rename_all = "SCREAMING_SNAKE_CASE", case_insensitive)]
+    enum E0 {
+        Straße,
+        Weg,
+    }
+
+    // These are longer than the name "STRASSE" before being converted to uppercase, so they must
+    // not be rejected for being too long to match it.
+    for &s in ["STRASSE", "straße", "ſtraße", "ſtraſſe"].iter() {
+        assert_deserializes_in_all_formats(s, Some(E0::Straße));
+    }
+
+    assert_deserializes_in_all_formats::<E0>("STRAẞE", None);
+    assert_deserializes_in_all_formats::<E0>("ſtraſſee", None);
+}
+
+/// Used to check whether a type implements `Serialize` and `Deserialize` without failing to
+/// compile if it does not. The inherent methods are only callable if the bounds are satisfied;
+/// otherwise, the methods of `NotImplemented` are used instead.
+struct ImplProbe<T>(std::marker::PhantomData<T>);
+
+// Unused if every probed type implements both traits, e.g. with the `optional_serde` feature.
+#[allow(dead_code)]
+trait NotImplemented {
+    fn implements_serialize(&self) -> bool {
+        false
+    }
+
+    fn implements_deserialize(&self) -> bool {
+        false
+    }
+}
+
+impl<T> NotImplemented for ImplProbe<T> {}
+
+impl<T: serde::Serialize> ImplProbe<T> {
+    fn implements_serialize(&self) -> bool {
+        true
+    }
+}
+
+impl<T: DeserializeOwned> ImplProbe<T> {
+    fn implements_deserialize(&self) -> bool {
+        true
+    }
+}
+
+#[test]
+fn test_serde_optional() {
+    #[derive(EnumSerialize, EnumDeserialize, Eq, PartialEq, Debug)]
+    #[enumscribe(serde_optional = "optional_serde")]
+    #[allow(dead_code)]
+    enum E0 {
+        #[enumscribe(str = "foo")]
+        V0,
+    }
+
+    let probe = ImplProbe::<E0>(std::marker::PhantomData);
+    assert_eq!(probe.implements_serialize(), cfg!(feature = "optional_serde"));
+    assert_eq!(probe.implements_deserialize(), cfg!(feature = "optional_serde"));
+
+    #[derive(EnumSerialize, EnumDeserialize, Eq, PartialEq, Debug)]
+    enum E1 {
+        #[enumscribe(str = "foo")]
+        V0,
+    }
+
+    let probe = ImplProbe::<E1>(std::marker::PhantomData);
+    assert!(probe.implements_serialize());
+    assert!(probe.implements_deserialize());
+}
+
+#[test]
+fn test_deserialize_ci_overflow() {
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    #[enumscribe(ci_overflow = "error")]
+    enum E0 {
+        #[enumscribe(str = "foo", case_insensitive)]
+        V0,
+        #[enumscribe(str = "lorem ipsum")]
+        V1,
+        #[enumscribe(other)]
+        V2(String),
+    }
+
+    assert_eq!(serde_json::from_str::<E0>(r#""FOO""#).unwrap(), E0::V0);
+    assert_eq!(serde_json::from_str::<E0>(r#""lorem ipsum""#).unwrap(), E0::V1);
+    assert_eq!(
+        serde_json::from_str::<E0>(r#""baa""#).unwrap(),
+        E0::V2("baa".to_owned())
+    );
+    assert!(serde_json::from_str::<E0>(r#""lorem ipsum!""#).is_err());
+}
+
+#[test]
+fn test_deserialize_surrogate_pair_escape() {
+    // JSON can only escape characters outside the Basic Multilingual Plane as a surrogate pair,
+    // which decodes to a single 4-byte character, so the string is no longer than the name.
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    #[enumscribe(ci_overflow = "error")]
+    enum E0 {
+        #[enumscribe(str = "\u{1f600}")]
+        V0,
+        #[enumscribe(str = "\u{1f600}x", case_insensitive)]
+        V1,
+        #[enumscribe(other)]
+        V2(String),
+    }
+
+    assert_eq!(serde_json::from_str::<E0>(r#""\uD83D\uDE00""#).unwrap(), E0::V0);
+    assert_eq!(serde_json::from_str::<E0>(r#""\ud83d\ude00""#).unwrap(), E0::V0);
+    assert_eq!(serde_json::from_str::<E0>(r#""\uD83D\uDE00X""#).unwrap(), E0::V1);
+    assert_eq!(serde_json::from_str::<E0>("\"\u{1f600}X\"").unwrap(), E0::V1);
+    assert_eq!(
+        serde_json::from_str::<E0>(r#""\uD83D\uDE01""#).unwrap(),
+        E0::V2("\u{1f601}".to_owned())
+    );
+    assert_eq!(
+        serde_json::from_slice::<E0>(br#""\uD83D\uDE00""#).unwrap(),
+        E0::V0
+    );
+
+    // A lone surrogate is not a valid character, so it is rejected by serde_json itself
+    assert!(serde_json::from_str::<E0>(r#""\uD83D""#).is_err());
+}
+
+#[test]
+fn test_deserialize_deny_prefix() {
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "__foo")]
+        V0,
+        #[enumscribe(other, deny_prefix = "__")]
+        V1(String),
+    }
+
+    assert_eq!(serde_json::from_str::<E0>(r#""__foo""#).unwrap(), E0::V0);
+    assert_eq!(
+        serde_json::from_str::<E0>(r#""baa""#).unwrap(),
+        E0::V1("baa".to_owned())
+    );
+    assert_eq!(
+        serde_json::from_str::<E0>(r#""_baa""#).unwrap(),
+        E0::V1("_baa".to_owned())
+    );
+
+    let err = serde_json::from_str::<E0>(r#""__baa""#).unwrap_err();
+    assert!(err.to_string().contains(r#"a string not starting with "__""#));
+    assert!(serde_json::from_reader::<_, E0>(&br#""__baa""#[..]).is_err());
+}
+
+#[test]
+fn test_deserialize_empty_is_other() {
+    #[derive(EnumDeserialize, Unscribe, Eq, PartialEq, Debug)]
+    #[enumscribe(empty_is_other)]
+    enum E0 {
+        #[enumscribe(str = "")]
+        V0,
+        #[enumscribe(str = "foo", case_insensitive)]
+        V1,
+        #[enumscribe(other)]
+        V2(String),
+    }
+
+    assert_eq!(serde_json::from_str::<E0>(r#""""#).unwrap(), E0::V2("".to_owned()));
+    assert_eq!(serde_json::from_str::<E0>(r#""FOO""#).unwrap(), E0::V1);
+    assert_eq!(
+        serde_json::from_str::<E0>(r#"" ""#).unwrap(),
+        E0::V2(" ".to_owned())
+    );
+    // Only deserialization is affected.
+    assert_eq!(E0::unscribe(""), E0::V0);
+
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    #[enumscribe(empty_is_other, serialize_as = "tagged")]
+    enum E1 {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(other)]
+        V1(String),
+    }
+
+    assert_eq!(serde_json::from_str::<E1>(r#"{"E1":""}"#).unwrap(), E1::V1("".to_owned()));
+    assert_eq!(serde_json::from_str::<E1>(r#"{"E1":"foo"}"#).unwrap(), E1::V0);
+
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    #[enumscribe(empty_is_other)]
+    enum E2 {
+        #[enumscribe(str = "")]
+        V0,
+        #[enumscribe(str = "foo")]
+        V1,
+    }
+
+    assert!(serde_json::from_str::<E2>(r#""""#).is_err());
+    assert_eq!(serde_json::from_str::<E2>(r#""foo""#).unwrap(), E2::V1);
+
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    #[enumscribe(empty_is_other, de_error = "unsupported code")]
+    enum E3 {
+        #[enumscribe(str = "foo")]
+        V0,
+    }
+
+    let err = serde_json::from_str::<E3>(r#""""#).unwrap_err().to_string();
+    assert!(err.contains("unsupported code: ``"), "unexpected error: {}", err);
+
+    // Without `empty_is_other`, an empty string matches a variant named `""`.
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    enum E4 {
+        #[enumscribe(str = "")]
+        V0,
+        #[enumscribe(other)]
+        V1(String),
+    }
+
+    assert_eq!(serde_json::from_str::<E4>(r#""""#).unwrap(), E4::V0);
+}
+
+#[test]
+fn test_deserialize_de_error() {
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    #[enumscribe(de_error = "unsupported airport code")]
+    enum E0 {
+        #[enumscribe(str = "LHR", case_insensitive)]
+        Heathrow,
+        #[enumscribe(str = "LGW")]
+        Gatwick,
+    }
+
+    assert_eq!(serde_json::from_str::<E0>(r#""lhr""#).unwrap(), E0::Heathrow);
+    assert_eq!(serde_json::from_str::<E0>(r#""LGW""#).unwrap(), E0::Gatwick);
+
+    let err = serde_json::from_str::<E0>(r#""STN""#).unwrap_err().to_string();
+    assert!(
+        err.starts_with("unsupported airport code: `STN`"),
+        "unexpected error message: {}",
+        err
+    );
+
+    let err = serde_json::from_reader::<_, E0>(&br#""lgw""#[..]).unwrap_err().to_string();
+    assert!(
+        err.starts_with("unsupported airport code: `lgw`"),
+        "unexpected error message: {}",
+        err
+    );
+
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    enum E1 {
+        #[enumscribe(str = "LHR")]
+        Heathrow,
+    }
+
+    let err = serde_json::from_str::<E1>(r#""STN""#).unwrap_err().to_string();
+    assert!(
+        err.starts_with("unknown variant `STN`, expected `LHR`"),
+        "unexpected error message: {}",
+        err
+    );
+}
+
+#[test]
+fn test_deserialize_sort_expected() {
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    #[enumscribe(sort_expected)]
+    enum E0 {
+        #[enumscribe(str = "LTN")]
+        Luton,
+        #[enumscribe(str = "LHR")]
+        Heathrow,
+        #[enumscribe(str = "BHX")]
+        Birmingham,
+        #[enumscribe(str = "LGW")]
+        Gatwick,
+        #[enumscribe(ignore)]
+        #[allow(dead_code)]
+        Secret,
+    }
+
+    assert_eq!(serde_json::from_str::<E0>(r#""LTN""#).unwrap(), E0::Luton);
+
+    let err = serde_json::from_str::<E0>(r#""STN""#).unwrap_err().to_string();
+    assert!(
+        err.starts_with("unknown variant `STN`, expected one of `BHX`, `LGW`, `LHR`, `LTN`"),
+        "unexpected error message: {}",
+        err
+    );
+
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    enum E1 {
+        #[enumscribe(str = "LTN")]
+        Luton,
+        #[enumscribe(str = "BHX")]
+        Birmingham,
+    }
+
+    let err = serde_json::from_str::<E1>(r#""STN""#).unwrap_err().to_string();
+    assert!(
+        err.starts_with("unknown variant `STN`, expected `LTN` or `BHX`"),
+        "unexpected error message: {}",
+        err
+    );
+}
+
+#[test]
+fn test_deserialize_other_verbatim() {
+    // Normalization only applies to the string used for matching, so the `other` variant always
+    // captures the unmatched string exactly as it was given, including surrounding whitespace.
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    #[enumscribe(normalize_newlines, nfc)]
+    enum E0 {
+        #[enumscribe(str = "caf\u{e9}\n", case_insensitive)]
+        V0,
+        #[enumscribe(other)]
+        V1(String),
+    }
+
+    assert_eq!(serde_json::from_str::<E0>(r#""CAFE\u0301\r\n""#).unwrap(), E0::V0);
+    assert_eq!(
+        serde_json::from_str::<E0>(r#""  cafe\u0301\r\n""#).unwrap(),
+        E0::V1("  cafe\u{301}\r\n".to_owned())
+    );
+    assert_eq!(
+        serde_json::from_str::<E0>(r#""\t{\"key\": [1, 2]}\r\n ""#).unwrap(),
+        E0::V1("\t{\"key\": [1, 2]}\r\n ".to_owned())
+    );
+    assert_eq!(
+        serde_json::from_reader::<_, E0>(&br#"" \r\n ""#[..]).unwrap(),
+        E0::V1(" \r\n ".to_owned())
+    );
+}
+
+#[test]
+fn test_deserialize_serde_native() {
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    #[enumscribe(serde_native)]
+    enum E0 {
+        #[enumscribe(str = "LHR", case_insensitive)]
+        Heathrow,
+        #[enumscribe(str = "LGW")]
+        Gatwick,
+        #[enumscribe(other)]
+        Other(String),
+    }
+
+    assert_eq!(serde_json::from_str::<E0>(r#"{"LHR": null}"#).unwrap(), E0::Heathrow);
+    assert_eq!(serde_json::from_str::<E0>(r#"{"lhr":null}"#).unwrap(), E0::Heathrow);
+    assert_eq!(serde_json::from_str::<E0>(r#"{"LGW": null}"#).unwrap(), E0::Gatwick);
+    assert_eq!(
+        serde_json::from_str::<E0>(r#"{"STN": null}"#).unwrap(),
+        E0::Other("STN".to_owned())
+    );
+
+    // serde_json also represents unit variants as bare strings
+    assert_eq!(serde_json::from_str::<E0>(r#""LGW""#).unwrap(), E0::Gatwick);
+    assert_eq!(serde_json::from_str::<E0>(r#""STN""#).unwrap(), E0::Other("STN".to_owned()));
+
+    // Only unit variants are accepted
+    assert!(serde_json::from_str::<E0>(r#"{"LHR": 1}"#).is_err());
+    assert!(serde_json::from_str::<E0>(r#"{"LHR": []}"#).is_err());
+    assert!(serde_json::from_str::<E0>(r#"{"LHR": null, "LGW": null}"#).is_err());
+    assert!(serde_json::from_str::<E0>(r#"{}"#).is_err());
+    assert!(serde_json::from_str::<E0>("1").is_err());
+
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    #[enumscribe(serde_native)]
+    enum E1 {
+        #[enumscribe(str = "LHR")]
+        Heathrow,
+        #[enumscribe(str = "LGW")]
+        Gatwick,
+    }
+
+    assert_eq!(serde_json::from_str::<E1>(r#"{"LHR": null}"#).unwrap(), E1::Heathrow);
+
+    let err = serde_json::from_str::<E1>(r#"{"STN": null}"#).unwrap_err().to_string();
+    assert!(
+        err.starts_with("unknown variant `STN`, expected `LHR` or `LGW`"),
+        "unexpected error message: {}",
+        err
+    );
+}
+
+#[test]
+fn test_deserialize_serde_native_interop() {
+    #[derive(serde::Serialize)]
+    enum Native {
+        #[serde(rename = "LHR")]
+        Heathrow,
+        #[serde(rename = "LGW")]
+        Gatwick,
+    }
+
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    #[enumscribe(serde_native)]
+    enum E0 {
+        #[enumscribe(str = "LHR")]
+        Heathrow,
+        #[enumscribe(str = "LGW")]
+        Gatwick,
+    }
+
+    #[derive(serde::Serialize)]
+    struct NativeFlight {
+        from: Native,
+        to: Native,
+    }
+
+    #[derive(Deserialize, Eq, PartialEq, Debug)]
+    struct Flight {
+        from: E0,
+        to: E0,
+    }
+
+    let json = serde_json::to_string(&NativeFlight {
+        from: Native::Heathrow,
+        to: Native::Gatwick,
+    })
+    .unwrap();
+
+    assert_eq!(
+        serde_json::from_str::<Flight>(&json).unwrap(),
+        Flight { from: E0::Heathrow, to: E0::Gatwick }
+    );
+
+    let value = serde_json::json!({ "from": { "LGW": null }, "to": "LHR" });
+    assert_eq!(
+        serde_json::from_value::<Flight>(value).unwrap(),
+        Flight { from: E0::Gatwick, to: E0::Heathrow }
+    );
+}
+
+#[test]
+fn test_deserialize_ignore_document_str() {
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "LHR")]
+        Heathrow,
+        #[enumscribe(ignore, document_str = "LCY")]
+        #[allow(dead_code)]
+        LondonCity,
+        #[enumscribe(str = "LGW")]
+        Gatwick,
+    }
+
+    assert_eq!(serde_json::from_str::<E0>(r#""LHR""#).unwrap(), E0::Heathrow);
+    assert_eq!(serde_json::from_str::<E0>(r#""LGW""#).unwrap(), E0::Gatwick);
+
+    let err = serde_json::from_str::<E0>(r#""STN""#).unwrap_err().to_string();
+    assert!(
+        err.starts_with("unknown variant `STN`, expected one of `LHR`, `LCY`, `LGW`"),
+        "unexpected error message: {}",
+        err
+    );
+
+    // The documented string is listed, but never matches
+    let err = serde_json::from_str::<E0>(r#""LCY""#).unwrap_err().to_string();
+    assert!(
+        err.starts_with("unknown variant `LCY`, expected one of `LHR`, `LCY`, `LGW`"),
+        "unexpected error message: {}",
+        err
+    );
+
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    #[enumscribe(sort_expected)]
+    enum E1 {
+        #[enumscribe(str = "LTN")]
+        Luton,
+        #[enumscribe(ignore, document_str = "BHX")]
+        #[allow(dead_code)]
+        Birmingham,
+        #[enumscribe(ignore)]
+        #[allow(dead_code)]
+        Secret,
+    }
+
+    let err = serde_json::from_str::<E1>(r#""BHX""#).unwrap_err().to_string();
+    assert!(
+        err.starts_with("unknown variant `BHX`, expected `BHX` or `LTN`"),
+        "unexpected error message: {}",
+        err
+    );
+}
+
+#[test]
+fn test_deserialize_accept_ident() {
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "LHR", accept_ident)]
+        Heathrow,
+        #[enumscribe(str = "LGW")]
+        Gatwick,
+    }
+
+    assert_eq!(serde_json::from_str::<E0>(r#""LHR""#).unwrap(), E0::Heathrow);
+    assert_eq!(serde_json::from_str::<E0>(r#""Heathrow""#).unwrap(), E0::Heathrow);
+    assert_eq!(serde_json::from_str::<E0>(r#""He\u0061throw""#).unwrap(), E0::Heathrow);
+    assert!(serde_json::from_str::<E0>(r#""Gatwick""#).is_err());
+}
+
+#[test]
+fn test_map_key_round_trip() {
+    use std::collections::BTreeMap;
+
+    #[derive(EnumSerialize, EnumDeserialize, Eq, PartialEq, Ord, PartialOrd, Debug)]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "baa", case_insensitive)]
+        V1,
+        #[enumscribe(other)]
+        V2(String),
+    }
+
+    let mut map = BTreeMap::new();
+    map.insert(E0::V0, 1);
+    map.insert(E0::V1, 2);
+    map.insert(E0::V2("a key much longer than any variant name".to_owned()), 3);
+
+    let json = serde_json::to_string(&map).unwrap();
+    assert_eq!(json, r#"{"V0":1,"baa":2,"a key much longer than any variant name":3}"#);
+
+    assert_eq!(serde_json::from_str::<BTreeMap<E0, i32>>(&json).unwrap(), map);
+    assert_eq!(serde_json::from_reader::<_, BTreeMap<E0, i32>>(json.as_bytes()).unwrap(), map);
+
+    let escaped = r#"{"V0":1,"BAA":2,"a key much longer than any variant name":3}"#;
+    assert_eq!(serde_json::from_str::<BTreeMap<E0, i32>>(escaped).unwrap(), map);
+
+    #[derive(EnumSerialize, EnumDeserialize, Eq, PartialEq, Ord, PartialOrd, Debug)]
+    enum E1 {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(str = "bar")]
+        V1,
+    }
+
+    let mut map = BTreeMap::new();
+    map.insert(E1::V0, E0::V0);
+    map.insert(E1::V1, E0::V2("lorem".to_owned()));
+
+    let json = serde_json::to_string(&map).unwrap();
+    assert_eq!(json, r#"{"foo":"V0","bar":"lorem"}"#);
+    assert_eq!(serde_json::from_reader::<_, BTreeMap<E1, E0>>(json.as_bytes()).unwrap(), map);
+    assert!(serde_json::from_str::<BTreeMap<E1, E0>>(r#"{"baz":"V0"}"#).is_err());
+    assert!(serde_json::from_reader::<_, BTreeMap<E1, E0>>(&br#"{"a long unknown key":"V0"}"#[..]).is_err());
+}
+
+#[test]
+fn test_transparent_wrapper_round_trip() {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(EnumSerialize, EnumDeserialize, Eq, PartialEq, Debug)]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "baa", case_insensitive)]
+        V1,
+        #[enumscribe(other)]
+        V2(String),
+    }
+
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug)]
+    #[serde(transparent)]
+    struct W0(E0);
+
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug)]
+    #[serde(transparent)]
+    struct W1 {
+        inner: W0,
+    }
+
+    #[derive(EnumSerialize, EnumDeserialize, Eq, PartialEq, Debug)]
+    #[enumscribe(serialize_as = "tagged")]
+    enum E1 {
+        #[enumscribe(str = "foo", case_insensitive)]
+        V0,
+        V1,
+    }
+
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug)]
+    #[serde(transparent)]
+    struct W2(E1);
+
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug)]
+    struct S {
+        a: W0,
+        b: Vec<W1>,
+        c: W2,
+    }
+
+    assert_eq!(serde_json::to_string(&W0(E0::V1)).unwrap(), r#""baa""#);
+    assert_eq!(serde_json::from_str::<W0>(r#""BAA""#).unwrap(), W0(E0::V1));
+    assert_eq!(serde_json::from_reader::<_, W0>(&br#""BAA""#[..]).unwrap(), W0(E0::V1));
+    assert_eq!(
+        serde_json::from_str::<W1>(r#""lorem""#).unwrap(),
+        W1 { inner: W0(E0::V2("lorem".to_owned())) }
+    );
+    assert_eq!(serde_json::to_string(&W2(E1::V0)).unwrap(), r#"{"E1":"foo"}"#);
+    assert_eq!(serde_json::from_str::<W2>(r#"{"E1":"FOO"}"#).unwrap(), W2(E1::V0));
+    assert!(serde_json::from_str::<W0>(r#"0"#).is_err());
+    assert!(serde_json::from_str::<W2>(r#""foo""#).is_err());
+
+    let value = S {
+        a: W0(E0::V0),
+        b: vec![
+            W1 { inner: W0(E0::V1) },
+            W1 { inner: W0(E0::V2("a string much longer than any variant name".to_owned())) },
+        ],
+        c: W2(E1::V1),
+    };
+
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(
+        json,
+        r#"{"a":"V0","b":["baa","a string much longer than any variant name"],"c":{"E1":"V1"}}"#
+    );
+    assert_eq!(serde_json::from_str::<S>(&json).unwrap(), value);
+    assert_eq!(serde_json::from_reader::<_, S>(json.as_bytes()).unwrap(), value);
+
+    // Deserializing from a `serde_json::Value` passes owned strings to the visitor rather than
+    // borrowed ones.
+    let json_value = serde_json::to_value(&value).unwrap();
+    assert_eq!(serde_json::from_value::<S>(json_value).unwrap(), value);
+}
+
+/// Deserializes `s` as a string using several formats, which variously pass it to the visitor as
+/// a borrowed string, a transient string which cannot be borrowed, or an owned `String`, and checks
+/// that they all produce `expected`.
+fn assert_deserializes_in_all_formats<T>(s: &str, expected: Option<T>)
+where
+    T: DeserializeOwned + PartialEq + Debug,
+{
+    let json = serde_json::to_string(s).unwrap();
+    let json_flattened = format!(r#"{{"value":{}}}"#, json);
+    let bincode_bytes = bincode::serialize(s).unwrap();
+    let owned_deserializer: StringDeserializer<serde::de::value::Error> =
+        s.to_owned().into_deserializer();
+
+    #[derive(Deserialize)]
+    struct Flattened<T> {
+        #[serde(flatten)]
+        inner: Inner<T>,
+    }
+
+    #[derive(Deserialize)]
+    struct Inner<T> {
+        value: T,
+    }
+
+    let results = [
+        ("serde_json::from_str", serde_json::from_str::<T>(&json).ok()),
+        ("serde_json::from_reader", serde_json::from_reader::<_, T>(json.as_bytes()).ok()),
+        (
+            "serde_json::from_str flattened",
+            serde_json::from_str::<Flattened<T>>(&json_flattened).ok().map(|f| f.inner.value),
+        ),
+        (
+            "serde_json::from_reader flattened",
+            serde_json::from_reader::<_, Flattened<T>>(json_flattened.as_bytes())
+                .ok()
+                .map(|f| f.inner.value),
+        ),
+        ("bincode::deserialize", bincode::deserialize::<T>(&bincode_bytes).ok()),
+        ("bincode::deserialize_from", bincode::deserialize_from::<_, T>(&*bincode_bytes).ok()),
+        ("StringDeserializer", T::deserialize(owned_deserializer).ok()),
+    ];
+
+    for (format, result) in results.iter() {
+        assert_eq!(result, &expected, "{:?} using {}", s, format);
+    }
+}
+
+#[test]
+fn test_deserialize_formats() {
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "baa", case_insensitive)]
+        V1,
+        #[enumscribe(str = "蟹")]
+        V2,
+    }
+
+    assert_deserializes_in_all_formats("V0", Some(E0::V0));
+    assert_deserializes_in_all_formats("baa", Some(E0::V1));
+    assert_deserializes_in_all_formats("BaA", Some(E0::V1));
+    assert_deserializes_in_all_formats("蟹", Some(E0::V2));
+    assert_deserializes_in_all_formats::<E0>("v0", None);
+    assert_deserializes_in_all_formats::<E0>("", None);
+    assert_deserializes_in_all_formats::<E0>("a string much longer than any name", None);
+
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    enum E1 {
+        #[enumscribe(str = "baa", case_insensitive)]
+        V0,
+        #[enumscribe(other)]
+        V1(String),
+    }
+
+    assert_deserializes_in_all_formats("BAA", Some(E1::V0));
+    assert_deserializes_in_all_formats("baz", Some(E1::V1("baz".to_owned())));
+    assert_deserializes_in_all_formats("", Some(E1::V1("".to_owned())));
+    assert_deserializes_in_all_formats(
+        "a string much longer than any name",
+        Some(E1::V1("a string much longer than any name".to_owned())),
+    );
+}
+
+// `EnumSerdeWith` generates a module alongside the enum, so the enums cannot be declared inside
+// the test function.
+#[derive(EnumSerdeWith, Eq, PartialEq, Debug)]
+#[enumscribe(rename_all = "snake_case")]
+enum TrafficLight {
+    Red,
+    #[enumscribe(case_insensitive)]
+    Amber,
+    Green,
+    #[enumscribe(other)]
+    Other(String),
+}
+
+#[derive(EnumSerdeWith, Eq, PartialEq, Debug)]
+#[enumscribe(serde_with = "tagged_serde", serialize_as = "tagged")]
+enum TaggedLight {
+    #[enumscribe(str = "red")]
+    Red,
+    #[enumscribe(str = "green")]
+    Green,
+}
+
+#[test]
+fn test_serde_with() {
+    use serde::Serialize;
+
+    #[derive(Serialize, Deserialize, Eq, PartialEq, Debug)]
+    struct Junction {
+        #[serde(with = "traffic_light_serde")]
+        north: TrafficLight,
+        #[serde(with = "traffic_light_serde::option", default)]
+        south: Option<TrafficLight>,
+        #[serde(with = "traffic_light_serde::option", default)]
+        east: Option<TrafficLight>,
+        #[serde(with = "tagged_serde")]
+        west: TaggedLight,
+    }
+
+    let junction = Junction {
+        north: TrafficLight::Amber,
+        south: Some(TrafficLight::Other("flashing".to_owned())),
+        east: None,
+        west: TaggedLight::Green,
+    };
+
+    let json = serde_json::to_string(&junction).unwrap();
+    assert_eq!(
+        json,
+        r#"{"north":"amber","south":"flashing","east":null,"west":{"TaggedLight":"green"}}"#
+    );
+    assert_eq!(serde_json::from_str::<Junction>(&json).unwrap(), junction);
+
+    assert_eq!(
+        serde_json::from_str::<Junction>(r#"{"north":"AMBER","west":{"TaggedLight":"red"}}"#).unwrap(),
+        Junction {
+            north: TrafficLight::Amber,
+            south: None,
+            east: None,
+            west: TaggedLight::Red,
+        }
+    );
+
+    assert!(serde_json::from_str::<Junction>(r#"{"north":"red","west":"red"}"#).is_err());
+    assert!(serde_json::from_str::<Junction>(r#"{"north":1,"west":{"TaggedLight":"red"}}"#).is_err());
+    assert!(
+        serde_json::from_str::<Junction>(r#"{"north":"red","west":{"TaggedLight":"blue"}}"#).is_err()
+    );
+
+    let mut buf = Vec::new();
+    traffic_light_serde::serialize(&TrafficLight::Green, &mut serde_json::Serializer::new(&mut buf))
+        .unwrap();
+    assert_eq!(buf, br#""green""#);
+    assert_eq!(
+        traffic_light_serde::deserialize(&mut serde_json::Deserializer::from_str(r#""red""#)).unwrap(),
+        TrafficLight::Red
+    );
+}
+
+#[test]
+fn test_deserialize_flatten_borrowed() {
+    use serde::de::value::{BorrowedStrDeserializer, Error as ValueError, MapDeserializer};
+    use std::borrow::Cow;
+
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    enum Airport {
+        #[enumscribe(str = "LHR", case_insensitive)]
+        Heathrow,
+        #[enumscribe(str = "LGW")]
+        Gatwick,
+    }
+
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    enum Runway {
+        #[enumscribe(str = "27L")]
+        TwentySevenLeft,
+        #[enumscribe(other)]
+        Other(String),
+    }
+
+    #[derive(Deserialize, Eq, PartialEq, Debug)]
+    struct Outer<'a> {
+        #[serde(borrow)]
+        flight: Cow<'a, str>,
+        #[serde(flatten, borrow)]
+        middle: Middle<'a>,
+    }
+
+    #[derive(Deserialize, Eq, PartialEq, Debug)]
+    struct Middle<'a> {
+        #[serde(borrow)]
+        gate: Cow<'a, str>,
+        #[serde(flatten)]
+        inner: Inner,
+    }
+
+    #[derive(Deserialize, Eq, PartialEq, Debug)]
+    struct Inner {
+        airport: Airport,
+        runway: Runway,
+    }
+
+    #[derive(Deserialize)]
+    struct OwnedOuter {
+        #[serde(flatten)]
+        middle: OwnedMiddle,
+    }
+
+    #[derive(Deserialize)]
+    struct OwnedMiddle {
+        #[serde(flatten)]
+        inner: Inner,
+    }
+
+    let expected = |airport, runway| Outer {
+        flight: Cow::Borrowed("BA123"),
+        middle: Middle {
+            gate: Cow::Borrowed("A1"),
+            inner: Inner { airport, runway },
+        },
+    };
+
+    let cases = [
+        ("LHR", "27L", expected(Airport::Heathrow, Runway::TwentySevenLeft)),
+        ("lhr", "09R", expected(Airport::Heathrow, Runway::Other("09R".to_owned()))),
+        ("LGW", "a runway much longer than any name", expected(
+            Airport::Gatwick,
+            Runway::Other("a runway much longer than any name".to_owned()),
+        )),
+    ];
+
+    for (airport, runway, expected) in cases.iter() {
+        let json = format!(
+            r#"{{"flight":"BA123","gate":"A1","airport":"{}","runway":"{}"}}"#,
+            airport, runway
+        );
+
+        // Escaping the strings stops serde_json from borrowing them, so they are owned instead.
+        let json_escaped = format!(
+            r#"{{"flight":"BA123","gate":"A1","airport":"\u{:04x}{}","runway":"\u{:04x}{}"}}"#,
+            airport.as_bytes()[0],
+            &airport[1..],
+            runway.as_bytes()[0],
+            &runway[1..]
+        );
+
+        let json_value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        let results = [
+            ("serde_json::from_str", serde_json::from_str::<Outer<'_>>(&json).ok()),
+            ("serde_json::from_slice", serde_json::from_slice::<Outer<'_>>(json.as_bytes()).ok()),
+            ("serde_json::from_str escaped", serde_json::from_str::<Outer<'_>>(&json_escaped).ok()),
+            ("serde_json::Value", Outer::deserialize(&json_value).ok()),
+        ];
+
+        for (format, result) in results.iter() {
+            assert_eq!(result.as_ref(), Some(expected), "{:?} using {}", json, format);
+        }
+
+        // Owned formats cannot borrow the other strings, but must still produce the enums.
+        let owned = serde_json::from_reader::<_, OwnedOuter>(json.as_bytes()).unwrap();
+        assert_eq!(owned.middle.inner, expected.middle.inner);
+        let owned = serde_json::from_value::<OwnedOuter>(json_value.clone()).unwrap();
+        assert_eq!(owned.middle.inner, expected.middle.inner);
+
+        // A deserializer which only ever passes borrowed strings to the visitor.
+        let entries = [
+            ("flight", "BA123"),
+            ("gate", "A1"),
+            ("airport", *airport),
+            ("runway", *runway),
+        ];
+        let borrowed = MapDeserializer::<_, ValueError>::new(entries.iter().map(|&(k, v)| {
+            (BorrowedStrDeserializer::new(k), BorrowedStrDeserializer::new(v))
+        }));
+        assert_eq!(Outer::deserialize(borrowed).as_ref(), Ok(expected), "{:?}", entries);
+    }
+
+    // Enums in the other representations are deserialized from a map and with `deserialize_enum`,
+    // which are also buffered by `#[serde(flatten)]`.
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    #[enumscribe(serialize_as = "tagged")]
+    enum Terminal {
+        #[enumscribe(str = "T5")]
+        Five,
+        #[enumscribe(other)]
+        Other(String),
+    }
+
+    #[derive(EnumDeserialize, Eq, PartialEq, Debug)]
+    #[enumscribe(serde_native)]
+    enum Class {
+        Economy,
+        #[enumscribe(str = "business", case_insensitive)]
+        Business,
+    }
+
+    #[derive(Deserialize, Eq, PartialEq, Debug)]
+    struct Booking<'a> {
+        #[serde(borrow)]
+        flight: Cow<'a, str>,
+        #[serde(flatten)]
+        seat: Seat,
+    }
+
+    #[derive(Deserialize, Eq, PartialEq, Debug)]
+    struct Seat {
+        terminal: Terminal,
+        class: Class,
+    }
+
+    #[derive(Deserialize)]
+    struct OwnedBooking {
+        #[serde(flatten)]
+        seat: Seat,
+    }
+
+    let expected = Booking {
+        flight: Cow::Borrowed("BA123"),
+        seat: Seat { terminal: Terminal::Five, class: Class::Business },
+    };
+
+    let json = r#"{"flight":"BA123","terminal":{"Terminal":"T5"},"class":"BUSINESS"}"#;
+    let json_escaped =
+        r#"{"flight":"BA123","terminal":{"\u0054erminal":"\u00545"},"class":"\u0042USINESS"}"#;
+
+    assert_eq!(serde_json::from_str::<Booking<'_>>(json).unwrap(), expected);
+    assert_eq!(serde_json::from_str::<Booking<'_>>(json_escaped).unwrap(), expected);
+    assert_eq!(
+        serde_json::from_reader::<_, OwnedBooking>(json.as_bytes()).unwrap().seat,
+        expected.seat
+    );
+    assert_eq!(
+        serde_json::from_value::<OwnedBooking>(serde_json::from_str(json).unwrap()).unwrap().seat,
+        expected.seat
+    );
+}
@@ -0,0 +1,62 @@
+use enumscribe::{EnumStrConsts, ScribeStaticStr};
+
+#[derive(EnumStrConsts, ScribeStaticStr)]
+#[allow(dead_code)]
+enum Airport {
+    #[enumscribe(str = "LHR")]
+    Heathrow,
+    #[enumscribe(str = "LGW", alias = "Gatwick")]
+    Gatwick,
+    LondonCity,
+    #[enumscribe(str = "LTN")]
+    Luton,
+}
+
+const HEATHROW: &str = Airport::HEATHROW_STR;
+
+fn terminals(code: &str) -> Option<u32> {
+    match code {
+        Airport::HEATHROW_STR => Some(4),
+        Airport::GATWICK_STR => Some(2),
+        Airport::LONDON_CITY_STR | Airport::LUTON_STR => Some(1),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_str_consts() {
+    assert_eq!(HEATHROW, "LHR");
+    assert_eq!(Airport::GATWICK_STR, "LGW");
+    assert_eq!(Airport::LONDON_CITY_STR, "LondonCity");
+    assert_eq!(Airport::LUTON_STR, "LTN");
+
+    assert_eq!(Airport::HEATHROW_STR, Airport::Heathrow.scribe());
+    assert_eq!(Airport::LONDON_CITY_STR, Airport::LondonCity.scribe());
+}
+
+#[test]
+fn test_str_consts_match() {
+    assert_eq!(terminals("LHR"), Some(4));
+    assert_eq!(terminals("LGW"), Some(2));
+    assert_eq!(terminals("Gatwick"), None);
+    assert_eq!(terminals("LondonCity"), Some(1));
+    assert_eq!(terminals("LTN"), Some(1));
+    assert_eq!(terminals("STN"), None);
+}
+
+#[test]
+fn test_str_consts_named_only() {
+    #[derive(EnumStrConsts)]
+    #[enumscribe(rename_all = "kebab-case")]
+    #[allow(dead_code)]
+    enum E0 {
+        FooBar,
+        #[enumscribe(ignore)]
+        V1,
+        #[enumscribe(other)]
+        V2(String),
+    }
+
+    const FOO_BAR: &str = E0::FOO_BAR_STR;
+    assert_eq!(FOO_BAR, "foo-bar");
+}
@@ -1,4 +1,8 @@
-use enumscribe::{TryUnscribe, Unscribe};
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::marker::PhantomData;
+
+use enumscribe::{CappedString, ScribeCowStr, ScribeStaticStr, TryUnscribe, Unscribe, UnscribePrefix};
 
 #[test]
 fn test_unscribe() {
@@ -120,6 +124,486 @@ fn test_unscribe() {
     assert_eq!(E1::unscribe("BAA"), E1::V2("BAA".to_owned()));
 }
 
+#[test]
+fn test_unscribe_tagged() {
+    #[derive(Unscribe, Eq, PartialEq, Debug)]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "foo", case_insensitive)]
+        V1,
+        #[enumscribe(str = "baa")]
+        V2 {},
+        #[enumscribe(other)]
+        V3 { s: String },
+    }
+
+    assert_eq!(E0::unscribe_tagged("V0"), (E0::V0, false));
+    assert_eq!(E0::unscribe_tagged("foo"), (E0::V1, false));
+    assert_eq!(E0::unscribe_tagged("FOO"), (E0::V1, false));
+    assert_eq!(E0::unscribe_tagged("baa"), (E0::V2 {}, false));
+    assert_eq!(E0::unscribe_tagged("v0"), (E0::V3 { s: "v0".to_owned() }, true));
+    assert_eq!(E0::unscribe_tagged("BAA"), (E0::V3 { s: "BAA".to_owned() }, true));
+    assert_eq!(E0::unscribe_tagged(""), (E0::V3 { s: "".to_owned() }, true));
+}
+
+#[test]
+fn test_unscribe_raw_norm_fields() {
+    #[derive(Unscribe, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "foo", case_insensitive)]
+        V0,
+        #[enumscribe(other, raw_field = "raw", norm_field = "norm")]
+        V1 { raw: String, norm: String },
+    }
+
+    assert_eq!(E0::unscribe("FOO"), E0::V0);
+    assert_eq!(
+        E0::unscribe("Baa"),
+        E0::V1 { raw: "Baa".to_owned(), norm: "baa".to_owned() }
+    );
+    assert_eq!(
+        E0::unscribe("baa"),
+        E0::V1 { raw: "baa".to_owned(), norm: "baa".to_owned() }
+    );
+    assert_eq!(
+        E0::unscribe("ÀÉÎ"),
+        E0::V1 { raw: "ÀÉÎ".to_owned(), norm: "àéî".to_owned() }
+    );
+    assert_eq!(
+        E0::unscribe(""),
+        E0::V1 { raw: "".to_owned(), norm: "".to_owned() }
+    );
+
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    enum E1 {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(other, norm_field = "b", raw_field = "a")]
+        V1 { b: String, a: String },
+    }
+
+    assert_eq!(E1::try_unscribe("foo"), Some(E1::V0));
+    assert_eq!(
+        E1::try_unscribe("FOO"),
+        Some(E1::V1 { a: "FOO".to_owned(), b: "foo".to_owned() })
+    );
+}
+
+#[test]
+fn test_unscribe_other_type_alias() {
+    // The type of the other field is never inspected by the derive, so it may be given through a
+    // type alias.
+    type S = String;
+    type B = Box<str>;
+
+    #[derive(Unscribe, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "foo", case_insensitive)]
+        V0,
+        #[enumscribe(other)]
+        V1(S),
+    }
+
+    assert_eq!(E0::unscribe("FOO"), E0::V0);
+    assert_eq!(E0::unscribe("bar"), E0::V1("bar".to_owned()));
+
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    enum E1 {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(other)]
+        V1 { s: B },
+    }
+
+    assert_eq!(E1::try_unscribe("foo"), Some(E1::V0));
+    assert_eq!(E1::try_unscribe("bar"), Some(E1::V1 { s: "bar".into() }));
+
+    #[derive(Unscribe, Eq, PartialEq, Debug)]
+    enum E2 {
+        #[enumscribe(str = "foo")]
+        V0,
+        #[enumscribe(other, raw_field = "raw", norm_field = "norm")]
+        V1 { raw: S, norm: S },
+    }
+
+    assert_eq!(E2::unscribe("foo"), E2::V0);
+    assert_eq!(
+        E2::unscribe("Bar"),
+        E2::V1 { raw: "Bar".to_owned(), norm: "bar".to_owned() }
+    );
+}
+
+#[test]
+fn test_unscribe_other_optional_field() {
+    #[derive(Unscribe, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "foo", case_insensitive)]
+        V0,
+        #[enumscribe(other, optional_field)]
+        V1(Option<String>),
+    }
+
+    assert_eq!(E0::unscribe("FOO"), E0::V0);
+    assert_eq!(E0::unscribe("bar"), E0::V1(Some("bar".to_owned())));
+    assert_eq!(E0::unscribe(" "), E0::V1(Some(" ".to_owned())));
+    assert_eq!(E0::unscribe(""), E0::V1(None));
+
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    enum E1 {
+        #[enumscribe(str = "")]
+        V0,
+        #[enumscribe(other, optional_field, placeholder = "-", deny_prefix = "_")]
+        V1 { s: Option<Box<str>> },
+    }
+
+    // A variant named "" takes precedence, as with any other name.
+    assert_eq!(E1::try_unscribe(""), Some(E1::V0));
+    assert_eq!(E1::try_unscribe("bar"), Some(E1::V1 { s: Some("bar".into()) }));
+    assert_eq!(E1::try_unscribe("-"), Some(E1::V1 { s: Some("-".into()) }));
+    assert_eq!(E1::try_unscribe("_bar"), None);
+}
+
+#[test]
+fn test_unscribe_uppercase_expansion() {
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "straße", case_insensitive)]
+        V0,
+    }
+
+    assert_eq!(E0::try_unscribe("straße"), Some(E0::V0));
+    assert_eq!(E0::try_unscribe("STRASSE"), Some(E0::V0));
+    assert_eq!(E0::try_unscribe("STRAßE"), Some(E0::V0));
+    assert_eq!(E0::try_unscribe("strasse"), Some(E0::V0));
+    assert_eq!(E0::try_unscribe("StraSSe"), Some(E0::V0));
+    assert_eq!(E0::try_unscribe("STRASS"), None);
+    assert_eq!(E0::try_unscribe("STRAßEN"), None);
+    assert_eq!(E0::try_unscribe("STRASSEN"), None);
+    assert_eq!(E0::try_unscribe("ßßßß"), None);
+
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    enum E1 {
+        #[enumscribe(str = "ss", case_insensitive)]
+        V0,
+        #[enumscribe(str = "a")]
+        V1,
+    }
+
+    assert_eq!(E1::try_unscribe("ß"), Some(E1::V0));
+    assert_eq!(E1::try_unscribe("SS"), Some(E1::V0));
+    assert_eq!(E1::try_unscribe("sS"), Some(E1::V0));
+    assert_eq!(E1::try_unscribe("ßß"), None);
+    assert_eq!(E1::try_unscribe("a"), Some(E1::V1));
+    assert_eq!(E1::try_unscribe("A"), None);
+}
+
+#[test]
+fn test_unscribe_punctuated_names() {
+    #[derive(Unscribe, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "a=b,c")]
+        V0,
+        #[enumscribe(str = "x, y = z", case_insensitive)]
+        V1,
+        #[enumscribe(str = ",")]
+        V2,
+        #[enumscribe(str = "=", case_insensitive)]
+        V3,
+        #[enumscribe(other)]
+        V4(String),
+    }
+
+    assert_eq!(E0::unscribe("a=b,c"), E0::V0);
+    assert_eq!(E0::unscribe("A=B,C"), E0::V4("A=B,C".to_owned()));
+    assert_eq!(E0::unscribe("a=b"), E0::V4("a=b".to_owned()));
+    assert_eq!(E0::unscribe("x, y = z"), E0::V1);
+    assert_eq!(E0::unscribe("X, Y = Z"), E0::V1);
+    assert_eq!(E0::unscribe("x,y=z"), E0::V4("x,y=z".to_owned()));
+    assert_eq!(E0::unscribe(","), E0::V2);
+    assert_eq!(E0::unscribe("="), E0::V3);
+    assert_eq!(E0::unscribe(",="), E0::V4(",=".to_owned()));
+}
+
+#[test]
+fn test_unscribe_non_exhaustive() {
+    #[derive(Unscribe, TryUnscribe, Eq, PartialEq, Debug)]
+    #[non_exhaustive]
+    enum E0 {
+        #[enumscribe(str = "foo", case_insensitive)]
+        V0,
+        #[enumscribe(ignore)]
+        V1,
+        #[enumscribe(other)]
+        V2(String),
+    }
+
+    assert_eq!(E0::unscribe("FOO"), E0::V0);
+    assert_eq!(E0::unscribe("V1"), E0::V2("V1".to_owned()));
+    assert_eq!(E0::try_unscribe("foo"), Some(E0::V0));
+    assert_ne!(E0::V1, E0::V0);
+}
+
+#[test]
+fn test_try_unscribe_ci_overflow() {
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "foo", case_insensitive)]
+        V0,
+        #[enumscribe(str = "lorem ipsum")]
+        V1,
+        #[enumscribe(other)]
+        V2(String),
+    }
+
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    #[enumscribe(ci_overflow = "other")]
+    enum E1 {
+        #[enumscribe(str = "foo", case_insensitive)]
+        V0,
+        #[enumscribe(str = "lorem ipsum")]
+        V1,
+        #[enumscribe(other)]
+        V2(String),
+    }
+
+    #[derive(TryUnscribe, Unscribe, Eq, PartialEq, Debug)]
+    #[enumscribe(ci_overflow = "error")]
+    enum E2 {
+        #[enumscribe(str = "foo", case_insensitive)]
+        V0,
+        #[enumscribe(str = "lorem ipsum")]
+        V1,
+        #[enumscribe(other)]
+        V2(String),
+    }
+
+    // The uppercase buffer is 11 bytes, the length of "LOREM IPSUM"
+    assert_eq!(E0::try_unscribe("FOO"), Some(E0::V0));
+    assert_eq!(E0::try_unscribe("baa"), Some(E0::V2("baa".to_owned())));
+    assert_eq!(E0::try_unscribe("lorem ipsum"), Some(E0::V1));
+    assert_eq!(E0::try_unscribe("LOREM IPSUM"), Some(E0::V2("LOREM IPSUM".to_owned())));
+    assert_eq!(E0::try_unscribe("lorem ipsum!"), Some(E0::V2("lorem ipsum!".to_owned())));
+    assert_eq!(E0::try_unscribe("ßßßßßß"), Some(E0::V2("ßßßßßß".to_owned())));
+
+    assert_eq!(E1::try_unscribe("FOO"), Some(E1::V0));
+    assert_eq!(E1::try_unscribe("lorem ipsum!"), Some(E1::V2("lorem ipsum!".to_owned())));
+
+    assert_eq!(E2::try_unscribe("FOO"), Some(E2::V0));
+    assert_eq!(E2::try_unscribe("baa"), Some(E2::V2("baa".to_owned())));
+    assert_eq!(E2::try_unscribe("lorem ipsum"), Some(E2::V1));
+    assert_eq!(E2::try_unscribe("LOREM IPSUM"), Some(E2::V2("LOREM IPSUM".to_owned())));
+    assert_eq!(E2::try_unscribe("lorem ipsum!"), None);
+    assert_eq!(E2::try_unscribe("ßßßßß"), Some(E2::V2("ßßßßß".to_owned())));
+    assert_eq!(E2::try_unscribe("ßßßßßß"), None);
+
+    // Unscribe cannot fail, so it always falls back to other
+    assert_eq!(E2::unscribe("lorem ipsum!"), E2::V2("lorem ipsum!".to_owned()));
+}
+
+#[test]
+fn test_unscribe_empty_case_insensitive_name() {
+    // The only name is empty, so the uppercase buffer has a capacity of zero
+    #[derive(TryUnscribe, Unscribe, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "", case_insensitive)]
+        V0,
+        #[enumscribe(other)]
+        V1(String),
+    }
+
+    assert_eq!(E0::try_unscribe(""), Some(E0::V0));
+    assert_eq!(E0::try_unscribe("a"), Some(E0::V1("a".to_owned())));
+    assert_eq!(E0::try_unscribe(" "), Some(E0::V1(" ".to_owned())));
+    assert_eq!(E0::unscribe(""), E0::V0);
+    assert_eq!(E0::unscribe("a"), E0::V1("a".to_owned()));
+    assert_eq!(E0::unscribe_with_buf("", &mut CappedString::<0>::new()), E0::V0);
+    assert_eq!(E0::unscribe_with_buf("a", &mut CappedString::<0>::new()), E0::V1("a".to_owned()));
+    assert_eq!(E0::unscribe_chars("".chars()), Some(E0::V0));
+    assert_eq!(E0::try_unscribe_bytes(b""), Some(E0::V0));
+    assert_eq!(E0::try_unscribe_bytes(b"a"), Some(E0::V1("a".to_owned())));
+
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    enum E1 {
+        #[enumscribe(str = "", case_insensitive)]
+        V0,
+        #[enumscribe(ignore)]
+        #[allow(dead_code)]
+        V1,
+    }
+
+    assert_eq!(E1::try_unscribe(""), Some(E1::V0));
+    assert_eq!(E1::try_unscribe("a"), None);
+    assert_eq!(E1::unscribe_chars("".chars()), Some(E1::V0));
+    assert_eq!(E1::unscribe_chars("a".chars()), None);
+    assert_eq!(E1::try_unscribe_bytes(b"a"), None);
+
+    // Every non-empty string overflows the buffer, so none of them are stored in other
+    #[derive(TryUnscribe, Unscribe, Eq, PartialEq, Debug)]
+    #[enumscribe(ci_overflow = "error")]
+    enum E2 {
+        #[enumscribe(str = "", case_insensitive)]
+        V0,
+        #[enumscribe(other)]
+        V1(String),
+    }
+
+    assert_eq!(E2::try_unscribe(""), Some(E2::V0));
+    assert_eq!(E2::try_unscribe("a"), None);
+    assert_eq!(E2::unscribe("a"), E2::V1("a".to_owned()));
+}
+
+#[test]
+fn test_try_unscribe_deny_prefix() {
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "__foo")]
+        V0,
+        #[enumscribe(str = "baa", case_insensitive)]
+        V1,
+        #[enumscribe(other, deny_prefix = "__")]
+        V2(String),
+    }
+
+    assert_eq!(E0::try_unscribe("__foo"), Some(E0::V0));
+    assert_eq!(E0::try_unscribe("BAA"), Some(E0::V1));
+    assert_eq!(E0::try_unscribe("lorem"), Some(E0::V2("lorem".to_owned())));
+    assert_eq!(E0::try_unscribe("_lorem"), Some(E0::V2("_lorem".to_owned())));
+    assert_eq!(E0::try_unscribe("lorem__"), Some(E0::V2("lorem__".to_owned())));
+    assert_eq!(E0::try_unscribe(""), Some(E0::V2("".to_owned())));
+    assert_eq!(E0::try_unscribe("__lorem"), None);
+    assert_eq!(E0::try_unscribe("__FOO"), None);
+    assert_eq!(E0::try_unscribe("__"), None);
+
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    #[enumscribe(case_insensitive)]
+    enum E1 {
+        V0,
+        #[enumscribe(other, raw_field = "raw", norm_field = "norm", deny_prefix = "蟹")]
+        V1 { raw: String, norm: String },
+    }
+
+    assert_eq!(E1::try_unscribe("v0"), Some(E1::V0));
+    assert_eq!(
+        E1::try_unscribe("Lorem蟹"),
+        Some(E1::V1 { raw: "Lorem蟹".to_owned(), norm: "lorem蟹".to_owned() })
+    );
+    assert_eq!(E1::try_unscribe("蟹"), None);
+    assert_eq!(E1::try_unscribe("蟹Lorem"), None);
+}
+
+#[test]
+fn test_unscribe_accept_ident() {
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "LHR", accept_ident)]
+        Heathrow,
+        #[enumscribe(str = "LGW")]
+        Gatwick,
+        #[enumscribe(str = "ltn", case_insensitive, accept_ident)]
+        Luton,
+        #[enumscribe(accept_ident)]
+        Stansted,
+    }
+
+    assert_eq!(E0::try_unscribe("LHR"), Some(E0::Heathrow));
+    assert_eq!(E0::try_unscribe("Heathrow"), Some(E0::Heathrow));
+    assert_eq!(E0::try_unscribe("heathrow"), None);
+    assert_eq!(E0::try_unscribe("LGW"), Some(E0::Gatwick));
+    assert_eq!(E0::try_unscribe("Gatwick"), None);
+    assert_eq!(E0::try_unscribe("LTN"), Some(E0::Luton));
+    assert_eq!(E0::try_unscribe("Luton"), Some(E0::Luton));
+    assert_eq!(E0::try_unscribe("LUTON"), Some(E0::Luton));
+    assert_eq!(E0::try_unscribe("Stansted"), Some(E0::Stansted));
+
+    #[derive(Unscribe, Eq, PartialEq, Debug)]
+    #[enumscribe(accept_ident, rename_all = "snake_case")]
+    enum E1 {
+        #[enumscribe(str = "LHR")]
+        Heathrow,
+        #[enumscribe(str = "LGW", accept_ident = false)]
+        Gatwick,
+        LondonCity,
+        #[enumscribe(other)]
+        Other(String),
+    }
+
+    assert_eq!(E1::unscribe("LHR"), E1::Heathrow);
+    assert_eq!(E1::unscribe("Heathrow"), E1::Heathrow);
+    assert_eq!(E1::unscribe("LGW"), E1::Gatwick);
+    assert_eq!(E1::unscribe("Gatwick"), E1::Other("Gatwick".to_owned()));
+    assert_eq!(E1::unscribe("london_city"), E1::LondonCity);
+    assert_eq!(E1::unscribe("LondonCity"), E1::LondonCity);
+    assert_eq!(E1::unscribe("Londoncity"), E1::Other("Londoncity".to_owned()));
+
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    #[enumscribe(case_insensitive, accept_ident)]
+    enum E2 {
+        #[enumscribe(str = "heathrow")]
+        Heathrow,
+        #[enumscribe(str = "gatwick", case_sensitive)]
+        Gatwick,
+    }
+
+    assert_eq!(E2::try_unscribe("heathrow"), Some(E2::Heathrow));
+    assert_eq!(E2::try_unscribe("Heathrow"), Some(E2::Heathrow));
+    assert_eq!(E2::try_unscribe("HEATHROW"), Some(E2::Heathrow));
+    assert_eq!(E2::try_unscribe("gatwick"), Some(E2::Gatwick));
+    assert_eq!(E2::try_unscribe("Gatwick"), Some(E2::Gatwick));
+    assert_eq!(E2::try_unscribe("GATWICK"), None);
+}
+
+#[test]
+fn test_unscribe_with_buf() {
+    #[derive(Unscribe, Eq, PartialEq, Debug)]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "baa", case_insensitive)]
+        V1,
+        #[enumscribe(str = "groß", case_insensitive)]
+        V2,
+        #[enumscribe(str = "蟹", case_insensitive)]
+        V3,
+        #[enumscribe(other)]
+        V4(String),
+    }
+
+    let inputs = [
+        "V0", "v0", "baa", "BAA", "bAa", "baa!", "groß", "GROSS", "gross", "grossbaa", "蟹", "",
+        "a much longer string than any of the names",
+    ];
+
+    // Reuse a single buffer across many calls, checking that every result agrees with `unscribe`.
+    let mut buf = CappedString::<5>::new();
+    for _ in 0..1000 {
+        for input in inputs.iter() {
+            assert_eq!(E0::unscribe_with_buf(input, &mut buf), E0::unscribe(input));
+        }
+    }
+
+    // A larger buffer than needed is fine.
+    let mut buf = CappedString::<64>::new();
+    for input in inputs.iter() {
+        assert_eq!(E0::unscribe_with_buf(input, &mut buf), E0::unscribe(input));
+    }
+
+    // A buffer which is too small is not used, so matching still works.
+    let mut buf = CappedString::<2>::new();
+    assert_eq!(E0::unscribe_with_buf("Gross", &mut buf), E0::V2);
+    assert_eq!(E0::unscribe_with_buf("bAA", &mut buf), E0::V1);
+    assert_eq!(E0::unscribe_with_buf("lorem", &mut buf), E0::V4("lorem".to_owned()));
+
+    #[derive(Unscribe, Eq, PartialEq, Debug)]
+    enum E1 {
+        V0,
+        #[enumscribe(other)]
+        V1(String),
+    }
+
+    let mut buf = CappedString::<0>::new();
+    assert_eq!(E1::unscribe_with_buf("V0", &mut buf), E1::V0);
+    assert_eq!(E1::unscribe_with_buf("v0", &mut buf), E1::V1("v0".to_owned()));
+}
+
 #[test]
 fn test_try_unscribe() {
     #[derive(TryUnscribe, Eq, PartialEq, Debug)]
@@ -352,3 +836,751 @@ fn test_try_unscribe() {
     assert_eq!(E2::try_unscribe("Baa"), None);
     assert_eq!(E2::try_unscribe("BAA"), None);
 }
+
+#[test]
+fn test_unscribe_chars() {
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "LHR")]
+        V0,
+        #[enumscribe(str = "LGW", case_insensitive)]
+        V1,
+        #[enumscribe(str = "S", case_insensitive)]
+        V2,
+        #[enumscribe(str = "蟹")]
+        V3,
+    }
+
+    assert_eq!(E0::unscribe_chars("LHR".chars()), Some(E0::V0));
+    assert_eq!(E0::unscribe_chars("lhr".chars()), None);
+    assert_eq!(E0::unscribe_chars("lgw".chars()), Some(E0::V1));
+    assert_eq!(E0::unscribe_chars("LgW".chars()), Some(E0::V1));
+    assert_eq!(E0::unscribe_chars("s".chars()), Some(E0::V2));
+    assert_eq!(E0::unscribe_chars("ſ".chars()), Some(E0::V2));
+    assert_eq!(E0::unscribe_chars("蟹".chars()), Some(E0::V3));
+    assert_eq!(E0::unscribe_chars("".chars()), None);
+    assert_eq!(E0::unscribe_chars("LHRX".chars()), None);
+    assert_eq!(E0::unscribe_chars(['L', 'H', 'R'].iter().copied()), Some(E0::V0));
+
+    // Overflowing the buffer stops consuming the iterator.
+    assert_eq!(E0::unscribe_chars("LHR".chars().cycle()), None);
+
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    enum E1 {
+        #[enumscribe(str = "LHR")]
+        V0,
+        #[enumscribe(other)]
+        V1(String),
+    }
+
+    assert_eq!(E1::unscribe_chars("LHR".chars()), Some(E1::V0));
+    assert_eq!(E1::unscribe_chars("LGW".chars()), Some(E1::V1("LGW".to_owned())));
+    assert_eq!(E1::unscribe_chars("".chars()), Some(E1::V1("".to_owned())));
+    assert_eq!(E1::unscribe_chars("LHRX".chars()), None);
+}
+
+#[test]
+fn test_max_match_len() {
+    #[derive(TryUnscribe)]
+    #[allow(dead_code)]
+    enum E0 {
+        #[enumscribe(str = "LHR", alias = "Heathrow")]
+        V0,
+        #[enumscribe(str = "蟹")]
+        V1,
+        #[enumscribe(ignore)]
+        V2(i32),
+        #[enumscribe(other)]
+        V3(String),
+    }
+
+    assert_eq!(E0::max_match_len(), 8);
+
+    // "ſ" is 2 bytes, but only 1 byte when converted to uppercase, so case-insensitive names
+    // allow longer strings to match
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    enum E1 {
+        #[enumscribe(str = "SS", case_insensitive)]
+        V0,
+    }
+
+    assert!(E1::max_match_len() >= "ſſ".len());
+    assert_eq!(E1::try_unscribe("ſſ"), Some(E1::V0));
+
+    #[derive(TryUnscribe)]
+    #[allow(dead_code)]
+    enum E2 {
+        #[enumscribe(other)]
+        V0(String),
+    }
+
+    assert_eq!(E2::max_match_len(), 0);
+}
+
+#[test]
+fn test_try_unscribe_bytes() {
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "LHR")]
+        V0,
+        #[enumscribe(str = "蟹", alias = "kani")]
+        V1,
+    }
+
+    assert_eq!(E0::try_unscribe_bytes(b"LHR"), Some(E0::V0));
+    assert_eq!(E0::try_unscribe_bytes("蟹".as_bytes()), Some(E0::V1));
+    assert_eq!(E0::try_unscribe_bytes(b"kani"), Some(E0::V1));
+    assert_eq!(E0::try_unscribe_bytes(b"lhr"), None);
+    assert_eq!(E0::try_unscribe_bytes(b""), None);
+    assert_eq!(E0::try_unscribe_bytes(b"LHR\xff"), None);
+    assert_eq!(E0::try_unscribe_bytes(&"蟹".as_bytes()[..2]), None);
+
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    enum E1 {
+        #[enumscribe(str = "LHR")]
+        V0,
+        #[enumscribe(str = "LGW", case_insensitive)]
+        V1,
+        #[enumscribe(str = "S", case_insensitive)]
+        V2,
+        #[enumscribe(other, deny_prefix = "__")]
+        V3(String),
+    }
+
+    assert_eq!(E1::try_unscribe_bytes(b"LHR"), Some(E1::V0));
+    assert_eq!(E1::try_unscribe_bytes(b"lgw"), Some(E1::V1));
+    assert_eq!(E1::try_unscribe_bytes(b"LgW"), Some(E1::V1));
+    assert_eq!(E1::try_unscribe_bytes("ſ".as_bytes()), Some(E1::V2));
+    assert_eq!(E1::try_unscribe_bytes(b"lhr"), Some(E1::V3("lhr".to_owned())));
+    assert_eq!(E1::try_unscribe_bytes("蟹".as_bytes()), Some(E1::V3("蟹".to_owned())));
+    assert_eq!(E1::try_unscribe_bytes(b""), Some(E1::V3("".to_owned())));
+    assert_eq!(E1::try_unscribe_bytes(b"__LHR"), None);
+    assert_eq!(E1::try_unscribe_bytes(b"\xff"), None);
+    assert_eq!(E1::try_unscribe_bytes(b"lgw\xc0"), None);
+
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    #[enumscribe(normalize_newlines)]
+    enum E2 {
+        #[enumscribe(str = "one\n")]
+        V0,
+    }
+
+    assert_eq!(E2::try_unscribe_bytes(b"one\n"), Some(E2::V0));
+    assert_eq!(E2::try_unscribe_bytes(b"one\r\n"), Some(E2::V0));
+    assert_eq!(E2::try_unscribe_bytes(b"one\r\n\xff"), None);
+}
+
+#[test]
+fn test_try_unscribe_echo() {
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "foo", case_insensitive)]
+        V1,
+    }
+
+    assert_eq!(E0::try_unscribe_echo("V0"), Ok(E0::V0));
+    assert_eq!(E0::try_unscribe_echo("FOO"), Ok(E0::V1));
+    assert_eq!(E0::try_unscribe_echo("v0"), Err("v0"));
+    assert_eq!(E0::try_unscribe_echo(""), Err(""));
+
+    let input = String::from("bar");
+    let err = E0::try_unscribe_echo(&input).unwrap_err();
+    assert!(std::ptr::eq(err, input.as_str()));
+
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    enum E1 {
+        V0,
+        #[enumscribe(other)]
+        V1(String),
+    }
+
+    assert_eq!(E1::try_unscribe_echo("V0"), Ok(E1::V0));
+    assert_eq!(E1::try_unscribe_echo("bar"), Ok(E1::V1("bar".to_owned())));
+    assert_eq!(E1::try_unscribe_echo(""), Ok(E1::V1("".to_owned())));
+}
+
+#[test]
+fn test_try_unscribe_is_unknown() {
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "foo", case_insensitive)]
+        V1,
+        #[enumscribe(str = "LHR", alias = "Heathrow")]
+        V2,
+    }
+
+    assert!(!E0::is_unknown("V0"));
+    assert!(!E0::is_unknown("foo"));
+    assert!(!E0::is_unknown("FOO"));
+    assert!(!E0::is_unknown("fOo"));
+    assert!(!E0::is_unknown("LHR"));
+    assert!(!E0::is_unknown("Heathrow"));
+    assert!(E0::is_unknown("v0"));
+    assert!(E0::is_unknown("lhr"));
+    assert!(E0::is_unknown("heathrow"));
+    assert!(E0::is_unknown("foo "));
+    assert!(E0::is_unknown(""));
+
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    #[enumscribe(empty_is_other)]
+    enum E1 {
+        #[enumscribe(str = "foo", case_insensitive)]
+        V0,
+        #[enumscribe(other, deny_prefix = "__")]
+        V1(String),
+    }
+
+    assert!(!E1::is_unknown("foo"));
+    assert!(!E1::is_unknown("FOO"));
+    assert!(E1::is_unknown("bar"));
+    assert!(E1::is_unknown("__foo"));
+    assert!(E1::is_unknown("foo bar baz qux"));
+    assert!(E1::is_unknown(""));
+
+    for s in ["foo", "Foo", "bar", "__foo", ""].iter() {
+        assert_eq!(
+            E1::is_unknown(s),
+            !matches!(E1::try_unscribe(s), Some(E1::V0))
+        );
+    }
+}
+
+#[test]
+fn test_unscribe_normalized() {
+    fn collapse_whitespace(s: &str) -> Cow<'_, str> {
+        let collapsed = s.split_whitespace().collect::<Vec<_>>().join(" ");
+        if collapsed == s {
+            Cow::Borrowed(s)
+        } else {
+            Cow::Owned(collapsed)
+        }
+    }
+
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "north east")]
+        V0,
+        #[enumscribe(str = "south west", case_insensitive)]
+        V1,
+        #[enumscribe(other)]
+        V2(String),
+    }
+
+    assert_eq!(E0::unscribe_normalized("north east", collapse_whitespace), Some(E0::V0));
+    assert_eq!(E0::unscribe_normalized("  north \t east\n", collapse_whitespace), Some(E0::V0));
+    assert_eq!(E0::unscribe_normalized("SOUTH    WEST", collapse_whitespace), Some(E0::V1));
+    assert_eq!(
+        E0::unscribe_normalized(" north  west ", collapse_whitespace),
+        Some(E0::V2("north west".to_owned()))
+    );
+    assert_eq!(
+        E0::unscribe_normalized(" north  east ", |s| Cow::Borrowed(s)),
+        Some(E0::V2(" north  east ".to_owned()))
+    );
+
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    enum E1 {
+        #[enumscribe(str = "north east")]
+        V0,
+    }
+
+    assert_eq!(E1::unscribe_normalized("north\neast", collapse_whitespace), Some(E1::V0));
+    assert_eq!(E1::unscribe_normalized("northeast", collapse_whitespace), None);
+    assert_eq!(E1::unscribe_normalized("", collapse_whitespace), None);
+}
+
+#[test]
+fn test_unscribe_filter() {
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "foo", case_insensitive)]
+        V1,
+        #[enumscribe(ignore)]
+        #[allow(dead_code)]
+        V2,
+    }
+
+    assert_eq!(
+        E0::unscribe_filter(&["V0", "V2", "FOO", "", "bar", "v0", "foo", "V0"]),
+        vec![E0::V0, E0::V1, E0::V1, E0::V0]
+    );
+    assert_eq!(E0::unscribe_filter(&["V2", "bar"]), vec![]);
+    assert_eq!(E0::unscribe_filter(&[]), vec![]);
+
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    enum E1 {
+        V0,
+        #[enumscribe(other)]
+        V1(String),
+    }
+
+    assert_eq!(
+        E1::unscribe_filter(&["bar", "V0", ""]),
+        vec![E1::V1("bar".to_owned()), E1::V0, E1::V1("".to_owned())]
+    );
+}
+
+#[test]
+fn test_unscribe_normalize_newlines() {
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    #[enumscribe(normalize_newlines)]
+    enum E0 {
+        #[enumscribe(str = "a\nb")]
+        V0,
+        #[enumscribe(str = "c\r\nd")]
+        V1,
+        #[enumscribe(str = "e\nf", case_insensitive)]
+        V2,
+        #[enumscribe(str = "g\rh")]
+        V3,
+    }
+
+    assert_eq!(E0::try_unscribe("a\nb"), Some(E0::V0));
+    assert_eq!(E0::try_unscribe("a\r\nb"), Some(E0::V0));
+    assert_eq!(E0::try_unscribe("c\nd"), Some(E0::V1));
+    assert_eq!(E0::try_unscribe("c\r\nd"), Some(E0::V1));
+    assert_eq!(E0::try_unscribe("E\r\nF"), Some(E0::V2));
+    assert_eq!(E0::try_unscribe("e\nF"), Some(E0::V2));
+    assert_eq!(E0::try_unscribe("g\rh"), Some(E0::V3));
+    assert_eq!(E0::try_unscribe("a\rb"), None);
+    assert_eq!(E0::try_unscribe("a\n\rb"), None);
+    assert_eq!(E0::try_unscribe("a\r\n\r\nb"), None);
+    assert_eq!(E0::unscribe_chars("a\r\nb".chars()), Some(E0::V0));
+    assert_eq!(E0::unscribe_chars("E\r\nf".chars()), Some(E0::V2));
+
+    #[derive(Unscribe, Eq, PartialEq, Debug)]
+    #[enumscribe(normalize_newlines)]
+    enum E1 {
+        #[enumscribe(str = "end\n")]
+        V0,
+        #[enumscribe(other)]
+        V1(String),
+    }
+
+    assert_eq!(E1::unscribe("end\n"), E1::V0);
+    assert_eq!(E1::unscribe("end\r\n"), E1::V0);
+    assert_eq!(E1::unscribe("start\r\n"), E1::V1("start\r\n".to_owned()));
+    assert_eq!(E1::unscribe("end\r\n\r\n\r\n"), E1::V1("end\r\n\r\n\r\n".to_owned()));
+
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    enum E2 {
+        #[enumscribe(str = "end\n")]
+        V0,
+    }
+
+    assert_eq!(E2::try_unscribe("end\n"), Some(E2::V0));
+    assert_eq!(E2::try_unscribe("end\r\n"), None);
+}
+
+#[test]
+fn test_unscribe_nfc() {
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    #[enumscribe(nfc)]
+    enum E0 {
+        // "café", with the "é" as a single precomposed character
+        #[enumscribe(str = "caf\u{e9}")]
+        V0,
+        // "résumé", with each "é" as an "e" followed by a combining acute accent
+        #[enumscribe(str = "re\u{301}sume\u{301}", case_insensitive)]
+        V1,
+        #[enumscribe(str = "\u{212b}")]
+        V2,
+    }
+
+    assert_eq!(E0::try_unscribe("caf\u{e9}"), Some(E0::V0));
+    assert_eq!(E0::try_unscribe("cafe\u{301}"), Some(E0::V0));
+    assert_eq!(E0::try_unscribe("r\u{e9}sum\u{e9}"), Some(E0::V1));
+    assert_eq!(E0::try_unscribe("re\u{301}sume\u{301}"), Some(E0::V1));
+    assert_eq!(E0::try_unscribe("R\u{c9}SUME\u{301}"), Some(E0::V1));
+    assert_eq!(E0::try_unscribe("\u{212b}"), Some(E0::V2));
+    assert_eq!(E0::try_unscribe("\u{c5}"), Some(E0::V2));
+    assert_eq!(E0::try_unscribe("A\u{30a}"), Some(E0::V2));
+    assert_eq!(E0::try_unscribe("cafe"), None);
+    assert_eq!(E0::try_unscribe("cafe\u{300}"), None);
+    assert_eq!(E0::unscribe_chars("cafe\u{301}".chars()), Some(E0::V0));
+    assert_eq!(E0::try_unscribe_bytes("caf\u{e9}".as_bytes()), Some(E0::V0));
+    assert_eq!(E0::try_unscribe_bytes("cafe\u{301}".as_bytes()), Some(E0::V0));
+
+    #[derive(Unscribe, Eq, PartialEq, Debug)]
+    #[enumscribe(nfc, normalize_newlines)]
+    enum E1 {
+        #[enumscribe(str = "caf\u{e9}\n")]
+        V0,
+        #[enumscribe(other)]
+        V1(String),
+    }
+
+    assert_eq!(E1::unscribe("caf\u{e9}\n"), E1::V0);
+    assert_eq!(E1::unscribe("cafe\u{301}\r\n"), E1::V0);
+    assert_eq!(E1::unscribe("cafe\u{301}"), E1::V1("cafe\u{301}".to_owned()));
+    assert_eq!(
+        E1::unscribe("cafe\u{301}\u{301}\u{301}\n"),
+        E1::V1("cafe\u{301}\u{301}\u{301}\n".to_owned())
+    );
+
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    enum E2 {
+        #[enumscribe(str = "caf\u{e9}")]
+        V0,
+    }
+
+    assert_eq!(E2::try_unscribe("caf\u{e9}"), Some(E2::V0));
+    assert_eq!(E2::try_unscribe("cafe\u{301}"), None);
+}
+
+#[test]
+fn test_phantom_data_variants() {
+    #[derive(ScribeStaticStr, TryUnscribe, Eq, PartialEq, Debug)]
+    enum E0<T> {
+        #[enumscribe(str = "LHR")]
+        V0(PhantomData<T>),
+        #[enumscribe(str = "LGW", case_insensitive)]
+        V1 { marker: PhantomData<fn() -> T> },
+        V2(std::marker::PhantomData<T>, core::marker::PhantomData<u8>),
+        V3,
+    }
+
+    assert_eq!(E0::<String>::V0(PhantomData).scribe(), "LHR");
+    assert_eq!(E0::<String>::V1 { marker: PhantomData }.scribe(), "LGW");
+    assert_eq!(E0::<String>::V2(PhantomData, PhantomData).scribe(), "V2");
+    assert_eq!(E0::<String>::V3.scribe(), "V3");
+
+    assert_eq!(E0::<String>::try_unscribe("LHR"), Some(E0::V0(PhantomData)));
+    assert_eq!(E0::<String>::try_unscribe("lgw"), Some(E0::V1 { marker: PhantomData }));
+    assert_eq!(E0::<String>::try_unscribe("V2"), Some(E0::V2(PhantomData, PhantomData)));
+    assert_eq!(E0::<String>::try_unscribe("V3"), Some(E0::V3));
+    assert_eq!(E0::<String>::try_unscribe("STN"), None);
+
+    #[derive(ScribeCowStr, Unscribe, Eq, PartialEq, Debug)]
+    enum E1<T> {
+        #[enumscribe(str = "LHR")]
+        V0(PhantomData<T>),
+        #[enumscribe(other)]
+        V1(String),
+    }
+
+    assert_eq!(E1::<u32>::V0(PhantomData).scribe(), "LHR");
+    assert_eq!(E1::<u32>::V1("STN".to_owned()).scribe(), "STN");
+    assert_eq!(E1::<u32>::unscribe("LHR"), E1::V0(PhantomData));
+    assert_eq!(E1::<u32>::unscribe("STN"), E1::V1("STN".to_owned()));
+}
+
+#[test]
+fn test_unscribe_alias() {
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "LHR", alias = "Heathrow")]
+        #[cfg_attr(all(), enumscribe(alias = "EGLL"))]
+        #[cfg_attr(any(), enumscribe(alias = "Disabled"))]
+        V0,
+        #[enumscribe(str = "LGW", alias = "gatwick", case_insensitive)]
+        #[enumscribe(alias = "EGKK", alias = "North Terminal")]
+        V1,
+        #[enumscribe(str = "LTN")]
+        V2,
+    }
+
+    assert_eq!(E0::try_unscribe("LHR"), Some(E0::V0));
+    assert_eq!(E0::try_unscribe("Heathrow"), Some(E0::V0));
+    assert_eq!(E0::try_unscribe("EGLL"), Some(E0::V0));
+    assert_eq!(E0::try_unscribe("heathrow"), None);
+    assert_eq!(E0::try_unscribe("Disabled"), None);
+    assert_eq!(E0::try_unscribe("LGW"), Some(E0::V1));
+    assert_eq!(E0::try_unscribe("Gatwick"), Some(E0::V1));
+    assert_eq!(E0::try_unscribe("egkk"), Some(E0::V1));
+    assert_eq!(E0::try_unscribe("NORTH TERMINAL"), Some(E0::V1));
+    assert_eq!(E0::try_unscribe("LTN"), Some(E0::V2));
+
+    #[derive(Unscribe, Eq, PartialEq, Debug)]
+    enum E1 {
+        #[enumscribe(alias = "foo")]
+        #[enumscribe(alias = "bar")]
+        V0,
+        #[enumscribe(other)]
+        V1(String),
+    }
+
+    assert_eq!(E1::unscribe("V0"), E1::V0);
+    assert_eq!(E1::unscribe("foo"), E1::V0);
+    assert_eq!(E1::unscribe("bar"), E1::V0);
+    assert_eq!(E1::unscribe("baz"), E1::V1("baz".to_owned()));
+}
+
+#[test]
+fn test_unscribe_case_sensitive_precedence() {
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "ok", case_insensitive)]
+        V0,
+        #[enumscribe(str = "OK")]
+        V1,
+        #[enumscribe(str = "groß", case_insensitive)]
+        V2,
+        #[enumscribe(str = "GROSS")]
+        V3,
+    }
+
+    assert_eq!(E0::try_unscribe("OK"), Some(E0::V1));
+    assert_eq!(E0::try_unscribe("ok"), Some(E0::V0));
+    assert_eq!(E0::try_unscribe("Ok"), Some(E0::V0));
+    assert_eq!(E0::try_unscribe("oK"), Some(E0::V0));
+    assert_eq!(E0::try_unscribe("GROSS"), Some(E0::V3));
+    assert_eq!(E0::try_unscribe("gross"), Some(E0::V2));
+    assert_eq!(E0::try_unscribe("GROß"), Some(E0::V2));
+    assert_eq!(E0::unscribe_chars("OK".chars()), Some(E0::V1));
+    assert_eq!(E0::unscribe_chars("ok".chars()), Some(E0::V0));
+
+    #[derive(Unscribe, Eq, PartialEq, Debug)]
+    #[enumscribe(case_insensitive)]
+    enum E1 {
+        #[enumscribe(str = "LHR", case_sensitive)]
+        V0,
+        #[enumscribe(str = "lhr")]
+        V1,
+        #[enumscribe(other, raw_field = "raw", norm_field = "norm")]
+        V2 { raw: String, norm: String },
+    }
+
+    assert_eq!(E1::unscribe("LHR"), E1::V0);
+    assert_eq!(E1::unscribe("lhr"), E1::V1);
+    assert_eq!(E1::unscribe("Lhr"), E1::V1);
+    assert_eq!(
+        E1::unscribe("LGW"),
+        E1::V2 { raw: "LGW".to_owned(), norm: "lgw".to_owned() }
+    );
+}
+
+#[test]
+fn test_unscribe_rename_all_case_insensitive_expansion() {
+    // The renamed names are already uppercase, so the capacity used for case-insensitive matching
+    // is the length of the renamed names. Inputs which are a different length before being
+    // converted to uppercase must still match.
+    #[derive(TryUnscribe, UnscribePrefix, ScribeStaticStr, Eq, PartialEq, Debug)]
+    #[enumscribe(rename_all = "SCREAMING_SNAKE_CASE", case_insensitive)]
+    enum E0 {
+        Straße,
+        GroßeStraße,
+        Weg,
+    }
+
+    assert_eq!(E0::Straße.scribe(), "STRASSE");
+    assert_eq!(E0::GroßeStraße.scribe(), "GROSSE_STRASSE");
+
+    for &s in ["STRASSE", "strasse", "straße", "STRAßE", "ſtraße", "ſtraſſe"].iter() {
+        assert_eq!(E0::try_unscribe(s), Some(E0::Straße), "{:?}", s);
+        assert_eq!(E0::unscribe_prefix(s), Some((E0::Straße, "")), "{:?}", s);
+    }
+
+    for &s in ["GROSSE_STRASSE", "große_straße", "Große_Strasse", "groſſe_ſtraße"].iter() {
+        assert_eq!(E0::try_unscribe(s), Some(E0::GroßeStraße), "{:?}", s);
+    }
+
+    assert_eq!(E0::try_unscribe("weg"), Some(E0::Weg));
+
+    for &s in ["STRAẞE", "strassee", "straßße", "große straße", "ßßßßßßßßßßßßßßßß"].iter() {
+        assert_eq!(E0::try_unscribe(s), None, "{:?}", s);
+    }
+
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    #[enumscribe(rename_all = "SCREAMING_SNAKE_CASE", case_insensitive, ci_overflow = "error")]
+    enum E1 {
+        Straße,
+        #[enumscribe(other)]
+        Other(String),
+    }
+
+    assert_eq!(E1::try_unscribe("ſtraße"), Some(E1::Straße));
+    assert_eq!(E1::try_unscribe("strasse"), Some(E1::Straße));
+    assert_eq!(E1::try_unscribe("strase"), Some(E1::Other("strase".to_owned())));
+    // The uppercase form of "ßßßß" is 8 bytes, which is longer than the name, so it overflows.
+    assert_eq!(E1::try_unscribe("ßßßß"), None);
+    assert_eq!(E1::try_unscribe("ßßß"), Some(E1::Other("ßßß".to_owned())));
+}
+
+#[test]
+fn test_unscribe_macro_names() {
+    #[derive(ScribeStaticStr, TryUnscribe, Eq, PartialEq, Debug)]
+    #[enumscribe(expect_names(concat!("a", "b"), "Baa", "x-1-2.5-true-c", "qux"))]
+    enum E0 {
+        #[enumscribe(str = concat!("a", "b"))]
+        V0,
+        #[enumscribe(str = stringify!(Baa), alias = concat!("b", stringify!(aa)))]
+        V1,
+        #[enumscribe(str = concat!("x-", 1, "-", 2.5, "-", true, "-", 'c',))]
+        V2,
+        #[enumscribe(str = core::concat!(concat!("q", "u"), "x"), case_insensitive)]
+        V3,
+    }
+
+    assert_eq!(E0::V0.scribe(), "ab");
+    assert_eq!(E0::V1.scribe(), "Baa");
+    assert_eq!(E0::V2.scribe(), "x-1-2.5-true-c");
+    assert_eq!(E0::V3.scribe(), "qux");
+
+    assert_eq!(E0::try_unscribe("ab"), Some(E0::V0));
+    assert_eq!(E0::try_unscribe("Baa"), Some(E0::V1));
+    assert_eq!(E0::try_unscribe("baa"), Some(E0::V1));
+    assert_eq!(E0::try_unscribe("x-1-2.5-true-c"), Some(E0::V2));
+    assert_eq!(E0::try_unscribe("QUX"), Some(E0::V3));
+    assert_eq!(E0::try_unscribe("concat"), None);
+}
+
+thread_local! {
+    static DEPRECATED_NAMES: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+fn record_deprecated(name: &str) {
+    DEPRECATED_NAMES.with(|names| names.borrow_mut().push(name.to_owned()));
+}
+
+fn take_deprecated() -> Vec<String> {
+    DEPRECATED_NAMES.with(|names| names.borrow_mut().drain(..).collect())
+}
+
+#[test]
+fn test_unscribe_on_deprecated() {
+    #[derive(Unscribe, TryUnscribe, enumscribe::FromStr, enumscribe::EnumDeserialize)]
+    #[derive(Eq, PartialEq, Debug)]
+    #[enumscribe(on_deprecated = "record_deprecated")]
+    enum E0 {
+        #[enumscribe(str = "lhr")]
+        V0,
+        #[enumscribe(str = "egll", alias = "heathrow", deprecated)]
+        V1,
+        #[enumscribe(str = "lgw", case_insensitive, deprecated)]
+        V2,
+        #[enumscribe(other)]
+        V3(String),
+    }
+
+    assert_eq!(E0::unscribe("lhr"), E0::V0);
+    assert_eq!(E0::unscribe("stn"), E0::V3("stn".to_owned()));
+    assert!(take_deprecated().is_empty());
+
+    assert_eq!(E0::unscribe("egll"), E0::V1);
+    assert_eq!(E0::unscribe("heathrow"), E0::V1);
+    assert_eq!(E0::unscribe("LgW"), E0::V2);
+    assert_eq!(take_deprecated(), ["egll", "heathrow", "LgW"]);
+
+    assert_eq!(E0::unscribe_tagged("egll"), (E0::V1, false));
+    assert_eq!(E0::try_unscribe("heathrow"), Some(E0::V1));
+    assert_eq!(E0::try_unscribe_bytes(b"egll"), Some(E0::V1));
+    assert_eq!(E0::try_unscribe_bytes(b"LGW"), Some(E0::V2));
+    assert_eq!("lgw".parse::<E0>(), Ok(E0::V2));
+    assert_eq!(serde_json::from_str::<E0>(r#""egll""#).unwrap(), E0::V1);
+    assert_eq!(take_deprecated(), ["egll", "heathrow", "egll", "LGW", "lgw", "egll"]);
+
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    #[allow(dead_code)]
+    enum E1 {
+        #[enumscribe(str = "old", deprecated)]
+        V0,
+    }
+
+    assert_eq!(E1::try_unscribe("old"), Some(E1::V0));
+    assert!(take_deprecated().is_empty());
+}
+
+#[test]
+fn test_unscribe_ascii_case_insensitive() {
+    #[derive(Unscribe, TryUnscribe, enumscribe::FromStr, enumscribe::EnumDeserialize)]
+    #[derive(Eq, PartialEq, Debug)]
+    #[enumscribe(case_insensitive = "ascii", ci_overflow = "error")]
+    enum E0 {
+        #[enumscribe(str = "lhr")]
+        V0,
+        #[enumscribe(str = "gross")]
+        V1,
+        #[enumscribe(str = "\u{e9}t\u{e9}")]
+        V2,
+        #[enumscribe(str = "OK", case_sensitive)]
+        V3,
+        #[enumscribe(str = "ok")]
+        V4,
+        #[enumscribe(other)]
+        V5(String),
+    }
+
+    assert_eq!(E0::unscribe("lhr"), E0::V0);
+    assert_eq!(E0::unscribe("LhR"), E0::V0);
+    assert_eq!(E0::unscribe("GROSS"), E0::V1);
+    assert_eq!(E0::unscribe("\u{e9}T\u{e9}"), E0::V2);
+    assert_eq!(E0::unscribe("OK"), E0::V3);
+    assert_eq!(E0::unscribe("Ok"), E0::V4);
+
+    // Only ASCII letters have their case ignored
+    assert_eq!(E0::unscribe("gro\u{df}"), E0::V5("gro\u{df}".to_owned()));
+    assert_eq!(E0::unscribe("\u{c9}t\u{e9}"), E0::V5("\u{c9}t\u{e9}".to_owned()));
+
+    // There is no uppercase buffer to overflow, so long strings go to the other variant
+    assert_eq!(E0::try_unscribe("lhrlhrlhr"), Some(E0::V5("lhrlhrlhr".to_owned())));
+
+    assert_eq!(E0::try_unscribe_bytes(b"LHR"), Some(E0::V0));
+    assert_eq!(E0::unscribe_chars("Gross".chars()), Some(E0::V1));
+    assert_eq!("lHr".parse::<E0>(), Ok(E0::V0));
+    assert_eq!(serde_json::from_str::<E0>(r#""GrOsS""#).unwrap(), E0::V1);
+
+    let mut buf = CappedString::<0>::new();
+    assert_eq!(E0::unscribe_with_buf("LHR", &mut buf), E0::V0);
+}
+
+#[test]
+fn test_unscribe_ascii_case_insensitive_variants() {
+    #[derive(Unscribe, TryUnscribe, enumscribe::FromStr, enumscribe::EnumDeserialize)]
+    #[derive(Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "lhr", case_insensitive = "ascii")]
+        V0,
+        #[enumscribe(str = "gro\u{df}", case_insensitive)]
+        V1,
+        #[enumscribe(str = "\u{e9}t\u{e9}", alias = "summer", case_insensitive = "ascii")]
+        V2,
+        #[enumscribe(str = "LGW")]
+        V3,
+        #[enumscribe(str = "lgw", case_insensitive = "ascii")]
+        V4,
+        #[enumscribe(other)]
+        V5(String),
+    }
+
+    assert_eq!(E0::unscribe("LHR"), E0::V0);
+    assert_eq!(E0::unscribe("lHr"), E0::V0);
+    assert_eq!(E0::unscribe("GROSS"), E0::V1);
+    assert_eq!(E0::unscribe("Gro\u{df}"), E0::V1);
+    assert_eq!(E0::unscribe("\u{e9}T\u{e9}"), E0::V2);
+    assert_eq!(E0::unscribe("SUMMER"), E0::V2);
+    assert_eq!(E0::unscribe("LGW"), E0::V3);
+    assert_eq!(E0::unscribe("lgw"), E0::V4);
+    assert_eq!(E0::unscribe("Lgw"), E0::V4);
+    assert_eq!(E0::unscribe("\u{c9}T\u{c9}"), E0::V5("\u{c9}T\u{c9}".to_owned()));
+    assert_eq!(E0::unscribe("lhr "), E0::V5("lhr ".to_owned()));
+
+    assert_eq!(E0::unscribe_tagged("LhR"), (E0::V0, false));
+    assert_eq!(E0::try_unscribe("SuMmEr"), Some(E0::V2));
+    assert_eq!(E0::try_unscribe_bytes(b"LHR"), Some(E0::V0));
+    assert_eq!(E0::try_unscribe_bytes(b"LGW"), Some(E0::V3));
+    assert_eq!(E0::unscribe_chars("gROSS".chars()), Some(E0::V1));
+    assert_eq!("lgW".parse::<E0>(), Ok(E0::V4));
+    assert_eq!(serde_json::from_str::<E0>(r#""Lhr""#).unwrap(), E0::V0);
+
+    let mut buf = CappedString::<0>::new();
+    assert_eq!(E0::unscribe_with_buf("LHR", &mut buf), E0::V0);
+    assert_eq!(E0::unscribe_with_buf("gross", &mut buf), E0::V1);
+
+    #[derive(TryUnscribe, Eq, PartialEq, Debug)]
+    #[enumscribe(case_insensitive = "ascii")]
+    enum E1 {
+        #[enumscribe(str = "lhr", case_insensitive)]
+        V0,
+        #[enumscribe(str = "lgw", case_sensitive)]
+        V1,
+    }
+
+    // `case_insensitive` on a variant of an ASCII case-insensitive enum is also ASCII
+    assert_eq!(E1::try_unscribe("LHR"), Some(E1::V0));
+    assert_eq!(E1::try_unscribe("lgw"), Some(E1::V1));
+    assert_eq!(E1::try_unscribe("LGW"), None);
+}
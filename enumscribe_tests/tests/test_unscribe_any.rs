@@ -0,0 +1,94 @@
+use enumscribe::{unscribe_any, TryUnscribe};
+
+#[derive(TryUnscribe, Eq, PartialEq, Debug)]
+enum E0 {
+    V0,
+    #[enumscribe(str = "foo")]
+    V1,
+}
+
+#[derive(TryUnscribe, Eq, PartialEq, Debug)]
+enum E1 {
+    #[enumscribe(str = "bar", case_insensitive)]
+    V0,
+    // Also a name of E0, so it is never reached through `unscribe_any!` when E0 comes first
+    #[enumscribe(str = "foo")]
+    V1,
+}
+
+#[derive(TryUnscribe, Eq, PartialEq, Debug)]
+enum E2 {
+    #[enumscribe(str = "baz")]
+    V0,
+    #[enumscribe(other)]
+    V1(String),
+}
+
+#[derive(Eq, PartialEq, Debug)]
+enum Any {
+    E0(E0),
+    E1(E1),
+    E2(E2),
+}
+
+impl From<E0> for Any {
+    fn from(e: E0) -> Self {
+        Any::E0(e)
+    }
+}
+
+impl From<E1> for Any {
+    fn from(e: E1) -> Self {
+        Any::E1(e)
+    }
+}
+
+impl From<E2> for Any {
+    fn from(e: E2) -> Self {
+        Any::E2(e)
+    }
+}
+
+fn parse(s: &str) -> Option<Any> {
+    unscribe_any!(s => E0 | E1 | E2)
+}
+
+#[test]
+fn test_unscribe_any() {
+    assert_eq!(parse("V0"), Some(Any::E0(E0::V0)));
+    assert_eq!(parse("foo"), Some(Any::E0(E0::V1)));
+    assert_eq!(parse("BaR"), Some(Any::E1(E1::V0)));
+    assert_eq!(parse("baz"), Some(Any::E2(E2::V0)));
+    assert_eq!(parse("qux"), Some(Any::E2(E2::V1("qux".to_owned()))));
+}
+
+#[test]
+fn test_unscribe_any_order() {
+    let e1_first: Option<Any> = unscribe_any!("foo" => E1 | E0);
+    assert_eq!(e1_first, Some(Any::E1(E1::V1)));
+
+    let e2_first: Option<Any> = unscribe_any!("foo" => E2 | E0 | E1);
+    assert_eq!(e2_first, Some(Any::E2(E2::V1("foo".to_owned()))));
+}
+
+#[test]
+fn test_unscribe_any_no_match() {
+    let none: Option<Any> = unscribe_any!("qux" => E0 | E1);
+    assert_eq!(none, None);
+
+    let single: Option<E0> = unscribe_any!("V0" => E0);
+    assert_eq!(single, Some(E0::V0));
+}
+
+#[test]
+fn test_unscribe_any_evaluates_once() {
+    let mut calls = 0;
+    let mut next = || {
+        calls += 1;
+        "baz"
+    };
+
+    let any: Option<Any> = unscribe_any!(next() => E0 | E1 | E2);
+    assert_eq!(any, Some(Any::E2(E2::V0)));
+    assert_eq!(calls, 1);
+}
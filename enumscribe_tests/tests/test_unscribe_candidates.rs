@@ -0,0 +1,113 @@
+use enumscribe::UnscribeCandidates;
+
+#[test]
+fn test_unscribe_candidates() {
+    #[derive(UnscribeCandidates, Eq, PartialEq, Debug)]
+    #[allow(dead_code)]
+    enum E0 {
+        #[enumscribe(str = "LHR")]
+        Heathrow,
+        #[enumscribe(str = "LGW", case_insensitive)]
+        Gatwick,
+        #[enumscribe(str = "LTN", alias = "Luton")]
+        Luton,
+        #[enumscribe(str = "STN")]
+        Stansted(),
+        #[enumscribe(str = "straße", case_insensitive)]
+        Strasse {},
+        #[enumscribe(ignore)]
+        Lydd,
+        #[enumscribe(other)]
+        Other(String),
+    }
+
+    assert_eq!(
+        E0::unscribe_candidates("L"),
+        vec![E0::Heathrow, E0::Gatwick, E0::Luton]
+    );
+    assert_eq!(E0::unscribe_candidates("l"), vec![E0::Gatwick]);
+    assert_eq!(E0::unscribe_candidates("LH"), vec![E0::Heathrow]);
+    assert_eq!(E0::unscribe_candidates("LHR"), vec![E0::Heathrow]);
+    assert_eq!(E0::unscribe_candidates("lg"), vec![E0::Gatwick]);
+    assert_eq!(E0::unscribe_candidates("Lu"), vec![E0::Luton]);
+    assert_eq!(E0::unscribe_candidates("lu"), vec![]);
+    assert_eq!(E0::unscribe_candidates("ST"), vec![E0::Stansted(), E0::Strasse {}]);
+    assert_eq!(E0::unscribe_candidates("strass"), vec![E0::Strasse {}]);
+    assert_eq!(E0::unscribe_candidates("X"), vec![]);
+    assert_eq!(E0::unscribe_candidates("LHRX"), vec![]);
+    assert_eq!(
+        E0::unscribe_candidates(""),
+        vec![E0::Heathrow, E0::Gatwick, E0::Luton, E0::Stansted(), E0::Strasse {}]
+    );
+
+    #[derive(UnscribeCandidates, Eq, PartialEq, Debug)]
+    #[enumscribe(normalize_newlines, case_insensitive)]
+    enum E1 {
+        #[enumscribe(str = "a\r\nb")]
+        V0,
+        #[enumscribe(str = "a\nc")]
+        V1,
+    }
+
+    assert_eq!(E1::unscribe_candidates("A\r\n"), vec![E1::V0, E1::V1]);
+    assert_eq!(E1::unscribe_candidates("a\nB"), vec![E1::V0]);
+
+    #[derive(UnscribeCandidates, Eq, PartialEq, Debug)]
+    #[enumscribe(nfc)]
+    enum E3 {
+        #[enumscribe(str = "caf\u{e9}")]
+        V0,
+        #[enumscribe(str = "cafe\u{300}")]
+        V1,
+    }
+
+    assert_eq!(E3::unscribe_candidates("caf"), vec![E3::V0, E3::V1]);
+    assert_eq!(E3::unscribe_candidates("cafe\u{301}"), vec![E3::V0]);
+    assert_eq!(E3::unscribe_candidates("caf\u{e8}"), vec![E3::V1]);
+
+    #[derive(UnscribeCandidates, Eq, PartialEq, Debug)]
+    #[allow(dead_code)]
+    enum E2 {
+        #[enumscribe(other)]
+        Other(String),
+    }
+
+    assert_eq!(E2::unscribe_candidates(""), vec![]);
+}
+
+#[test]
+fn test_unscribe_candidates_ascii_case_insensitive() {
+    #[derive(UnscribeCandidates, Eq, PartialEq, Debug)]
+    #[enumscribe(case_insensitive = "ascii")]
+    enum E0 {
+        #[enumscribe(str = "lhr")]
+        V0,
+        #[enumscribe(str = "lgw")]
+        V1,
+        #[enumscribe(str = "\u{e9}t\u{e9}")]
+        V2,
+    }
+
+    assert_eq!(E0::unscribe_candidates("L"), vec![E0::V0, E0::V1]);
+    assert_eq!(E0::unscribe_candidates("lH"), vec![E0::V0]);
+    assert_eq!(E0::unscribe_candidates("\u{e9}T"), vec![E0::V2]);
+    assert_eq!(E0::unscribe_candidates("\u{c9}"), vec![]);
+    assert_eq!(E0::unscribe_candidates("lhrs"), vec![]);
+}
+
+#[test]
+fn test_unscribe_candidates_generic() {
+    #[derive(UnscribeCandidates, Eq, PartialEq, Debug)]
+    #[allow(dead_code)]
+    enum E0<T> {
+        #[enumscribe(str = "LHR")]
+        Heathrow,
+        #[enumscribe(str = "LGW")]
+        Gatwick,
+        #[enumscribe(other)]
+        Other(T),
+    }
+
+    assert_eq!(E0::<String>::unscribe_candidates("L"), vec![E0::Heathrow, E0::Gatwick]);
+    assert_eq!(E0::<String>::unscribe_candidates("LG"), vec![E0::Gatwick]);
+}
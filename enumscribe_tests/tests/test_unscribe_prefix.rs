@@ -0,0 +1,119 @@
+use enumscribe::UnscribePrefix;
+
+#[test]
+fn test_unscribe_prefix() {
+    #[allow(dead_code)]
+    #[derive(UnscribePrefix, Eq, PartialEq, Debug)]
+    enum E0 {
+        #[enumscribe(str = "<")]
+        V0,
+        #[enumscribe(str = "<=")]
+        V1,
+        #[enumscribe(str = "<<")]
+        V2,
+        #[enumscribe(str = "<<=")]
+        V3,
+        #[enumscribe(ignore)]
+        V4(i32),
+        #[enumscribe(other)]
+        V5(String),
+    }
+
+    assert_eq!(E0::unscribe_prefix("<"), Some((E0::V0, "")));
+    assert_eq!(E0::unscribe_prefix("< 1"), Some((E0::V0, " 1")));
+    assert_eq!(E0::unscribe_prefix("<="), Some((E0::V1, "")));
+    assert_eq!(E0::unscribe_prefix("<= 1"), Some((E0::V1, " 1")));
+    assert_eq!(E0::unscribe_prefix("<<"), Some((E0::V2, "")));
+    assert_eq!(E0::unscribe_prefix("<<<"), Some((E0::V2, "<")));
+    assert_eq!(E0::unscribe_prefix("<<="), Some((E0::V3, "")));
+    assert_eq!(E0::unscribe_prefix("<<=="), Some((E0::V3, "=")));
+    assert_eq!(E0::unscribe_prefix("=<"), None);
+    assert_eq!(E0::unscribe_prefix(""), None);
+
+    // The longest match wins regardless of declaration order.
+    #[derive(UnscribePrefix, Eq, PartialEq, Debug)]
+    enum E1 {
+        #[enumscribe(str = "foobar")]
+        V0,
+        #[enumscribe(str = "foo")]
+        V1,
+        #[enumscribe(str = "foobarbaz")]
+        V2,
+    }
+
+    assert_eq!(E1::unscribe_prefix("foo"), Some((E1::V1, "")));
+    assert_eq!(E1::unscribe_prefix("foob"), Some((E1::V1, "b")));
+    assert_eq!(E1::unscribe_prefix("foobar"), Some((E1::V0, "")));
+    assert_eq!(E1::unscribe_prefix("foobarba"), Some((E1::V0, "ba")));
+    assert_eq!(E1::unscribe_prefix("foobarbaz!"), Some((E1::V2, "!")));
+    assert_eq!(E1::unscribe_prefix("fo"), None);
+
+    #[derive(UnscribePrefix, Eq, PartialEq, Debug)]
+    enum E2 {
+        #[enumscribe(str = "lhr", case_insensitive)]
+        V0,
+        #[enumscribe(str = "LHRX")]
+        V1,
+        #[enumscribe(str = "groß", case_insensitive)]
+        V2,
+        #[enumscribe(case_insensitive, accept_ident)]
+        Gatwick,
+    }
+
+    assert_eq!(E2::unscribe_prefix("LhR-"), Some((E2::V0, "-")));
+    assert_eq!(E2::unscribe_prefix("LHRX-"), Some((E2::V1, "-")));
+    assert_eq!(E2::unscribe_prefix("lhrx-"), Some((E2::V0, "x-")));
+    assert_eq!(E2::unscribe_prefix("GROSSARTIG"), Some((E2::V2, "ARTIG")));
+    assert_eq!(E2::unscribe_prefix("großartig"), Some((E2::V2, "artig")));
+    assert_eq!(E2::unscribe_prefix("gatwick airport"), Some((E2::Gatwick, " airport")));
+    assert_eq!(E2::unscribe_prefix("gro"), None);
+
+    // Case-sensitive names are tried before case-insensitive names of the same length, regardless
+    // of declaration order.
+    #[derive(UnscribePrefix, Eq, PartialEq, Debug)]
+    enum E3 {
+        #[enumscribe(str = "ok", case_insensitive)]
+        V0,
+        #[enumscribe(str = "OK")]
+        V1,
+    }
+
+    assert_eq!(E3::unscribe_prefix("OK!"), Some((E3::V1, "!")));
+    assert_eq!(E3::unscribe_prefix("ok!"), Some((E3::V0, "!")));
+    assert_eq!(E3::unscribe_prefix("Ok!"), Some((E3::V0, "!")));
+}
+
+#[test]
+fn test_unscribe_prefix_ascii_case_insensitive() {
+    #[derive(UnscribePrefix, Eq, PartialEq, Debug)]
+    #[enumscribe(case_insensitive = "ascii")]
+    enum E0 {
+        #[enumscribe(str = "lhr")]
+        V0,
+        #[enumscribe(str = "\u{e9}t\u{e9}")]
+        V1,
+    }
+
+    assert_eq!(E0::unscribe_prefix("LHR extra"), Some((E0::V0, " extra")));
+    assert_eq!(E0::unscribe_prefix("\u{e9}T\u{e9}!"), Some((E0::V1, "!")));
+    assert_eq!(E0::unscribe_prefix("\u{c9}T\u{e9}!"), None);
+    assert_eq!(E0::unscribe_prefix("lh"), None);
+}
+
+#[test]
+fn test_unscribe_prefix_generic() {
+    #[allow(dead_code)]
+    #[derive(UnscribePrefix, Eq, PartialEq, Debug)]
+    enum E0<T> {
+        #[enumscribe(str = "<")]
+        V0,
+        #[enumscribe(str = "<=")]
+        V1,
+        #[enumscribe(ignore)]
+        V2(T),
+    }
+
+    assert_eq!(E0::<u8>::unscribe_prefix("<= 1"), Some((E0::V1, " 1")));
+    assert_eq!(E0::<u8>::unscribe_prefix("< 1"), Some((E0::V0, " 1")));
+    assert_eq!(E0::<u8>::unscribe_prefix("> 1"), None);
+}
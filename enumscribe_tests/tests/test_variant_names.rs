@@ -0,0 +1,80 @@
+use enumscribe::{EnumCount, EnumVariantNames};
+
+struct Table<const N: usize> {
+    counts: [u32; N],
+}
+
+impl<const N: usize> Table<N> {
+    fn new() -> Self {
+        Self { counts: [0; N] }
+    }
+}
+
+fn zeroed_counts<T: EnumVariantNames<N>, const N: usize>() -> [(&'static str, u32); N] {
+    T::VARIANT_NAMES.map(|name| (name, 0))
+}
+
+#[test]
+fn test_enum_variant_names() {
+    #[derive(EnumVariantNames, EnumCount)]
+    #[allow(dead_code)]
+    enum E0 {
+        V0,
+        #[enumscribe(str = "foo")]
+        V1,
+        V2(),
+        #[enumscribe(ignore)]
+        V3(i32),
+        V4 {},
+        #[enumscribe(other)]
+        V5(String),
+    }
+
+    assert_eq!(E0::VARIANT_NAMES, ["V0", "foo", "V2", "V4"]);
+    assert_eq!(E0::VARIANT_NAMES.len(), E0::NUM_NAMED_VARIANTS);
+    assert_eq!(zeroed_counts::<E0, 4>(), [("V0", 0), ("foo", 0), ("V2", 0), ("V4", 0)]);
+
+    let table = Table::<{ E0::VARIANT_NAMES.len() }>::new();
+    assert_eq!(table.counts.len(), 4);
+
+    #[derive(EnumVariantNames)]
+    #[enumscribe(rename_all = "kebab-case")]
+    #[allow(dead_code)]
+    enum E1 {
+        FooBar,
+        #[enumscribe(str = "baz:{}", fmt_fields)]
+        Baz(i32),
+        #[enumscribe(alias = "qux")]
+        QuxQuux,
+    }
+
+    const E1_NAMES: [&str; 2] = E1::VARIANT_NAMES;
+    assert_eq!(E1_NAMES, ["foo-bar", "qux-quux"]);
+
+    #[derive(EnumVariantNames)]
+    #[allow(dead_code)]
+    enum E2 {
+        #[enumscribe(ignore)]
+        V0,
+        #[enumscribe(other)]
+        V1(String),
+    }
+
+    assert!(E2::VARIANT_NAMES.is_empty());
+    assert_eq!(Table::<{ E2::VARIANT_NAMES.len() }>::new().counts, []);
+}
+
+#[test]
+fn test_enum_variant_names_generic() {
+    #[derive(EnumVariantNames)]
+    #[allow(dead_code)]
+    enum E0<'a, T> {
+        V0,
+        #[enumscribe(str = "foo")]
+        V1,
+        #[enumscribe(ignore)]
+        V2(&'a T),
+    }
+
+    assert_eq!(E0::<'static, u8>::VARIANT_NAMES, ["V0", "foo"]);
+}
@@ -0,0 +1,127 @@
+//! Enums deriving every trait in a crate which denies many of the lints that are allowed by
+//! default, to check that the generated code does not trip lints in downstream crates. The
+//! workspace is checked with `cargo clippy --workspace --all-targets -- -D warnings`, which checks
+//! this crate too.
+//!
+//! The enums cover the shapes most likely to produce suspicious-looking code, such as enums with
+//! a single variant, or with only an `other` or `ignore` variant.
+
+#![deny(clippy::all, clippy::pedantic, clippy::nursery, clippy::cargo)]
+#![allow(clippy::cargo_common_metadata, clippy::multiple_crate_versions)]
+#![deny(
+    warnings,
+    rust_2018_idioms,
+    unused_qualifications,
+    unused_results,
+    unused_lifetimes,
+    single_use_lifetimes,
+    elided_lifetimes_in_paths,
+    trivial_casts,
+    trivial_numeric_casts,
+    unreachable_pub,
+    missing_copy_implementations,
+    missing_debug_implementations,
+    unsafe_code
+)]
+
+use enumscribe::{
+    EnumAllScribed, EnumCount, EnumDeserialize, EnumExtend, EnumFlags, EnumHashByName, EnumIs,
+    EnumMatchStrings, EnumMessage, EnumRustName, EnumSerdeWith, EnumSerialize, EnumStrConsts, EnumStrEq,
+    EnumVariantNames, FromStr, IntoCowStr, ScribeBoxStr, ScribeBytes, ScribeCasedCowStr, ScribeCowStr, ScribeFixed, ScribeInterned,
+    ScribeStaticBytes,
+    ScribeStaticStr, ScribeString, TryScribeBoxStr, TryScribeCowStr, TryScribeStaticStr,
+    TryScribeString, TryUnscribe, Unscribe, UnscribeBytes, UnscribeCandidates, UnscribePrefix,
+};
+
+#[derive(ScribeStaticStr, ScribeStaticBytes, TryScribeStaticStr, ScribeString, TryScribeString)]
+#[derive(ScribeCowStr, ScribeCasedCowStr, TryScribeCowStr, TryUnscribe, FromStr, UnscribePrefix)]
+#[derive(EnumHashByName, EnumStrEq, EnumCount, EnumMessage, EnumSerialize, EnumDeserialize)]
+#[derive(EnumRustName, EnumAllScribed, EnumFlags, EnumMatchStrings, UnscribeCandidates)]
+#[derive(IntoCowStr, EnumVariantNames, EnumSerdeWith, ScribeBoxStr, TryScribeBoxStr)]
+#[derive(ScribeFixed, EnumStrConsts, EnumIs, Clone, Copy, Debug)]
+pub enum Single {
+    #[enumscribe(str = "single")]
+    Single,
+}
+
+#[derive(ScribeString, TryScribeString, ScribeCowStr, ScribeCasedCowStr, TryScribeCowStr)]
+#[derive(Unscribe, TryUnscribe, FromStr, EnumHashByName, EnumStrEq, EnumCount, EnumMessage)]
+#[derive(EnumSerialize, EnumDeserialize, EnumRustName, EnumFlags, ScribeInterned)]
+#[derive(EnumMatchStrings, UnscribeCandidates, IntoCowStr, EnumSerdeWith)]
+#[derive(ScribeBoxStr, TryScribeBoxStr, ScribeFixed, EnumStrConsts, EnumIs, Debug)]
+pub enum OtherOnly {
+    #[enumscribe(other)]
+    Other(String),
+}
+
+#[derive(TryScribeStaticStr, TryScribeString, TryScribeCowStr, TryUnscribe, FromStr)]
+#[derive(EnumCount, EnumMessage, EnumSerialize, EnumRustName, EnumAllScribed)]
+#[derive(EnumMatchStrings, EnumVariantNames, TryScribeBoxStr)]
+#[derive(EnumIs, Clone, Copy, Debug)]
+pub enum IgnoreOnly {
+    #[enumscribe(ignore)]
+    Ignored,
+}
+
+#[derive(ScribeCowStr, Unscribe, TryUnscribe, FromStr, UnscribePrefix, EnumDeserialize)]
+#[derive(UnscribeCandidates, Debug)]
+#[enumscribe(case_insensitive, scribe_checked)]
+pub enum CaseInsensitive {
+    #[enumscribe(str = "single")]
+    Single,
+    #[enumscribe(other)]
+    Other(String),
+}
+
+#[derive(ScribeString, ScribeCowStr, ScribeCasedCowStr, TryScribeCowStr, Unscribe, TryUnscribe)]
+#[derive(FromStr, EnumHashByName, EnumStrEq, EnumSerialize, EnumDeserialize, ScribeInterned)]
+#[derive(Debug)]
+pub enum OptionalOther {
+    #[enumscribe(str = "named")]
+    Named,
+    #[enumscribe(other, optional_field, placeholder = "none")]
+    Other(Option<String>),
+}
+
+#[derive(TryUnscribe, FromStr, EnumDeserialize, UnscribeCandidates)]
+#[derive(Debug)]
+#[enumscribe(phf, normalize_newlines, case_insensitive, ci_overflow = "error")]
+pub enum Phf {
+    #[enumscribe(str = "one\n")]
+    One,
+    #[enumscribe(str = "two\n")]
+    Two,
+    #[enumscribe(other, deny_prefix = "__")]
+    Other(String),
+}
+
+#[derive(ScribeCowStr, TryScribeCowStr)]
+#[enumscribe(debug_as_scribe)]
+pub enum Formatted {
+    #[enumscribe(str = "port:{}", fmt_fields)]
+    Port(u16),
+    #[enumscribe(other)]
+    Other(String),
+}
+
+#[derive(EnumExtend, Clone, Copy, Debug)]
+#[enumscribe(extend = Single)]
+pub enum ExtendsSingle {
+    #[enumscribe(str = "single")]
+    Single,
+    #[enumscribe(str = "double")]
+    Double,
+}
+
+#[derive(ScribeBytes, UnscribeBytes, Debug)]
+#[enumscribe(case_insensitive)]
+pub enum Bytes {
+    #[enumscribe(str = "named", case_sensitive)]
+    Named,
+    #[enumscribe(str = "ascii", case_insensitive = "ascii")]
+    Ascii,
+    #[enumscribe(str = "unicode")]
+    Unicode,
+    #[enumscribe(other)]
+    Other(Vec<u8>),
+}
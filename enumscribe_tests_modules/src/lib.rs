@@ -0,0 +1,196 @@
+//! Enums deriving the traits inside nested modules, with `pub(crate)` and `pub(super)`
+//! visibility, and used from other modules. The modules also declare items with the same names as
+//! those in the prelude, to check that the generated code only refers to items by absolute paths
+//! and does not depend on where the enum is declared.
+
+// Everything here is only used by the tests at the bottom of this file
+#![cfg_attr(not(test), allow(dead_code))]
+
+pub(crate) mod airports {
+    use enumscribe::{
+        EnumAllScribed, EnumCount, EnumDeserialize, EnumHashByName, EnumIs, EnumMatchStrings,
+        EnumMessage, EnumRustName, EnumSerdeWith, EnumSerialize, EnumStrEq, EnumVariantNames,
+        FromStr, IntoCowStr, ScribeBoxStr, ScribeCasedCowStr, ScribeCowStr, ScribeInterned,
+        ScribeStaticBytes, ScribeStaticStr, ScribeString, TryScribeBoxStr, TryScribeCowStr,
+        TryScribeStaticStr, TryScribeString, TryUnscribe, Unscribe, UnscribeCandidates,
+        UnscribePrefix,
+    };
+
+    #[derive(ScribeStaticStr, ScribeStaticBytes, TryScribeStaticStr, ScribeString)]
+    #[derive(TryScribeString, ScribeBoxStr, TryScribeBoxStr, ScribeCasedCowStr)]
+    #[derive(TryUnscribe, FromStr, UnscribePrefix, UnscribeCandidates, EnumHashByName)]
+    #[derive(EnumStrEq, EnumCount, EnumMessage, EnumSerialize, EnumDeserialize, EnumRustName)]
+    #[derive(EnumAllScribed, EnumMatchStrings, EnumVariantNames, EnumIs, Clone, Copy, Debug)]
+    #[enumscribe(case_insensitive)]
+    pub(crate) enum Airport {
+        #[enumscribe(str = "LHR", alias = "EGLL", message = "London Heathrow")]
+        Heathrow,
+        #[enumscribe(str = "LGW", case_sensitive)]
+        Gatwick,
+    }
+
+    pub(crate) mod terminals {
+        use super::*;
+
+        #[derive(ScribeCowStr, TryScribeCowStr, ScribeInterned, Unscribe, TryUnscribe, FromStr)]
+        #[derive(EnumSerialize, EnumDeserialize, EnumSerdeWith, IntoCowStr, Eq, PartialEq)]
+        #[derive(Debug)]
+        #[enumscribe(serde_with = "terminal_serde", normalize_newlines)]
+        pub(super) enum Terminal {
+            #[enumscribe(str = "T2", case_insensitive = "ascii")]
+            Two,
+            #[enumscribe(str = "T5", deprecated)]
+            Five,
+            #[enumscribe(other)]
+            Other(std::string::String),
+        }
+
+        pub(in crate::airports) fn parse_terminal(s: &str) -> Terminal {
+            Terminal::unscribe(s)
+        }
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Flight {
+        #[serde(with = "terminals::terminal_serde")]
+        terminal: terminals::Terminal,
+    }
+
+    pub(crate) fn parse_terminal(s: &str) -> Option<std::string::String> {
+        terminals::parse_terminal(s).try_scribe().map(|name| name.into_owned())
+    }
+
+    pub(crate) fn round_trip_flight(json: &str) -> std::string::String {
+        let flight = serde_json::from_str::<Flight>(json).unwrap();
+        serde_json::to_string(&flight).unwrap()
+    }
+}
+
+/// Items with the same names as items in the prelude, which the generated code must not refer to.
+#[allow(dead_code, non_camel_case_types)]
+mod shadowed {
+    pub(crate) struct Option;
+    pub(crate) struct Result;
+    pub(crate) struct String;
+    pub(crate) struct Vec;
+    pub(crate) struct Box;
+    pub(crate) struct Some;
+    pub(crate) struct None;
+    pub(crate) struct Ok;
+    pub(crate) struct Err;
+    pub(crate) struct Self_;
+    pub(crate) trait Into {}
+    pub(crate) trait From {}
+    pub(crate) trait AsRef {}
+    pub(crate) trait Iterator {}
+    pub(crate) trait Default {}
+    pub(crate) mod core {}
+    pub(crate) mod std {}
+    pub(crate) mod alloc {}
+    pub(crate) mod serde {}
+    pub(crate) mod enumscribe {}
+}
+
+pub(crate) mod shadowing {
+    #[allow(unused_imports)]
+    use crate::shadowed::*;
+
+    #[derive(::enumscribe::ScribeCowStr, ::enumscribe::TryScribeCowStr)]
+    #[derive(::enumscribe::Unscribe, ::enumscribe::TryUnscribe, ::enumscribe::FromStr)]
+    #[derive(::enumscribe::UnscribeCandidates, ::enumscribe::UnscribePrefix)]
+    #[derive(::enumscribe::EnumSerialize, ::enumscribe::EnumDeserialize)]
+    #[derive(::enumscribe::ScribeString, ::enumscribe::TryScribeString)]
+    #[derive(::enumscribe::ScribeBoxStr, ::enumscribe::EnumHashByName)]
+    #[derive(::enumscribe::EnumStrEq, ::enumscribe::EnumCount)]
+    #[derive(Debug)]
+    #[enumscribe(case_insensitive, ci_overflow = "error")]
+    pub(crate) enum Light {
+        #[enumscribe(str = "red")]
+        Red,
+        #[enumscribe(str = "amber", case_insensitive = "ascii")]
+        Amber,
+        #[enumscribe(str = "green", case_sensitive)]
+        Green,
+        #[enumscribe(other)]
+        Other(::std::string::String),
+    }
+}
+
+pub(crate) mod shadowing_static {
+    #[allow(unused_imports)]
+    use crate::shadowed::*;
+
+    #[derive(::enumscribe::ScribeStaticStr, ::enumscribe::ScribeStaticBytes)]
+    #[derive(::enumscribe::TryScribeStaticStr, ::enumscribe::TryScribeBoxStr)]
+    #[derive(::enumscribe::ScribeCasedCowStr, ::enumscribe::ScribeInterned)]
+    #[derive(::enumscribe::IntoCowStr, ::enumscribe::EnumMessage, ::enumscribe::EnumRustName)]
+    #[derive(::enumscribe::EnumAllScribed, ::enumscribe::EnumMatchStrings)]
+    #[derive(::enumscribe::EnumVariantNames, ::enumscribe::EnumIs, ::enumscribe::EnumFlags)]
+    #[derive(::enumscribe::EnumSerdeWith, ::enumscribe::TryUnscribe, ::enumscribe::ScribeCowStr)]
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    #[enumscribe(phf, case_insensitive)]
+    pub(super) enum Signal {
+        #[enumscribe(str = "stop", message = "Stop")]
+        Stop,
+        #[enumscribe(str = "go")]
+        Go,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+
+    use enumscribe::{
+        EnumCount, ScribeCowStr, ScribeStaticStr, TryScribeCowStr, TryUnscribe, Unscribe,
+        UnscribeCandidates, UnscribePrefix,
+    };
+
+    use crate::airports::{self, Airport};
+    use crate::shadowing::Light;
+    use crate::shadowing_static::Signal;
+
+    #[test]
+    fn test_pub_crate_enum() {
+        assert_eq!(Airport::Heathrow.scribe(), "LHR");
+        assert_eq!(Airport::try_unscribe("egll"), Some(Airport::Heathrow));
+        assert_eq!(Airport::try_unscribe("lgw"), None);
+        assert_eq!("lhr".parse::<Airport>().map(|a| a.is_heathrow()), Ok(true));
+        assert_eq!(Airport::unscribe_prefix("LGW!"), Some((Airport::Gatwick, "!")));
+        assert_eq!(Airport::unscribe_candidates("L").len(), 2);
+        assert_eq!(Airport::NUM_VARIANTS, 2);
+        assert_eq!(Airport::Heathrow, Airport::Heathrow);
+        assert_eq!(serde_json::to_string(&Airport::Gatwick).unwrap(), r#""LGW""#);
+        assert_eq!(serde_json::from_str::<Airport>(r#""lhr""#).unwrap(), Airport::Heathrow);
+    }
+
+    #[test]
+    fn test_pub_super_enum() {
+        assert_eq!(airports::parse_terminal("t2").as_deref(), Some("T2"));
+        assert_eq!(airports::parse_terminal("T5").as_deref(), Some("T5"));
+        assert_eq!(airports::parse_terminal("T4").as_deref(), Some("T4"));
+        assert_eq!(airports::round_trip_flight(r#"{"terminal":"t2"}"#), r#"{"terminal":"T2"}"#);
+    }
+
+    #[test]
+    fn test_shadowed_prelude() {
+        assert_eq!(Light::Red.scribe(), Cow::Borrowed("red"));
+        assert_eq!(Light::Other("blue".to_owned()).try_scribe(), Some(Cow::Borrowed("blue")));
+        assert_eq!(Light::unscribe("RED"), Light::Red);
+        assert_eq!(Light::unscribe("Amber"), Light::Amber);
+        assert_eq!(Light::unscribe("GREEN"), Light::Other("GREEN".to_owned()));
+        assert_eq!(Light::try_unscribe("green"), Some(Light::Green));
+        assert_eq!(Light::try_unscribe_bytes(b"red"), Some(Light::Red));
+        assert_eq!(Light::unscribe_chars("ReD".chars()), Some(Light::Red));
+        assert_eq!(Light::unscribe_candidates("a"), vec![Light::Amber]);
+        assert_eq!(Light::unscribe_prefix("red light"), Some((Light::Red, " light")));
+        assert_eq!(Light::NUM_NAMED_VARIANTS, 3);
+        assert_eq!(serde_json::from_str::<Light>(r#""AMBER""#).unwrap(), Light::Amber);
+    }
+    #[test]
+    fn test_shadowed_prelude_static() {
+        assert_eq!(ScribeStaticStr::scribe(&Signal::Stop), "stop");
+        assert_eq!(Signal::try_unscribe("GO"), Some(Signal::Go));
+        assert!(Signal::Go.is_go());
+    }
+}
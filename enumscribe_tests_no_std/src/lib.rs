@@ -0,0 +1,42 @@
+//! Enums deriving the traits which need `alloc`, defined in a `no_std` crate to check that the
+//! generated code does not depend on `std`.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+
+use enumscribe::{
+    EnumDeserialize, EnumFlags, EnumSerialize, ScribeCasedCowStr, ScribeCowStr, ScribeString,
+    TryScribeCowStr, TryScribeString, TryUnscribe, Unscribe,
+};
+
+#[derive(ScribeCowStr, ScribeString, ScribeCasedCowStr, Unscribe, EnumSerialize, EnumDeserialize)]
+#[derive(Eq, PartialEq, Debug)]
+pub enum Airport {
+    #[enumscribe(str = "LHR", case_insensitive)]
+    Heathrow,
+    #[enumscribe(str = "LGW")]
+    Gatwick,
+    #[enumscribe(other)]
+    Other(String),
+}
+
+#[derive(TryScribeCowStr, TryScribeString, Eq, PartialEq, Debug)]
+pub enum Address {
+    #[enumscribe(str = "localhost")]
+    Localhost,
+    #[enumscribe(str = "port:{}", fmt_fields)]
+    Port(u16),
+    #[enumscribe(ignore)]
+    Secret(u64),
+}
+
+#[derive(Unscribe, TryUnscribe, EnumFlags, Eq, PartialEq, Debug)]
+pub enum Website {
+    #[enumscribe(str = "github.com", case_insensitive)]
+    Github,
+    #[enumscribe(other, raw_field = "raw", norm_field = "norm")]
+    Other { raw: String, norm: String },
+}
@@ -0,0 +1,53 @@
+use std::borrow::Cow;
+
+use enumscribe::{
+    EnumFlags, ScribeCasedCowStr, ScribeCowStr, ScribeString, TryScribeCowStr, TryScribeString, TryUnscribe,
+    Unscribe,
+};
+use enumscribe_tests_no_std::{Address, Airport, Website};
+
+#[test]
+fn test_scribe_alloc() {
+    assert_eq!(ScribeCowStr::scribe(&Airport::Heathrow), Cow::Borrowed("LHR"));
+    assert_eq!(
+        ScribeCowStr::scribe(&Airport::Other("STN".to_owned())),
+        Cow::Owned::<'static, str>("STN".to_owned())
+    );
+    assert_eq!(ScribeString::scribe(&Airport::Gatwick), "LGW".to_owned());
+    assert_eq!(Airport::Heathrow.scribe_lower(), "lhr");
+    assert_eq!(Airport::Other("Stn".to_owned()).scribe_upper(), "STN");
+
+    assert_eq!(TryScribeCowStr::try_scribe(&Address::Localhost), Some(Cow::Borrowed("localhost")));
+    assert_eq!(TryScribeString::try_scribe(&Address::Port(8080)), Some("port:8080".to_owned()));
+    assert_eq!(TryScribeCowStr::try_scribe(&Address::Secret(0)), None);
+}
+
+#[test]
+fn test_unscribe_alloc() {
+    assert_eq!(Airport::unscribe("lhr"), Airport::Heathrow);
+    assert_eq!(Airport::unscribe("LGW"), Airport::Gatwick);
+    assert_eq!(Airport::unscribe("lgw"), Airport::Other("lgw".to_owned()));
+
+    assert_eq!(Website::unscribe("GitHub.com"), Website::Github);
+    assert_eq!(
+        Website::try_unscribe("Crates.io"),
+        Some(Website::Other { raw: "Crates.io".to_owned(), norm: "crates.io".to_owned() })
+    );
+    assert_eq!(
+        Website::parse_set(&["GITHUB.COM", "Crates.io"]),
+        Ok(vec![
+            Website::Github,
+            Website::Other { raw: "Crates.io".to_owned(), norm: "crates.io".to_owned() },
+        ])
+    );
+}
+
+#[test]
+fn test_serde_alloc() {
+    assert_eq!(serde_json::to_string(&Airport::Heathrow).unwrap(), r#""LHR""#);
+    assert_eq!(serde_json::from_str::<Airport>(r#""lhr""#).unwrap(), Airport::Heathrow);
+    assert_eq!(
+        serde_json::from_str::<Airport>(r#""a string much longer than any airport code""#).unwrap(),
+        Airport::Other("a string much longer than any airport code".to_owned())
+    );
+}
@@ -0,0 +1,72 @@
+//! Enums deriving every trait in a crate which depends on enumscribe under a different name, to
+//! check that `#[enumscribe(crate = "...")]` replaces every path to enumscribe in the generated
+//! code. There is no `enumscribe` crate for the generated code to fall back on here.
+
+use renamed_enumscribe::{
+    EnumAllScribed, EnumCount, EnumDeserialize, EnumExtend, EnumFlags, EnumHashByName, EnumIs,
+    EnumMatchStrings, EnumMessage, EnumRustName, EnumSerdeWith, EnumSerialize, EnumStrConsts, EnumStrEq,
+    EnumVariantNames, FromStr, IntoCowStr, ScribeBoxStr, ScribeBytes, ScribeCasedCowStr, ScribeCowStr, ScribeFixed, ScribeInterned,
+    ScribeStaticBytes,
+    ScribeStaticStr, ScribeString, TryScribeBoxStr, TryScribeCowStr, TryScribeStaticStr,
+    TryScribeString, TryUnscribe, Unscribe, UnscribeBytes, UnscribeCandidates, UnscribePrefix,
+};
+
+#[derive(ScribeStaticStr, ScribeStaticBytes, TryScribeStaticStr, ScribeString, TryScribeString)]
+#[derive(ScribeCowStr, ScribeCasedCowStr, TryScribeCowStr, TryUnscribe, FromStr, UnscribePrefix)]
+#[derive(EnumHashByName, EnumStrEq, EnumCount, EnumMessage, EnumSerialize, EnumDeserialize)]
+#[derive(EnumRustName, EnumAllScribed, EnumFlags, EnumMatchStrings, UnscribeCandidates)]
+#[derive(EnumVariantNames, EnumStrConsts, EnumIs, Clone, Copy, Debug)]
+#[enumscribe(crate = "renamed_enumscribe", phf, case_insensitive)]
+pub enum Colour {
+    #[enumscribe(str = "red", message = "The colour red")]
+    Red,
+    #[enumscribe(str = "green", alias = "verde")]
+    Green,
+}
+
+#[derive(ScribeCowStr, TryScribeCowStr, ScribeInterned, Unscribe, TryUnscribe, FromStr)]
+#[derive(EnumSerialize, EnumDeserialize, EnumSerdeWith, UnscribeCandidates, IntoCowStr)]
+#[derive(ScribeFixed, Eq, PartialEq)]
+#[enumscribe(crate = "::renamed_enumscribe", debug_as_scribe, scribe_checked)]
+pub enum Airport {
+    #[enumscribe(str = "LHR", case_insensitive)]
+    Heathrow,
+    #[enumscribe(str = "LGW")]
+    Gatwick,
+    #[enumscribe(other)]
+    Other(String),
+}
+
+#[derive(ScribeString, ScribeCowStr, TryScribeString, TryScribeCowStr, Eq, PartialEq, Debug)]
+#[derive(ScribeBoxStr, TryScribeBoxStr)]
+#[enumscribe(crate = "renamed_enumscribe")]
+pub enum Address {
+    #[enumscribe(str = "localhost")]
+    Localhost,
+    #[enumscribe(str = "port:{}", fmt_fields)]
+    Port(u16),
+}
+
+#[derive(EnumExtend, Eq, PartialEq, Debug)]
+#[enumscribe(crate = "renamed_enumscribe", extend = Colour)]
+pub enum ExtendedColour {
+    #[enumscribe(str = "red")]
+    Red,
+    #[enumscribe(str = "green")]
+    Green,
+    #[enumscribe(str = "blue")]
+    Blue,
+}
+
+#[derive(ScribeBytes, UnscribeBytes, Debug)]
+#[enumscribe(crate = "renamed_enumscribe", case_insensitive)]
+pub enum Bytes {
+    #[enumscribe(str = "named", case_sensitive)]
+    Named,
+    #[enumscribe(str = "ascii", case_insensitive = "ascii")]
+    Ascii,
+    #[enumscribe(str = "unicode")]
+    Unicode,
+    #[enumscribe(other)]
+    Other(Vec<u8>),
+}
@@ -0,0 +1,81 @@
+use std::borrow::Cow;
+use std::collections::HashSet;
+
+use renamed_enumscribe::{
+    EnumAllScribed, EnumCount, EnumFlags, EnumMatchStrings, EnumMessage, EnumRustName,
+    EnumVariantNames, OtherShadowsNamed, ScribeBoxStr, ScribeCasedCowStr, ScribeChecked,
+    ScribeCowStr, ScribeInterned, ScribeStaticBytes, ScribeStaticStr, ScribeString,
+    TryScribeBoxStr, TryScribeCowStr, TryScribeStaticStr, TryScribeString, TryUnscribe, Unscribe,
+    UnscribeCandidates, UnscribePrefix,
+};
+use enumscribe_tests_renamed::{airport_serde, Address, Airport, Colour};
+
+#[test]
+fn test_scribe_renamed() {
+    assert_eq!(ScribeStaticStr::scribe(&Colour::Red), "red");
+    assert_eq!(Colour::Green.scribe_bytes(), b"green");
+    assert_eq!(TryScribeStaticStr::try_scribe(&Colour::Red), Some("red"));
+    assert_eq!(ScribeString::scribe(&Colour::Green), "green".to_owned());
+    assert_eq!(TryScribeString::try_scribe(&Colour::Green), Some("green".to_owned()));
+    assert_eq!(ScribeCowStr::scribe(&Colour::Red), Cow::Borrowed("red"));
+    assert_eq!(TryScribeCowStr::try_scribe(&Colour::Red), Some(Cow::Borrowed("red")));
+    assert_eq!(Colour::Red.scribe_upper(), "RED");
+
+    assert_eq!(ScribeCowStr::scribe(&Airport::Heathrow), Cow::Borrowed("LHR"));
+    assert_eq!(Cow::from(&Airport::Gatwick), Cow::Borrowed("LGW"));
+    assert_eq!(Airport::Other("STN".to_owned()).scribe_interned(), "STN");
+    assert_eq!(format!("{:?}", Airport::Other("STN".to_owned())), "STN");
+    assert_eq!(Airport::Gatwick.scribe_checked().as_deref(), Ok("LGW"));
+    assert_eq!(Airport::Other("lhr".to_owned()).scribe_checked(), Err(OtherShadowsNamed));
+
+    assert_eq!(ScribeString::scribe(&Address::Port(8080)), "port:8080".to_owned());
+    assert_eq!(TryScribeCowStr::try_scribe(&Address::Localhost), Some(Cow::Borrowed("localhost")));
+    assert_eq!(ScribeBoxStr::scribe(&Address::Localhost), Box::from("localhost"));
+    assert_eq!(TryScribeBoxStr::try_scribe(&Address::Port(22)), Some(Box::from("port:22")));
+}
+
+#[test]
+fn test_unscribe_renamed() {
+    assert_eq!(Colour::try_unscribe("RED"), Some(Colour::Red));
+    assert_eq!(Colour::try_unscribe("verde"), Some(Colour::Green));
+    assert_eq!(Colour::try_unscribe("blue"), None);
+    assert_eq!("green".parse::<Colour>(), Ok(Colour::Green));
+    assert_eq!(Colour::unscribe_prefix("red!"), Some((Colour::Red, "!")));
+    assert_eq!(Colour::unscribe_candidates("g"), vec![Colour::Green]);
+    assert_eq!(Colour::parse_set(&["red", "green"]), Ok(vec![Colour::Red, Colour::Green]));
+
+    assert!(Airport::unscribe("lhr") == Airport::Heathrow);
+    assert!(Airport::unscribe("STN") == Airport::Other("STN".to_owned()));
+    assert!(Airport::try_unscribe("LGW") == Some(Airport::Gatwick));
+    assert!("LGW".parse::<Airport>() == Ok(Airport::Gatwick));
+    assert!(Airport::unscribe_candidates("l") == vec![Airport::Heathrow]);
+    assert!(Airport::unscribe_candidates("L") == vec![Airport::Heathrow, Airport::Gatwick]);
+}
+
+#[test]
+fn test_enum_info_renamed() {
+    assert_eq!(Colour::NUM_VARIANTS, 2);
+    assert_eq!(Colour::Red.message(), Some("The colour red"));
+    assert_eq!(Colour::Green.rust_name(), "Green");
+    assert_eq!(Colour::all_scribed().len(), 2);
+    assert_eq!(Colour::VARIANT_NAMES, ["red", "green"]);
+    assert_eq!(Colour::Green.match_strings(), &["green", "verde"]);
+    assert!(Colour::Green.is_green());
+    assert!(!Colour::Green.is_red());
+
+    let colours = [Colour::Red, Colour::Green, Colour::Red].iter().copied().collect::<HashSet<_>>();
+    assert_eq!(colours.len(), 2);
+}
+
+#[test]
+fn test_serde_renamed() {
+    assert_eq!(serde_json::to_string(&Colour::Red).unwrap(), "\"red\"");
+    assert_eq!(serde_json::from_str::<Colour>("\"GREEN\"").unwrap(), Colour::Green);
+
+    assert_eq!(serde_json::to_string(&Airport::Other("STN".to_owned())).unwrap(), "\"STN\"");
+    assert!(serde_json::from_str::<Airport>("\"lhr\"").unwrap() == Airport::Heathrow);
+
+    let mut buf = Vec::new();
+    airport_serde::serialize(&Airport::Gatwick, &mut serde_json::Serializer::new(&mut buf)).unwrap();
+    assert_eq!(buf, b"\"LGW\"");
+}